@@ -1,5 +1,6 @@
 export interface VaultInfoResponseDto {
   vaultAddress: string;
+  vaultId: number;
   authority: string;
   payoutWallet: string;
   usdtMint: string;
@@ -27,8 +28,10 @@ export interface ClaimInfoResponseDto {
 export interface WithdrawalRequestResponseDto {
   vault: string;
   user: string;
+  requestId: string;
   amount: number;
   createdAt: string;
   expiresAt: string;
   pdaAddress: string;
+  userLedgerPda: string;
 }
@@ -14,21 +14,22 @@ export type TreasuryVault = {
   };
   instructions: [
     {
-      name: 'cancelWithdrawal';
+      name: 'acceptAuthority';
       docs: [
-        'Cancel an expired withdrawal request. Only authority can call.',
-        'Cleans up the PDA and returns rent to authority.',
+        'Accept a pending authority proposal. Signed by the proposed authority.',
+        'Vault address is unchanged because its PDA is seeded by `seed_key`',
+        'and `vault_id`.',
       ];
-      discriminator: [183, 104, 181, 250, 28, 128, 210, 70];
+      discriminator: [107, 86, 198, 91, 33, 12, 107, 160];
       accounts: [
         {
-          name: 'authority';
-          writable: true;
+          name: 'newAuthority';
+          docs: ['Proposed authority signs to prove it controls the new key'];
           signer: true;
-          relations: ['vault'];
         },
         {
           name: 'vault';
+          writable: true;
           pda: {
             seeds: [
               {
@@ -52,31 +53,13 @@ export type TreasuryVault = {
               },
               {
                 kind: 'account';
-                path: 'authority';
-              },
-            ];
-          };
-          relations: ['withdrawalRequest'];
-        },
-        {
-          name: 'user';
-        },
-        {
-          name: 'withdrawalRequest';
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: 'const';
-                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
-              },
-              {
-                kind: 'account';
-                path: 'vault';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
               },
               {
                 kind: 'account';
-                path: 'user';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
               },
             ];
           };
@@ -85,24 +68,19 @@ export type TreasuryVault = {
       args: [];
     },
     {
-      name: 'claimWithdrawal';
+      name: 'addMint';
       docs: [
-        'Claim a pending withdrawal. User signs with their wallet.',
-        "USDT goes directly from vault to user's token account.",
+        'Register another mint (e.g. USDC, PYUSD) with its own vault token',
+        'account and totals. Only authority can call. In multisig mode also',
+        'consumes an approved AddMint proposal. The mint must have the same',
+        'decimals as the primary USDT mint.',
       ];
-      discriminator: [118, 206, 173, 38, 239, 165, 65, 30];
+      discriminator: [171, 222, 111, 37, 60, 166, 208, 108];
       accounts: [
-        {
-          name: 'user';
-          docs: ['User signs the transaction with their wallet'];
-          writable: true;
-          signer: true;
-          relations: ['withdrawalRequest'];
-        },
         {
           name: 'authority';
-          docs: ['Validated through vault.has_one = authority.'];
           writable: true;
+          signer: true;
           relations: ['vault'];
         },
         {
@@ -131,50 +109,36 @@ export type TreasuryVault = {
               },
               {
                 kind: 'account';
-                path: 'authority';
-              },
-            ];
-          };
-          relations: ['withdrawalRequest'];
-        },
-        {
-          name: 'withdrawalRequest';
-          writable: true;
-          pda: {
-            seeds: [
-              {
-                kind: 'const';
-                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
-              },
-              {
-                kind: 'account';
-                path: 'vault';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
               },
               {
                 kind: 'account';
-                path: 'user';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
               },
             ];
           };
         },
         {
-          name: 'usdtMint';
-          relations: ['vault'];
+          name: 'mint';
+          docs: [
+            "Mint to register. Must share the primary mint's decimals so amounts,",
+            'totals and rate limits keep a single unit.',
+          ];
         },
         {
           name: 'vaultTokenAccount';
-          docs: ["Vault's USDT token account (source)"];
-          writable: true;
-        },
-        {
-          name: 'userTokenAccount';
-          docs: ["User's USDT token account (destination)"];
+          docs: [
+            "Vault's token account for the mint (ATA owned by vault PDA).",
+            'Anyone can create an ATA, so an existing one is reused.',
+          ];
           writable: true;
           pda: {
             seeds: [
               {
                 kind: 'account';
-                path: 'user';
+                path: 'vault';
               },
               {
                 kind: 'account';
@@ -182,7 +146,7 @@ export type TreasuryVault = {
               },
               {
                 kind: 'account';
-                path: 'usdtMint';
+                path: 'mint';
               },
             ];
             program: {
@@ -224,6 +188,12 @@ export type TreasuryVault = {
             };
           };
         },
+        {
+          name: 'proposal';
+          docs: ['Approved AddMint proposal, required in multisig mode'];
+          writable: true;
+          optional: true;
+        },
         {
           name: 'tokenProgram';
         },
@@ -239,16 +209,16 @@ export type TreasuryVault = {
       args: [];
     },
     {
-      name: 'createWithdrawal';
+      name: 'amendWithdrawal';
       docs: [
-        'Create a withdrawal request for a user. Only authority can call.',
-        'User can then claim USDT directly by signing with their wallet.',
+        "Lower a pending request's amount. Only authority can call.",
+        'The difference is released from `total_reserved`; raising the amount',
+        'requires a new request.',
       ];
-      discriminator: [247, 103, 160, 95, 42, 161, 108, 91];
+      discriminator: [110, 30, 29, 218, 145, 203, 182, 206];
       accounts: [
         {
           name: 'authority';
-          writable: true;
           signer: true;
           relations: ['vault'];
         },
@@ -278,17 +248,17 @@ export type TreasuryVault = {
               },
               {
                 kind: 'account';
-                path: 'authority';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
               },
             ];
           };
-        },
-        {
-          name: 'usdtMint';
-          relations: ['vault'];
-        },
-        {
-          name: 'vaultTokenAccount';
+          relations: ['withdrawalRequest'];
         },
         {
           name: 'user';
@@ -310,33 +280,57 @@ export type TreasuryVault = {
                 kind: 'account';
                 path: 'user';
               },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
             ];
           };
         },
         {
-          name: 'systemProgram';
-          address: '11111111111111111111111111111111';
+          name: 'userLedger';
+          docs: ["User's on-chain ledger"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+            ];
+          };
         },
       ];
       args: [
         {
-          name: 'amount';
+          name: 'requestId';
           type: 'u64';
         },
         {
-          name: 'expiresIn';
-          type: 'i64';
+          name: 'amount';
+          type: 'u64';
         },
       ];
     },
     {
-      name: 'deposit';
-      docs: ['Deposit USDT into the vault. Only authority can call.'];
-      discriminator: [242, 35, 198, 137, 82, 225, 242, 182];
+      name: 'applyTransferAllowlist';
+      docs: [
+        'Apply the pending transfer allowlist once its timelock has elapsed.',
+        'Only authority can call. In multisig mode also consumes an approved',
+        'ApplyTransferAllowlist proposal naming the pending list.',
+      ];
+      discriminator: [254, 83, 176, 59, 143, 211, 0, 33];
       accounts: [
         {
           name: 'authority';
-          writable: true;
           signer: true;
           relations: ['vault'];
         },
@@ -366,101 +360,113 @@ export type TreasuryVault = {
               },
               {
                 kind: 'account';
-                path: 'authority';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
               },
             ];
           };
         },
         {
-          name: 'usdtMint';
-          relations: ['vault'];
+          name: 'proposal';
+          docs: [
+            'Approved ApplyTransferAllowlist proposal, required in multisig mode',
+          ];
+          writable: true;
+          optional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'approveProposal';
+      docs: ['Approve a pending proposal. Only multisig signers can call.'];
+      discriminator: [136, 108, 102, 85, 98, 114, 7, 147];
+      accounts: [
+        {
+          name: 'signer';
+          docs: ["Member of the vault's multisig signer set"];
+          signer: true;
         },
         {
-          name: 'authorityTokenAccount';
-          docs: ["Authority's USDT token account (source of deposit)"];
-          writable: true;
+          name: 'vault';
           pda: {
             seeds: [
               {
-                kind: 'account';
-                path: 'authority';
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
               },
               {
                 kind: 'account';
-                path: 'tokenProgram';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
               },
               {
                 kind: 'account';
-                path: 'usdtMint';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
               },
             ];
-            program: {
-              kind: 'const';
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89,
-              ];
-            };
           };
+          relations: ['proposal'];
         },
         {
-          name: 'vaultTokenAccount';
-          docs: ["Vault's USDT token account (destination)"];
+          name: 'proposal';
           writable: true;
-        },
-        {
-          name: 'tokenProgram';
-        },
-      ];
-      args: [
-        {
-          name: 'amount';
-          type: 'u64';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'proposal.proposal_id';
+                account: 'proposal';
+              },
+            ];
+          };
         },
       ];
+      args: [];
     },
     {
-      name: 'initialize';
+      name: 'cancelExpiredBatch';
       docs: [
-        'Initialize the treasury vault. Called once after deploy.',
-        'Sets authority (backend wallet) and payout_wallet (payout destination).',
+        'Cancel many expired requests at once. Only authority can call.',
+        'Each request and its user ledger are passed as remaining accounts;',
+        'requests that are still claimable or already closed are skipped.',
       ];
-      discriminator: [175, 175, 109, 31, 13, 152, 155, 237];
+      discriminator: [57, 196, 41, 2, 242, 57, 239, 25];
       accounts: [
         {
           name: 'authority';
           writable: true;
           signer: true;
+          relations: ['vault'];
         },
         {
           name: 'vault';
@@ -488,102 +494,198 @@ export type TreasuryVault = {
               },
               {
                 kind: 'account';
-                path: 'authority';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
               },
             ];
           };
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'cancelTransferAllowlist';
+      docs: [
+        'Drop the pending transfer allowlist before it takes effect.',
+        'Guardian or authority can call.',
+      ];
+      discriminator: [26, 186, 164, 237, 232, 9, 28, 71];
+      accounts: [
         {
-          name: 'usdtMint';
-          docs: ['USDT SPL mint'];
+          name: 'signer';
+          docs: ['Guardian or authority'];
+          signer: true;
         },
         {
-          name: 'vaultTokenAccount';
-          docs: ["Vault's token account (ATA owned by vault PDA)"];
+          name: 'vault';
           writable: true;
           pda: {
             seeds: [
               {
-                kind: 'account';
-                path: 'vault';
-              },
-              {
-                kind: 'account';
-                path: 'tokenProgram';
-              },
-              {
-                kind: 'account';
-                path: 'usdtMint';
-              },
-            ];
-            program: {
-              kind: 'const';
-              value: [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89,
-              ];
-            };
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
           };
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'cancelWithdrawal';
+      docs: [
+        'Cancel an expired withdrawal request. Only authority can call.',
+        'Cleans up the PDA and returns rent to authority.',
+      ];
+      discriminator: [183, 104, 181, 250, 28, 128, 210, 70];
+      accounts: [
         {
-          name: 'payoutWallet';
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['vault'];
         },
         {
-          name: 'tokenProgram';
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+          relations: ['withdrawalRequest'];
         },
         {
-          name: 'associatedTokenProgram';
-          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+          name: 'user';
         },
         {
-          name: 'systemProgram';
-          address: '11111111111111111111111111111111';
+          name: 'withdrawalRequest';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
+            ];
+          };
+        },
+        {
+          name: 'userLedger';
+          docs: ["User's on-chain ledger"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'requestId';
+          type: 'u64';
         },
       ];
-      args: [];
     },
     {
-      name: 'payout';
+      name: 'claimWithVoucher';
       docs: [
-        'Payout USDT from vault to payout_wallet. Only authority can call.',
+        'Claim with a backend-signed voucher instead of a withdrawal request.',
+        'The transaction must carry an Ed25519 verification of the voucher by',
+        '`vault.voucher_signer` right before this instruction. Each nonce is',
+        'usable once per user; the user pays for the whole transaction.',
       ];
-      discriminator: [149, 140, 194, 236, 174, 189, 6, 239];
+      discriminator: [48, 217, 238, 96, 67, 81, 122, 55];
       accounts: [
         {
-          name: 'authority';
+          name: 'user';
+          docs: [
+            'User signs and pays for the transaction and any new accounts',
+          ];
           writable: true;
           signer: true;
-          relations: ['vault'];
         },
         {
           name: 'vault';
@@ -611,29 +713,63 @@ export type TreasuryVault = {
               },
               {
                 kind: 'account';
-                path: 'authority';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
               },
             ];
           };
         },
         {
-          name: 'usdtMint';
-          relations: ['vault'];
+          name: 'voucherNonces';
+          docs: ['Used-nonce bitmap page holding `nonce`'];
+          writable: true;
+        },
+        {
+          name: 'userLedger';
+          docs: ["User's on-chain ledger, created on first claim"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: [
+            'Registered mint the voucher pays in, enabled for new withdrawals',
+          ];
         },
         {
           name: 'vaultTokenAccount';
-          docs: ["Vault's USDT token account (source — PDA is authority)"];
+          docs: ["Vault's token account (source)"];
           writable: true;
         },
         {
-          name: 'payoutTokenAccount';
-          docs: ["Payout wallet's USDT token account (destination)"];
+          name: 'userTokenAccount';
+          docs: ["User's token account (destination)"];
           writable: true;
           pda: {
             seeds: [
               {
                 kind: 'account';
-                path: 'payoutWallet';
+                path: 'user';
               },
               {
                 kind: 'account';
@@ -641,7 +777,7 @@ export type TreasuryVault = {
               },
               {
                 kind: 'account';
-                path: 'usdtMint';
+                path: 'mint';
               },
             ];
             program: {
@@ -684,8 +820,8 @@ export type TreasuryVault = {
           };
         },
         {
-          name: 'payoutWallet';
-          relations: ['vault'];
+          name: 'instructionsSysvar';
+          address: 'Sysvar1nstructions1111111111111111111111111';
         },
         {
           name: 'tokenProgram';
@@ -704,108 +840,4420 @@ export type TreasuryVault = {
           name: 'amount';
           type: 'u64';
         },
+        {
+          name: 'nonce';
+          type: 'u64';
+        },
+        {
+          name: 'expiresAt';
+          type: 'i64';
+        },
       ];
     },
-  ];
-  accounts: [
-    {
-      name: 'treasuryVault';
-      discriminator: [86, 102, 19, 109, 56, 58, 144, 81];
-    },
-    {
-      name: 'withdrawalRequest';
-      discriminator: [242, 88, 147, 173, 182, 62, 229, 193];
-    },
-  ];
-  events: [
-    {
-      name: 'depositEvent';
-      discriminator: [120, 248, 61, 83, 31, 142, 107, 144];
-    },
-    {
-      name: 'payoutEvent';
-      discriminator: [84, 234, 195, 72, 143, 79, 70, 82];
-    },
-    {
-      name: 'vaultInitialized';
-      discriminator: [180, 43, 207, 2, 18, 71, 3, 75];
-    },
     {
-      name: 'withdrawalCancelledEvent';
-      discriminator: [40, 218, 165, 230, 31, 49, 238, 127];
-    },
-    {
-      name: 'withdrawalClaimedEvent';
-      discriminator: [112, 246, 33, 174, 230, 205, 121, 26];
-    },
-    {
-      name: 'withdrawalCreatedEvent';
-      discriminator: [173, 205, 34, 150, 97, 42, 211, 30];
-    },
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: 'unauthorized';
-      msg: 'Unauthorized: only vault authority can perform this action';
-    },
-    {
-      code: 6001;
-      name: 'invalidMint';
-      msg: 'Invalid USDT mint address';
-    },
-    {
-      code: 6002;
-      name: 'invalidVaultAccount';
-      msg: 'Invalid vault token account';
-    },
-    {
-      code: 6003;
-      name: 'invalidPayoutWallet';
-      msg: 'Invalid payout wallet';
+      name: 'claimWithdrawal';
+      docs: [
+        'Claim a pending withdrawal. User signs with their wallet.',
+        "Funds go directly from vault to the token account of the request's",
+        "destination (the user's own wallet unless one was set at creation).",
+      ];
+      discriminator: [118, 206, 173, 38, 239, 165, 65, 30];
+      accounts: [
+        {
+          name: 'user';
+          docs: ['User signs the transaction with their wallet'];
+          writable: true;
+          signer: true;
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'authority';
+          docs: ['Validated through vault.has_one = authority.'];
+          writable: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'withdrawalRequest';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
+            ];
+          };
+        },
+        {
+          name: 'userLedger';
+          docs: ["User's on-chain ledger"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: ['Mint the request is paid in'];
+        },
+        {
+          name: 'vaultTokenAccount';
+          docs: ["Vault's token account (source)"];
+          writable: true;
+        },
+        {
+          name: 'destination';
+          docs: [
+            '`destination` or the user. Validated against withdrawal_request.',
+          ];
+        },
+        {
+          name: 'destinationTokenAccount';
+          docs: ["Destination's token account"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'account';
+                path: 'destination';
+              },
+              {
+                kind: 'account';
+                path: 'tokenProgram';
+              },
+              {
+                kind: 'account';
+                path: 'mint';
+              },
+            ];
+            program: {
+              kind: 'const';
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89,
+              ];
+            };
+          };
+        },
+        {
+          name: 'tokenProgram';
+        },
+        {
+          name: 'associatedTokenProgram';
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'requestId';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'claimWithdrawalRelayed';
+      docs: [
+        "Claim with a relayer paying the transaction fee and the user's ATA rent,",
+        'so wallets without SOL can withdraw. The user still signs. If the',
+        'request carries a relayer fee it goes to the recorded relayer token',
+        'account; `max_fee` bounds what the user agrees to pay.',
+      ];
+      discriminator: [145, 80, 105, 10, 37, 51, 6, 175];
+      accounts: [
+        {
+          name: 'feePayer';
+          docs: [
+            "Relayer that pays the transaction fee and the user's ATA rent",
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'user';
+          docs: ['User still signs to authorize the claim, but needs no SOL'];
+          signer: true;
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'authority';
+          docs: ['Validated through vault.has_one = authority.'];
+          writable: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'withdrawalRequest';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
+            ];
+          };
+        },
+        {
+          name: 'userLedger';
+          docs: ["User's on-chain ledger"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: ['Mint the request is paid in'];
+        },
+        {
+          name: 'vaultTokenAccount';
+          docs: ["Vault's token account (source)"];
+          writable: true;
+        },
+        {
+          name: 'destination';
+          docs: [
+            '`destination` or the user. Validated against withdrawal_request.',
+          ];
+        },
+        {
+          name: 'destinationTokenAccount';
+          docs: [
+            "Destination's token account, created at the relayer's expense",
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'account';
+                path: 'destination';
+              },
+              {
+                kind: 'account';
+                path: 'tokenProgram';
+              },
+              {
+                kind: 'account';
+                path: 'mint';
+              },
+            ];
+            program: {
+              kind: 'const';
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89,
+              ];
+            };
+          };
+        },
+        {
+          name: 'relayerTokenAccount';
+          docs: [
+            'Fee destination, must match `withdrawal_request.relayer_token_account`.',
+            'Required only when the request carries a relayer fee.',
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'tokenProgram';
+        },
+        {
+          name: 'associatedTokenProgram';
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'requestId';
+          type: 'u64';
+        },
+        {
+          name: 'maxFee';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'closeProposal';
+      docs: [
+        'Close an executed proposal, or one left stale by a signer set change,',
+        'returning its rent to the proposer. Anyone can call.',
+      ];
+      discriminator: [213, 178, 139, 19, 50, 191, 82, 245];
+      accounts: [
+        {
+          name: 'vault';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+          relations: ['proposal'];
+        },
+        {
+          name: 'proposer';
+          docs: ['Validated through proposal.has_one = proposer.'];
+          writable: true;
+          relations: ['proposal'];
+        },
+        {
+          name: 'proposal';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'proposal.proposal_id';
+                account: 'proposal';
+              },
+            ];
+          };
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'commitLiabilities';
+      docs: [
+        'Commit a proof-of-liabilities snapshot. Only authority can call.',
+        'Stores a Merkle root over (user, balance) leaves and their total so',
+        'users can prove their in-game balance is included and backed.',
+        "Token accounts of the vault's other registered mints follow as",
+        'remaining accounts, in registry order; the snapshot records each',
+        "mint's balance and reservations, and their unreserved total.",
+      ];
+      discriminator: [217, 16, 106, 28, 128, 219, 12, 132];
+      accounts: [
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'vaultTokenAccount';
+        },
+        {
+          name: 'liabilitySnapshot';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [108, 105, 97, 98, 105, 108, 105, 116, 105, 101, 115];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'arg';
+                path: 'epoch';
+              },
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'epoch';
+          type: 'u64';
+        },
+        {
+          name: 'merkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'totalLiabilities';
+          type: 'u64';
+        },
+        {
+          name: 'leafCount';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'configureMultisig';
+      docs: [
+        'Set the multisig signer set (N keys, threshold M). Only authority can call.',
+        'Enabling is authority-only; once on, changes need an approved proposal.',
+        'An empty signer set turns multisig mode off.',
+      ];
+      discriminator: [159, 211, 99, 149, 224, 74, 185, 6];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'proposal';
+          docs: [
+            'Approved ConfigureMultisig proposal, required once multisig mode is on',
+          ];
+          writable: true;
+          optional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'signers';
+          type: {
+            vec: 'pubkey';
+          };
+        },
+        {
+          name: 'threshold';
+          type: 'u8';
+        },
+        {
+          name: 'largeWithdrawalThreshold';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'crankCancelExpired';
+      docs: [
+        'Cancel an expired request without the authority. Anyone can call once',
+        '`vault.crank_grace_period` has passed after expiry; the caller earns',
+        '`vault.crank_bounty` lamports and the rest of the rent goes to authority.',
+      ];
+      discriminator: [247, 226, 68, 219, 219, 31, 172, 189];
+      accounts: [
+        {
+          name: 'cranker';
+          docs: [
+            "Anyone; receives `vault.crank_bounty` from the request's rent",
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'authority';
+          writable: true;
+        },
+        {
+          name: 'user';
+        },
+        {
+          name: 'withdrawalRequest';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
+            ];
+          };
+        },
+        {
+          name: 'userLedger';
+          docs: ["User's on-chain ledger"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'requestId';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'createProposal';
+      docs: [
+        'Propose a sensitive action. Only multisig signers can call.',
+        "The proposer's approval is recorded automatically.",
+      ];
+      discriminator: [132, 116, 68, 174, 216, 160, 198, 22];
+      accounts: [
+        {
+          name: 'proposer';
+          docs: ["Member of the vault's multisig signer set"];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'proposal';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [112, 114, 111, 112, 111, 115, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.proposal_count';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'action';
+          type: {
+            defined: {
+              name: 'proposalAction';
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: 'createWithdrawal';
+      docs: [
+        'Create a withdrawal request for a user. Only authority can call.',
+        "User can then claim the request's mint directly by signing with their wallet.",
+        '`request_id` is part of the PDA seeds, so a user may hold several',
+        'pending requests as long as each uses a distinct id.',
+        'In multisig mode, amounts above `large_withdrawal_threshold`',
+        'also consume an approved CreateWithdrawal proposal.',
+        '`destination` is the wallet the user asked to be paid to (e.g. a cold',
+        "wallet or exchange deposit address); None pays the user's own wallet.",
+      ];
+      discriminator: [247, 103, 160, 95, 42, 161, 108, 91];
+      accounts: [
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: [
+            'Registered mint the request is paid in, enabled for new requests',
+          ];
+        },
+        {
+          name: 'vaultTokenAccount';
+        },
+        {
+          name: 'user';
+        },
+        {
+          name: 'withdrawalRequest';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
+            ];
+          };
+        },
+        {
+          name: 'userLedger';
+          docs: ["User's on-chain ledger, created on first request"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+            ];
+          };
+        },
+        {
+          name: 'proposal';
+          docs: [
+            'Approved CreateWithdrawal proposal, required in multisig mode',
+            'for amounts above `vault.large_withdrawal_threshold`',
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'requestId';
+          type: 'u64';
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+        {
+          name: 'expiresIn';
+          type: 'i64';
+        },
+        {
+          name: 'destination';
+          type: {
+            option: 'pubkey';
+          };
+        },
+      ];
+    },
+    {
+      name: 'createWithdrawalsBatch';
+      docs: [
+        'Create up to MAX_WITHDRAWAL_BATCH requests in one transaction.',
+        "Each entry's withdrawal request and user ledger PDAs are passed as",
+        'remaining accounts, in entry order. The whole batch must fit the',
+        'available balance and rate limits, or nothing is created; in',
+        'multisig mode large amounts still go through create_withdrawal.',
+      ];
+      discriminator: [227, 187, 217, 72, 15, 236, 28, 96];
+      accounts: [
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: ['Registered mint every request in the batch is paid in'];
+        },
+        {
+          name: 'vaultTokenAccount';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'withdrawals';
+          type: {
+            vec: {
+              defined: {
+                name: 'batchWithdrawal';
+              };
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: 'deposit';
+      docs: [
+        'Deposit a registered mint into the vault. Only authority can call.',
+      ];
+      discriminator: [242, 35, 198, 137, 82, 225, 242, 182];
+      accounts: [
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: ['Registered mint, enabled for deposits'];
+        },
+        {
+          name: 'authorityTokenAccount';
+          docs: ["Authority's token account (source of deposit)"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'account';
+                path: 'authority';
+              },
+              {
+                kind: 'account';
+                path: 'tokenProgram';
+              },
+              {
+                kind: 'account';
+                path: 'mint';
+              },
+            ];
+            program: {
+              kind: 'const';
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89,
+              ];
+            };
+          };
+        },
+        {
+          name: 'vaultTokenAccount';
+          docs: ["Vault's token account (destination)"];
+          writable: true;
+        },
+        {
+          name: 'tokenProgram';
+        },
+      ];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'extendWithdrawal';
+      docs: [
+        "Push a pending request's deadline to `now + expires_in`. Only authority",
+        'can call. The new deadline must be later than the current one; expired',
+        'requests that were not cancelled yet can be revived this way.',
+      ];
+      discriminator: [138, 101, 28, 175, 225, 252, 70, 62];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'user';
+        },
+        {
+          name: 'withdrawalRequest';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'requestId';
+          type: 'u64';
+        },
+        {
+          name: 'expiresIn';
+          type: 'i64';
+        },
+      ];
+    },
+    {
+      name: 'initialize';
+      docs: [
+        'Initialize a treasury vault. Called once per vault after deploy.',
+        'Sets authority (backend wallet), payout_wallet (payout destination)',
+        'and guardian (emergency pause key). `vault_id` is part of the vault',
+        'PDA, so one authority can run separate vaults (treasury, jackpot',
+        'pool, referral reserve, insurance fund) under different ids.',
+        '`transfer_allowlist` lists the vaults `internal_transfer` may send to.',
+      ];
+      discriminator: [175, 175, 109, 31, 13, 152, 155, 237];
+      accounts: [
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'authority';
+              },
+              {
+                kind: 'arg';
+                path: 'vaultId';
+              },
+            ];
+          };
+        },
+        {
+          name: 'usdtMint';
+          docs: [
+            "USDT SPL mint, the vault's primary mint. Others are added with add_mint.",
+          ];
+        },
+        {
+          name: 'vaultTokenAccount';
+          docs: ["Vault's token account (ATA owned by vault PDA)"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'tokenProgram';
+              },
+              {
+                kind: 'account';
+                path: 'usdtMint';
+              },
+            ];
+            program: {
+              kind: 'const';
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89,
+              ];
+            };
+          };
+        },
+        {
+          name: 'payoutWallet';
+        },
+        {
+          name: 'guardian';
+        },
+        {
+          name: 'tokenProgram';
+        },
+        {
+          name: 'associatedTokenProgram';
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'vaultId';
+          type: 'u16';
+        },
+        {
+          name: 'transferAllowlist';
+          type: {
+            vec: 'pubkey';
+          };
+        },
+      ];
+    },
+    {
+      name: 'internalTransfer';
+      docs: [
+        'Move a registered mint to another vault of this program, e.g. to',
+        'rebalance reserves. Only authority can call, and only to a vault on',
+        'the transfer allowlist. In multisig mode also consumes an approved',
+        'InternalTransfer proposal.',
+      ];
+      discriminator: [56, 217, 60, 137, 252, 221, 185, 114];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          docs: ['Source vault'];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'destinationVault';
+          docs: [
+            "Vault of this program on the source vault's transfer allowlist",
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'destination_vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'destination_vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: [
+            'Mint registered with both vaults, enabled for deposits in the destination',
+          ];
+        },
+        {
+          name: 'vaultTokenAccount';
+          docs: ["Source vault's token account (PDA is authority)"];
+          writable: true;
+        },
+        {
+          name: 'destinationTokenAccount';
+          docs: ["Destination vault's token account for the mint"];
+          writable: true;
+        },
+        {
+          name: 'proposal';
+          docs: [
+            'Approved InternalTransfer proposal, required in multisig mode',
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'tokenProgram';
+        },
+      ];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'pause';
+      docs: [
+        'Pause the vault: blocks deposit, payout and create_withdrawal.',
+        'Pending requests stay claimable. Only guardian can call.',
+      ];
+      discriminator: [211, 22, 221, 251, 74, 121, 193, 47];
+      accounts: [
+        {
+          name: 'guardian';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'payout';
+      docs: [
+        'Payout a registered mint from vault to payout_wallet. Only authority can call.',
+        'In multisig mode also consumes an approved Payout proposal.',
+      ];
+      discriminator: [149, 140, 194, 236, 174, 189, 6, 239];
+      accounts: [
+        {
+          name: 'authority';
+          writable: true;
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: [
+            'Registered mint to pay out (disabled mints can still be drained)',
+          ];
+        },
+        {
+          name: 'vaultTokenAccount';
+          docs: ["Vault's token account (source — PDA is authority)"];
+          writable: true;
+        },
+        {
+          name: 'payoutTokenAccount';
+          docs: ["Payout wallet's token account (destination)"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'account';
+                path: 'payoutWallet';
+              },
+              {
+                kind: 'account';
+                path: 'tokenProgram';
+              },
+              {
+                kind: 'account';
+                path: 'mint';
+              },
+            ];
+            program: {
+              kind: 'const';
+              value: [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89,
+              ];
+            };
+          };
+        },
+        {
+          name: 'payoutWallet';
+          relations: ['vault'];
+        },
+        {
+          name: 'proposal';
+          docs: ['Approved Payout proposal, required in multisig mode'];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: 'tokenProgram';
+        },
+        {
+          name: 'associatedTokenProgram';
+          address: 'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'proposeAuthority';
+      docs: [
+        'Propose a new authority. Only current authority can call.',
+        'Takes effect once the proposed key signs `accept_authority`.',
+      ];
+      discriminator: [20, 148, 236, 198, 76, 119, 99, 142];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'newAuthority';
+          type: 'pubkey';
+        },
+      ];
+    },
+    {
+      name: 'proposeTransferAllowlist';
+      docs: [
+        'Propose a new transfer allowlist. Only authority can call.',
+        'Replaces any pending proposal; applicable after TRANSFER_ALLOWLIST_TIMELOCK.',
+        'In multisig mode also consumes an approved ProposeTransferAllowlist proposal.',
+      ];
+      discriminator: [47, 250, 88, 222, 165, 180, 75, 155];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'proposal';
+          docs: [
+            'Approved ProposeTransferAllowlist proposal, required in multisig mode',
+          ];
+          writable: true;
+          optional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'transferAllowlist';
+          type: {
+            vec: 'pubkey';
+          };
+        },
+      ];
+    },
+    {
+      name: 'setCrankConfig';
+      docs: [
+        'Set the grace period after expiry before anyone may crank a request',
+        'closed, and the lamport bounty they earn for it. Only authority can call.',
+      ];
+      discriminator: [40, 32, 168, 165, 48, 100, 102, 62];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'gracePeriod';
+          type: 'i64';
+        },
+        {
+          name: 'bounty';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'setGuardian';
+      docs: ['Hand the guardian role to another key. Only guardian can call.'];
+      discriminator: [147, 243, 50, 121, 154, 164, 50, 30];
+      accounts: [
+        {
+          name: 'guardian';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'newGuardian';
+          type: 'pubkey';
+        },
+      ];
+    },
+    {
+      name: 'setMintEnabled';
+      docs: [
+        'Enable or disable a registered mint. Only authority can call.',
+        'A disabled mint takes no deposits, withdrawal requests or vouchers;',
+        'pending requests stay claimable and payout can drain the balance.',
+      ];
+      discriminator: [76, 77, 8, 197, 208, 74, 183, 2];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'mint';
+          type: 'pubkey';
+        },
+        {
+          name: 'enabled';
+          type: 'bool';
+        },
+      ];
+    },
+    {
+      name: 'setRateLimits';
+      docs: [
+        'Set payout / withdrawal rate limits (0 = unlimited). Only authority can call.',
+        'In multisig mode also consumes an approved SetRateLimits proposal.',
+      ];
+      discriminator: [184, 201, 116, 157, 138, 90, 113, 119];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'proposal';
+          docs: ['Approved SetRateLimits proposal, required in multisig mode'];
+          writable: true;
+          optional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'maxPayoutPerTx';
+          type: 'u64';
+        },
+        {
+          name: 'maxOutflowPerWindow';
+          type: 'u64';
+        },
+        {
+          name: 'maxWithdrawalAmount';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'setVoucherSigner';
+      docs: [
+        'Set the key that signs claim vouchers (Pubkey::default() disables',
+        'voucher claims). Only authority can call. In multisig mode also',
+        'consumes an approved SetVoucherSigner proposal.',
+      ];
+      discriminator: [92, 110, 33, 43, 16, 221, 48, 25];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'proposal';
+          docs: [
+            'Approved SetVoucherSigner proposal, required in multisig mode',
+          ];
+          writable: true;
+          optional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'newVoucherSigner';
+          type: 'pubkey';
+        },
+      ];
+    },
+    {
+      name: 'setWithdrawalRelayer';
+      docs: [
+        'Record the relayer token account and fee for sponsored claims of a',
+        'pending request. Only authority can call. The fee is capped at',
+        'MAX_RELAYER_FEE and must stay below the request amount.',
+      ];
+      discriminator: [111, 152, 1, 52, 65, 207, 97, 50];
+      accounts: [
+        {
+          name: 'authority';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'user';
+        },
+        {
+          name: 'withdrawalRequest';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'requestId';
+          type: 'u64';
+        },
+        {
+          name: 'relayerTokenAccount';
+          type: {
+            option: 'pubkey';
+          };
+        },
+        {
+          name: 'relayerFee';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'unpause';
+      docs: ['Resume normal operation. Only guardian can call.'];
+      discriminator: [169, 144, 4, 38, 10, 141, 188, 255];
+      accounts: [
+        {
+          name: 'guardian';
+          signer: true;
+          relations: ['vault'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'userCancelWithdrawal';
+      docs: [
+        "Cancel your own pending withdrawal request. Signed by the request's user,",
+        'works at any time. Rent goes back to whoever paid it at creation;',
+        '`reason` is an app-defined code (non-zero) recorded in the event.',
+      ];
+      discriminator: [116, 113, 181, 34, 116, 23, 137, 77];
+      accounts: [
+        {
+          name: 'user';
+          docs: ['User who the request was created for'];
+          signer: true;
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'payer';
+          docs: ['back. Validated through withdrawal_request.has_one = payer.'];
+          writable: true;
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+          relations: ['withdrawalRequest'];
+        },
+        {
+          name: 'withdrawalRequest';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+              {
+                kind: 'arg';
+                path: 'requestId';
+              },
+            ];
+          };
+        },
+        {
+          name: 'userLedger';
+          docs: ["User's on-chain ledger"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'user';
+              },
+            ];
+          };
+        },
+      ];
+      args: [
+        {
+          name: 'requestId';
+          type: 'u64';
+        },
+        {
+          name: 'reason';
+          type: 'u8';
+        },
+      ];
+    },
+    {
+      name: 'userDeposit';
+      docs: [
+        "Deposit a registered mint from any wallet's own token account into the vault.",
+        '`user_ref` identifies the app user to credit (see UserDepositEvent).',
+      ];
+      discriminator: [186, 198, 140, 233, 129, 39, 98, 153];
+      accounts: [
+        {
+          name: 'depositor';
+          docs: ['Any wallet depositing its own USDT'];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: 'vault';
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                ];
+              },
+              {
+                kind: 'account';
+                path: 'vault.seed_key';
+                account: 'treasuryVault';
+              },
+              {
+                kind: 'account';
+                path: 'vault.vault_id';
+                account: 'treasuryVault';
+              },
+            ];
+          };
+        },
+        {
+          name: 'mint';
+          docs: ['Registered mint, enabled for deposits'];
+        },
+        {
+          name: 'depositorTokenAccount';
+          docs: ["Depositor's token account (source of deposit)"];
+          writable: true;
+        },
+        {
+          name: 'vaultTokenAccount';
+          docs: ["Vault's token account (destination)"];
+          writable: true;
+        },
+        {
+          name: 'userLedger';
+          docs: ["Depositor's on-chain ledger, created on first deposit"];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: 'const';
+                value: [117, 115, 101, 114, 95, 108, 101, 100, 103, 101, 114];
+              },
+              {
+                kind: 'account';
+                path: 'vault';
+              },
+              {
+                kind: 'account';
+                path: 'depositor';
+              },
+            ];
+          };
+        },
+        {
+          name: 'tokenProgram';
+        },
+        {
+          name: 'systemProgram';
+          address: '11111111111111111111111111111111';
+        },
+      ];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+        {
+          name: 'userRef';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+      ];
+    },
+  ];
+  accounts: [
+    {
+      name: 'liabilitySnapshot';
+      discriminator: [67, 56, 28, 7, 10, 161, 240, 105];
+    },
+    {
+      name: 'proposal';
+      discriminator: [26, 94, 189, 187, 116, 136, 53, 33];
+    },
+    {
+      name: 'treasuryVault';
+      discriminator: [86, 102, 19, 109, 56, 58, 144, 81];
+    },
+    {
+      name: 'userLedger';
+      discriminator: [185, 84, 101, 128, 8, 6, 160, 83];
+    },
+    {
+      name: 'voucherNonces';
+      discriminator: [111, 214, 241, 58, 134, 38, 200, 252];
+    },
+    {
+      name: 'withdrawalRequest';
+      discriminator: [242, 88, 147, 173, 182, 62, 229, 193];
+    },
+  ];
+  events: [
+    {
+      name: 'authorityProposedEvent';
+      discriminator: [221, 27, 73, 198, 252, 169, 231, 224];
+    },
+    {
+      name: 'authorityTransferredEvent';
+      discriminator: [54, 31, 118, 237, 152, 5, 201, 11];
+    },
+    {
+      name: 'crankBountyPaidEvent';
+      discriminator: [69, 10, 236, 49, 50, 180, 152, 71];
+    },
+    {
+      name: 'crankConfigUpdatedEvent';
+      discriminator: [56, 63, 62, 185, 255, 234, 99, 231];
+    },
+    {
+      name: 'depositEvent';
+      discriminator: [120, 248, 61, 83, 31, 142, 107, 144];
+    },
+    {
+      name: 'guardianChangedEvent';
+      discriminator: [173, 106, 62, 253, 243, 46, 79, 126];
+    },
+    {
+      name: 'internalTransferEvent';
+      discriminator: [115, 190, 181, 50, 156, 247, 159, 186];
+    },
+    {
+      name: 'liabilitiesCommittedEvent';
+      discriminator: [101, 154, 230, 163, 31, 31, 151, 187];
+    },
+    {
+      name: 'mintAddedEvent';
+      discriminator: [57, 251, 51, 192, 194, 14, 7, 146];
+    },
+    {
+      name: 'mintStatusChangedEvent';
+      discriminator: [123, 32, 139, 151, 1, 229, 221, 229];
+    },
+    {
+      name: 'multisigConfiguredEvent';
+      discriminator: [58, 96, 185, 119, 212, 62, 196, 29];
+    },
+    {
+      name: 'payoutEvent';
+      discriminator: [84, 234, 195, 72, 143, 79, 70, 82];
+    },
+    {
+      name: 'proposalApprovedEvent';
+      discriminator: [238, 183, 55, 8, 87, 232, 170, 248];
+    },
+    {
+      name: 'proposalCreatedEvent';
+      discriminator: [154, 240, 33, 66, 194, 233, 203, 209];
+    },
+    {
+      name: 'proposalExecutedEvent';
+      discriminator: [120, 242, 13, 36, 223, 3, 110, 180];
+    },
+    {
+      name: 'rateLimitsUpdatedEvent';
+      discriminator: [141, 44, 42, 39, 55, 199, 120, 50];
+    },
+    {
+      name: 'relayerFeePaidEvent';
+      discriminator: [180, 127, 203, 150, 113, 124, 141, 233];
+    },
+    {
+      name: 'transferAllowlistCancelledEvent';
+      discriminator: [84, 153, 125, 108, 42, 56, 239, 178];
+    },
+    {
+      name: 'transferAllowlistChangedEvent';
+      discriminator: [94, 176, 220, 107, 220, 101, 196, 86];
+    },
+    {
+      name: 'transferAllowlistProposedEvent';
+      discriminator: [250, 253, 249, 13, 180, 10, 241, 120];
+    },
+    {
+      name: 'userDepositEvent';
+      discriminator: [70, 55, 110, 118, 235, 187, 239, 33];
+    },
+    {
+      name: 'vaultInitialized';
+      discriminator: [180, 43, 207, 2, 18, 71, 3, 75];
+    },
+    {
+      name: 'vaultPausedEvent';
+      discriminator: [75, 189, 120, 167, 117, 229, 155, 60];
+    },
+    {
+      name: 'vaultUnpausedEvent';
+      discriminator: [131, 193, 72, 96, 27, 110, 75, 199];
+    },
+    {
+      name: 'voucherClaimedEvent';
+      discriminator: [145, 14, 189, 52, 210, 129, 10, 211];
+    },
+    {
+      name: 'voucherSignerChangedEvent';
+      discriminator: [146, 71, 66, 191, 232, 180, 202, 109];
+    },
+    {
+      name: 'withdrawalAmendedEvent';
+      discriminator: [200, 239, 123, 203, 250, 216, 251, 137];
+    },
+    {
+      name: 'withdrawalCancelledEvent';
+      discriminator: [40, 218, 165, 230, 31, 49, 238, 127];
+    },
+    {
+      name: 'withdrawalClaimedEvent';
+      discriminator: [112, 246, 33, 174, 230, 205, 121, 26];
+    },
+    {
+      name: 'withdrawalCreatedEvent';
+      discriminator: [173, 205, 34, 150, 97, 42, 211, 30];
+    },
+    {
+      name: 'withdrawalExtendedEvent';
+      discriminator: [24, 17, 32, 20, 253, 88, 28, 187];
+    },
+    {
+      name: 'withdrawalRelayerSetEvent';
+      discriminator: [80, 248, 86, 189, 192, 82, 182, 160];
+    },
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: 'unauthorized';
+      msg: 'Unauthorized: only vault authority can perform this action';
+    },
+    {
+      code: 6001;
+      name: 'invalidMint';
+      msg: 'Invalid USDT mint address';
+    },
+    {
+      code: 6002;
+      name: 'invalidVaultAccount';
+      msg: 'Invalid vault token account';
+    },
+    {
+      code: 6003;
+      name: 'invalidPayoutWallet';
+      msg: 'Invalid payout wallet';
+    },
+    {
+      code: 6004;
+      name: 'zeroAmount';
+      msg: 'Amount must be greater than zero';
+    },
+    {
+      code: 6005;
+      name: 'insufficientBalance';
+      msg: 'Insufficient vault balance for payout';
+    },
+    {
+      code: 6006;
+      name: 'overflow';
+      msg: 'Arithmetic overflow';
+    },
+    {
+      code: 6007;
+      name: 'withdrawalExpired';
+      msg: 'Withdrawal request has expired';
+    },
+    {
+      code: 6008;
+      name: 'withdrawalNotExpired';
+      msg: 'Withdrawal has not expired yet, cannot cancel';
+    },
+    {
+      code: 6009;
+      name: 'invalidExpiration';
+      msg: 'Expiration duration must be positive';
+    },
+    {
+      code: 6010;
+      name: 'invalidNewAuthority';
+      msg: 'New authority must be a different, non-default key and match the pending proposal';
+    },
+    {
+      code: 6011;
+      name: 'multisigRequired';
+      msg: 'Multisig mode is on: this action needs an approved proposal';
+    },
+    {
+      code: 6012;
+      name: 'notMultisigSigner';
+      msg: "Signer is not in the vault's multisig signer set";
+    },
+    {
+      code: 6013;
+      name: 'invalidMultisigConfig';
+      msg: 'Invalid multisig signer set or threshold';
+    },
+    {
+      code: 6014;
+      name: 'invalidProposal';
+      msg: 'Proposal does not match this vault or action';
+    },
+    {
+      code: 6015;
+      name: 'staleProposal';
+      msg: 'Proposal was created for a previous multisig signer set';
+    },
+    {
+      code: 6016;
+      name: 'proposalAlreadyExecuted';
+      msg: 'Proposal has already been executed';
+    },
+    {
+      code: 6017;
+      name: 'proposalNotApproved';
+      msg: 'Proposal has not reached the approval threshold';
+    },
+    {
+      code: 6018;
+      name: 'alreadyApproved';
+      msg: 'Signer has already approved this proposal';
+    },
+    {
+      code: 6019;
+      name: 'rateLimitExceeded';
+      msg: "Payout or withdrawal exceeds the vault's rate limits";
+    },
+    {
+      code: 6020;
+      name: 'vaultPaused';
+      msg: 'Vault is paused by the guardian';
+    },
+    {
+      code: 6021;
+      name: 'notGuardian';
+      msg: 'Unauthorized: only the vault guardian can perform this action';
+    },
+    {
+      code: 6022;
+      name: 'invalidGuardian';
+      msg: 'Guardian must be a non-default key different from the authority and any pending authority';
+    },
+    {
+      code: 6023;
+      name: 'invalidEpoch';
+      msg: 'Liability snapshot epoch must be greater than the last committed epoch';
+    },
+    {
+      code: 6024;
+      name: 'amountNotReduced';
+      msg: 'Amended amount must be lower than the current request amount';
+    },
+    {
+      code: 6025;
+      name: 'invalidCancelReason';
+      msg: 'Cancel reason code 0 is reserved for expired requests';
+    },
+    {
+      code: 6026;
+      name: 'relayerFeeTooHigh';
+      msg: "Relayer fee exceeds the cap, the request amount or the user's max_fee";
+    },
+    {
+      code: 6027;
+      name: 'invalidRelayerAccount';
+      msg: 'Relayer token account does not match the withdrawal request';
+    },
+    {
+      code: 6028;
+      name: 'invalidDestination';
+      msg: 'Destination does not match the withdrawal request';
+    },
+    {
+      code: 6029;
+      name: 'vouchersDisabled';
+      msg: 'Voucher claims are disabled for this vault';
+    },
+    {
+      code: 6030;
+      name: 'invalidVoucher';
+      msg: "Voucher is not signed by the vault's voucher signer";
+    },
+    {
+      code: 6031;
+      name: 'voucherAlreadyUsed';
+      msg: 'Voucher nonce was already used';
+    },
+    {
+      code: 6032;
+      name: 'invalidBatchSize';
+      msg: 'Batch must hold between 1 and MAX_WITHDRAWAL_BATCH entries';
+    },
+    {
+      code: 6033;
+      name: 'invalidBatchAccounts';
+      msg: 'Remaining accounts do not match the batch entries';
+    },
+    {
+      code: 6034;
+      name: 'crankTooEarly';
+      msg: 'Expired request is still within the crank grace period';
+    },
+    {
+      code: 6035;
+      name: 'invalidCrankConfig';
+      msg: 'Crank grace period must be non-negative and bounty at most MAX_CRANK_BOUNTY';
+    },
+    {
+      code: 6036;
+      name: 'mintDisabled';
+      msg: 'Mint is disabled for deposits and new withdrawals';
+    },
+    {
+      code: 6037;
+      name: 'mintRegistryFull';
+      msg: 'Mint registry is full';
+    },
+    {
+      code: 6038;
+      name: 'mintAlreadyRegistered';
+      msg: 'Mint is already registered with this vault';
+    },
+    {
+      code: 6039;
+      name: 'invalidTransferAllowlist';
+      msg: 'Transfer allowlist is too long or holds a duplicate, default or the vault itself';
+    },
+    {
+      code: 6040;
+      name: 'transferNotAllowed';
+      msg: 'Destination vault is not on the transfer allowlist';
+    },
+    {
+      code: 6041;
+      name: 'noPendingTransferAllowlist';
+      msg: 'No transfer allowlist change is pending';
+    },
+    {
+      code: 6042;
+      name: 'transferAllowlistTimelocked';
+      msg: 'Transfer allowlist timelock has not elapsed';
+    },
+    {
+      code: 6043;
+      name: 'invalidPayer';
+      msg: 'Rent must be returned to the account that paid it';
+    },
+    {
+      code: 6044;
+      name: 'proposalStillActive';
+      msg: 'Proposal can still be approved or executed';
+    },
+  ];
+  types: [
+    {
+      name: 'authorityProposedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'authority';
+            type: 'pubkey';
+          },
+          {
+            name: 'pendingAuthority';
+            type: 'pubkey';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'authorityTransferredEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'previousAuthority';
+            type: 'pubkey';
+          },
+          {
+            name: 'newAuthority';
+            type: 'pubkey';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'batchWithdrawal';
+      docs: [
+        'One request created by `create_withdrawals_batch`, same meaning as the',
+        '`create_withdrawal` arguments',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'user';
+            type: 'pubkey';
+          },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'expiresIn';
+            type: 'i64';
+          },
+          {
+            name: 'destination';
+            type: {
+              option: 'pubkey';
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'crankBountyPaidEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'user';
+            type: 'pubkey';
+          },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
+          {
+            name: 'cranker';
+            type: 'pubkey';
+          },
+          {
+            name: 'bounty';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'crankConfigUpdatedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'gracePeriod';
+            type: 'i64';
+          },
+          {
+            name: 'bounty';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'depositEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'totalDeposited';
+            type: 'u64';
+          },
+          {
+            name: 'depositCount';
+            type: 'u32';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'guardianChangedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'previousGuardian';
+            type: 'pubkey';
+          },
+          {
+            name: 'newGuardian';
+            type: 'pubkey';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'internalTransferEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'destinationVault';
+            type: 'pubkey';
+          },
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'totalPaidOut';
+            type: 'u64';
+          },
+          {
+            name: 'destinationTotalDeposited';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'liabilitiesCommittedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'epoch';
+            type: 'u64';
+          },
+          {
+            name: 'merkleRoot';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'totalLiabilities';
+            type: 'u64';
+          },
+          {
+            name: 'leafCount';
+            type: 'u64';
+          },
+          {
+            name: 'vaultBalance';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'liabilitySnapshot';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            docs: ['Which vault this snapshot covers'];
+            type: 'pubkey';
+          },
+          {
+            name: 'epoch';
+            docs: [
+              'Snapshot epoch, strictly increasing per vault, part of the PDA seeds',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'merkleRoot';
+            docs: [
+              'Merkle-sum root over (user, balance) leaves of in-game balances, also',
+              'committing to `total_liabilities` and `leaf_count` (see treasury-vault-proof)',
+            ];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'totalLiabilities';
+            docs: ['Sum of all leaf balances (raw units, 6 decimals)'];
+            type: 'u64';
+          },
+          {
+            name: 'leafCount';
+            docs: ['Number of leaves in the tree'];
+            type: 'u64';
+          },
+          {
+            name: 'vaultBalance';
+            docs: [
+              'Vault token balance across all registered mints at commit time, less',
+              'what is reserved for pending withdrawal requests, for a quick',
+              'solvency comparison',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'mintBalances';
+            docs: [
+              'Balance and reservations of each registered mint, in registry order,',
+              'so a verifier can leave out a mint it does not accept as backing',
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: 'mintBalance';
+                };
+              };
+            };
+          },
+          {
+            name: 'committedAt';
+            docs: ['Unix timestamp when snapshot was committed'];
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            docs: ['PDA bump seed'];
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'mintAddedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
+          {
+            name: 'tokenAccount';
+            type: 'pubkey';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'mintBalance';
+      docs: ["One mint's share of a LiabilitySnapshot"];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
+          {
+            name: 'balance';
+            docs: ['Vault token balance in `mint` at commit time (raw units)'];
+            type: 'u64';
+          },
+          {
+            name: 'reserved';
+            docs: [
+              'Part of `balance` reserved for pending withdrawal requests',
+            ];
+            type: 'u64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'mintStatusChangedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
+          {
+            name: 'enabled';
+            type: 'bool';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'multisigConfiguredEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'signers';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'threshold';
+            type: 'u8';
+          },
+          {
+            name: 'largeWithdrawalThreshold';
+            type: 'u64';
+          },
+          {
+            name: 'multisigVersion';
+            type: 'u32';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'payoutEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
+          {
+            name: 'payoutWallet';
+            type: 'pubkey';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'totalPaidOut';
+            type: 'u64';
+          },
+          {
+            name: 'payoutCount';
+            type: 'u32';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'proposal';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            docs: ['Which vault this proposal belongs to'];
+            type: 'pubkey';
+          },
+          {
+            name: 'proposalId';
+            docs: ['Sequential id, part of the PDA seeds'];
+            type: 'u64';
+          },
+          {
+            name: 'proposer';
+            docs: ['Signer that created the proposal'];
+            type: 'pubkey';
+          },
+          {
+            name: 'action';
+            docs: [
+              'Action that becomes executable once the threshold is reached',
+            ];
+            type: {
+              defined: {
+                name: 'proposalAction';
+              };
+            };
+          },
+          {
+            name: 'multisigVersion';
+            docs: ['`multisig_version` of the vault at creation time'];
+            type: 'u32';
+          },
+          {
+            name: 'approvals';
+            docs: ['Bitmask of approvals, bit i = vault.multisig_signers[i]'];
+            type: 'u16';
+          },
+          {
+            name: 'approvalCount';
+            docs: ['Number of set bits in `approvals`'];
+            type: 'u8';
+          },
+          {
+            name: 'executed';
+            docs: [
+              'Set once the action has been executed; a proposal runs at most once',
+            ];
+            type: 'bool';
+          },
+          {
+            name: 'createdAt';
+            docs: ['Unix timestamp when proposal was created'];
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            docs: ['PDA bump seed'];
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'proposalAction';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'payout';
+            fields: [
+              {
+                name: 'mint';
+                type: 'pubkey';
+              },
+              {
+                name: 'amount';
+                type: 'u64';
+              },
+            ];
+          },
+          {
+            name: 'createWithdrawal';
+            fields: [
+              {
+                name: 'mint';
+                type: 'pubkey';
+              },
+              {
+                name: 'user';
+                type: 'pubkey';
+              },
+              {
+                name: 'requestId';
+                type: 'u64';
+              },
+              {
+                name: 'amount';
+                type: 'u64';
+              },
+              {
+                name: 'expiresIn';
+                type: 'i64';
+              },
+              {
+                name: 'destination';
+                type: {
+                  option: 'pubkey';
+                };
+              },
+            ];
+          },
+          {
+            name: 'configureMultisig';
+            fields: [
+              {
+                name: 'signers';
+                type: {
+                  vec: 'pubkey';
+                };
+              },
+              {
+                name: 'threshold';
+                type: 'u8';
+              },
+              {
+                name: 'largeWithdrawalThreshold';
+                type: 'u64';
+              },
+            ];
+          },
+          {
+            name: 'setRateLimits';
+            fields: [
+              {
+                name: 'maxPayoutPerTx';
+                type: 'u64';
+              },
+              {
+                name: 'maxOutflowPerWindow';
+                type: 'u64';
+              },
+              {
+                name: 'maxWithdrawalAmount';
+                type: 'u64';
+              },
+            ];
+          },
+          {
+            name: 'internalTransfer';
+            fields: [
+              {
+                name: 'mint';
+                type: 'pubkey';
+              },
+              {
+                name: 'destinationVault';
+                type: 'pubkey';
+              },
+              {
+                name: 'amount';
+                type: 'u64';
+              },
+            ];
+          },
+          {
+            name: 'setVoucherSigner';
+            fields: [
+              {
+                name: 'voucherSigner';
+                type: 'pubkey';
+              },
+            ];
+          },
+          {
+            name: 'proposeTransferAllowlist';
+            fields: [
+              {
+                name: 'transferAllowlist';
+                type: {
+                  vec: 'pubkey';
+                };
+              },
+            ];
+          },
+          {
+            name: 'applyTransferAllowlist';
+            fields: [
+              {
+                name: 'transferAllowlist';
+                type: {
+                  vec: 'pubkey';
+                };
+              },
+            ];
+          },
+          {
+            name: 'addMint';
+            fields: [
+              {
+                name: 'mint';
+                type: 'pubkey';
+              },
+            ];
+          },
+        ];
+      };
+    },
+    {
+      name: 'proposalApprovedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposalId';
+            type: 'u64';
+          },
+          {
+            name: 'signer';
+            type: 'pubkey';
+          },
+          {
+            name: 'approvalCount';
+            type: 'u8';
+          },
+          {
+            name: 'threshold';
+            type: 'u8';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
     },
     {
-      code: 6004;
-      name: 'zeroAmount';
-      msg: 'Amount must be greater than zero';
+      name: 'proposalCreatedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposalId';
+            type: 'u64';
+          },
+          {
+            name: 'proposer';
+            type: 'pubkey';
+          },
+          {
+            name: 'action';
+            type: {
+              defined: {
+                name: 'proposalAction';
+              };
+            };
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
     },
     {
-      code: 6005;
-      name: 'insufficientBalance';
-      msg: 'Insufficient vault balance for payout';
+      name: 'proposalExecutedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposal';
+            type: 'pubkey';
+          },
+          {
+            name: 'proposalId';
+            type: 'u64';
+          },
+          {
+            name: 'approvalCount';
+            type: 'u8';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'rateLimitsUpdatedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'maxPayoutPerTx';
+            type: 'u64';
+          },
+          {
+            name: 'maxOutflowPerWindow';
+            type: 'u64';
+          },
+          {
+            name: 'maxWithdrawalAmount';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'relayerFeePaidEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'user';
+            type: 'pubkey';
+          },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
+          {
+            name: 'feePayer';
+            type: 'pubkey';
+          },
+          {
+            name: 'relayerTokenAccount';
+            type: 'pubkey';
+          },
+          {
+            name: 'fee';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'transferAllowlistCancelledEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'cancelledBy';
+            type: 'pubkey';
+          },
+          {
+            name: 'cancelledTransferAllowlist';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'transferAllowlistChangedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'previousTransferAllowlist';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'transferAllowlist';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'transferAllowlistProposedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'pendingTransferAllowlist';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'effectiveAt';
+            type: 'i64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'treasuryVault';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'authority';
+            docs: [
+              'Authority (backend wallet) — only this key can call deposit/payout',
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'seedKey';
+            docs: [
+              'Key used in the vault PDA seeds (the authority at initialize).',
+              'Never changes, so rotating `authority` keeps the vault address.',
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'vaultId';
+            docs: [
+              'Second PDA seed (little-endian), chosen at initialize, so one',
+              '`seed_key` can own several vaults: treasury, jackpot pool, reserves',
+            ];
+            type: 'u16';
+          },
+          {
+            name: 'pendingAuthority';
+            docs: [
+              'Authority proposed via `propose_authority`, waiting for `accept_authority`',
+            ];
+            type: {
+              option: 'pubkey';
+            };
+          },
+          {
+            name: 'guardian';
+            docs: ['Guardian — separate key that can pause/unpause the vault'];
+            type: 'pubkey';
+          },
+          {
+            name: 'paused';
+            docs: [
+              'When set, deposit/payout/create_withdrawal are blocked.',
+              'Claims of already-created requests keep working.',
+            ];
+            type: 'bool';
+          },
+          {
+            name: 'payoutWallet';
+            docs: [
+              'Payout wallet — the only allowed recipient of payout instructions',
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'usdtMint';
+            docs: ['USDT SPL mint address, the primary mint (`mints[0]`)'];
+            type: 'pubkey';
+          },
+          {
+            name: 'vaultTokenAccount';
+            docs: [
+              "Vault's token account for `usdt_mint` (ATA owned by this PDA)",
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'mintDecimals';
+            docs: [
+              'Decimals shared by every registered mint, so amounts, totals and',
+              'rate limits are in the same raw units whatever the mint',
+            ];
+            type: 'u8';
+          },
+          {
+            name: 'mints';
+            docs: [
+              'Registry of mints the vault custodies, each with its own token',
+              'account and totals. The primary mint is registered at initialize.',
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: 'vaultMint';
+                };
+              };
+            };
+          },
+          {
+            name: 'totalDeposited';
+            docs: ['Total deposited across all mints (raw units, 6 decimals)'];
+            type: 'u64';
+          },
+          {
+            name: 'totalPaidOut';
+            docs: ['Total paid out across all mints (raw units, 6 decimals)'];
+            type: 'u64';
+          },
+          {
+            name: 'totalReserved';
+            docs: [
+              'Reserved for pending withdrawal requests across all mints (raw units,',
+              "6 decimals). Each mint's share is covered by that mint's balance.",
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'depositCount';
+            docs: ['Number of deposit transactions (u32 = up to 4B ops)'];
+            type: 'u32';
+          },
+          {
+            name: 'payoutCount';
+            docs: ['Number of payout transactions (u32 = up to 4B ops)'];
+            type: 'u32';
+          },
+          {
+            name: 'lastDepositAt';
+            docs: ['Last deposit unix timestamp'];
+            type: 'i64';
+          },
+          {
+            name: 'lastPayoutAt';
+            docs: ['Last payout unix timestamp'];
+            type: 'i64';
+          },
+          {
+            name: 'multisigSigners';
+            docs: [
+              'Multisig signer set. Empty = multisig mode off, authority acts alone',
+            ];
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'multisigThreshold';
+            docs: ['Approvals (M) required to execute a proposal'];
+            type: 'u8';
+          },
+          {
+            name: 'multisigVersion';
+            docs: [
+              'Bumped on every signer set change; invalidates older proposals',
+            ];
+            type: 'u32';
+          },
+          {
+            name: 'largeWithdrawalThreshold';
+            docs: [
+              'In multisig mode, withdrawal requests above this amount need a proposal',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'proposalCount';
+            docs: [
+              'Number of proposals created (used as the next proposal id)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'maxPayoutPerTx';
+            docs: ['Max amount of a single payout (0 = unlimited)'];
+            type: 'u64';
+          },
+          {
+            name: 'maxOutflowPerWindow';
+            docs: [
+              'Max payout + withdrawal-request volume per rolling 24h window (0 = unlimited)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'maxWithdrawalAmount';
+            docs: ['Max amount of a single withdrawal request (0 = unlimited)'];
+            type: 'u64';
+          },
+          {
+            name: 'outflowBuckets';
+            docs: [
+              'Outflow per hour, indexed by `hour % OUTFLOW_BUCKETS`. Only the',
+              'buckets for `outflow_hour` and the hours before it are live; the rest',
+              'are cleared as time moves on.',
+            ];
+            type: {
+              array: ['u64', 25];
+            };
+          },
+          {
+            name: 'outflowHour';
+            docs: [
+              'Hour (unix timestamp / OUTFLOW_BUCKET_SECONDS) of the newest bucket',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'liabilityEpoch';
+            docs: [
+              'Epoch of the latest LiabilitySnapshot (0 = none committed yet)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'voucherSigner';
+            docs: [
+              'Backend key whose Ed25519 vouchers `claim_with_voucher` accepts.',
+              'Pubkey::default() = voucher claims disabled.',
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'crankGracePeriod';
+            docs: [
+              'Seconds after `expires_at` before `crank_cancel_expired` is open to anyone',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'crankBounty';
+            docs: [
+              "Lamports from a cranked request's rent paid to the cranker",
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'transferAllowlist';
+            docs: [
+              'Vaults of this program that `internal_transfer` may send to. Set at',
+              'initialize, then changed only through the timelocked pending list.',
+            ];
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'pendingTransferAllowlist';
+            docs: ['Allowlist proposed by `propose_transfer_allowlist`'];
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'transferAllowlistEffectiveAt';
+            docs: [
+              'When the pending allowlist may be applied (0 = no change pending)',
+            ];
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            docs: ['PDA bump seed'];
+            type: 'u8';
+          },
+        ];
+      };
     },
     {
-      code: 6006;
-      name: 'overflow';
-      msg: 'Arithmetic overflow';
+      name: 'userDepositEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
+          {
+            name: 'depositor';
+            type: 'pubkey';
+          },
+          {
+            name: 'userRef';
+            docs: [
+              'Opaque app-side user id (e.g. UUID bytes) the deposit is credited to',
+            ];
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'totalDeposited';
+            type: 'u64';
+          },
+          {
+            name: 'depositCount';
+            type: 'u32';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
     },
     {
-      code: 6007;
-      name: 'withdrawalExpired';
-      msg: 'Withdrawal request has expired';
+      name: 'userLedger';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            docs: ['Which vault this ledger tracks'];
+            type: 'pubkey';
+          },
+          {
+            name: 'user';
+            docs: ['Wallet whose activity is recorded'];
+            type: 'pubkey';
+          },
+          {
+            name: 'totalDeposited';
+            docs: [
+              'Lifetime USDT deposited via user_deposit (raw units, 6 decimals)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'totalClaimed';
+            docs: [
+              'Lifetime USDT claimed from withdrawal requests (raw units, 6 decimals)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'pendingAmount';
+            docs: [
+              'USDT in currently pending withdrawal requests (raw units, 6 decimals)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'pendingCount';
+            docs: ['Number of currently pending withdrawal requests'];
+            type: 'u32';
+          },
+          {
+            name: 'depositCount';
+            docs: ['Number of user_deposit transactions'];
+            type: 'u32';
+          },
+          {
+            name: 'claimCount';
+            docs: ['Number of claimed withdrawal requests'];
+            type: 'u32';
+          },
+          {
+            name: 'cancelCount';
+            docs: ['Number of cancelled withdrawal requests'];
+            type: 'u32';
+          },
+          {
+            name: 'lastDepositAt';
+            docs: ['Last deposit unix timestamp'];
+            type: 'i64';
+          },
+          {
+            name: 'lastClaimAt';
+            docs: ['Last claim unix timestamp'];
+            type: 'i64';
+          },
+          {
+            name: 'lastActivityAt';
+            docs: ['Unix timestamp of the last change to this ledger'];
+            type: 'i64';
+          },
+          {
+            name: 'bump';
+            docs: ['PDA bump seed'];
+            type: 'u8';
+          },
+        ];
+      };
     },
     {
-      code: 6008;
-      name: 'withdrawalNotExpired';
-      msg: 'Withdrawal has not expired yet, cannot cancel';
+      name: 'vaultInitialized';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'vaultId';
+            type: 'u16';
+          },
+          {
+            name: 'authority';
+            type: 'pubkey';
+          },
+          {
+            name: 'payoutWallet';
+            type: 'pubkey';
+          },
+          {
+            name: 'guardian';
+            type: 'pubkey';
+          },
+          {
+            name: 'usdtMint';
+            type: 'pubkey';
+          },
+          {
+            name: 'transferAllowlist';
+            type: {
+              vec: 'pubkey';
+            };
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
     },
-  ];
-  types: [
     {
-      name: 'depositEvent';
+      name: 'vaultMint';
+      docs: ["One mint in a vault's registry"];
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'vault';
+            name: 'mint';
             type: 'pubkey';
           },
           {
-            name: 'amount';
-            type: 'u64';
+            name: 'tokenAccount';
+            docs: [
+              "Vault's token account for `mint` (ATA owned by the vault PDA)",
+            ];
+            type: 'pubkey';
+          },
+          {
+            name: 'enabled';
+            docs: [
+              'Disabled mints take no deposits, withdrawal requests or vouchers.',
+              'Pending requests still claim and payout can still drain the balance.',
+            ];
+            type: 'bool';
           },
           {
             name: 'totalDeposited';
+            docs: ['Total deposited in this mint (raw units)'];
+            type: 'u64';
+          },
+          {
+            name: 'totalPaidOut';
+            docs: ['Total paid out in this mint (raw units)'];
+            type: 'u64';
+          },
+          {
+            name: 'totalReserved';
+            docs: [
+              'Reserved in this mint for pending withdrawal requests (raw units)',
+            ];
             type: 'u64';
           },
           {
@@ -813,14 +5261,14 @@ export type TreasuryVault = {
             type: 'u32';
           },
           {
-            name: 'timestamp';
-            type: 'i64';
+            name: 'payoutCount';
+            type: 'u32';
           },
         ];
       };
     },
     {
-      name: 'payoutEvent';
+      name: 'vaultPausedEvent';
       type: {
         kind: 'struct';
         fields: [
@@ -829,20 +5277,28 @@ export type TreasuryVault = {
             type: 'pubkey';
           },
           {
-            name: 'payoutWallet';
+            name: 'guardian';
             type: 'pubkey';
           },
           {
-            name: 'amount';
-            type: 'u64';
+            name: 'timestamp';
+            type: 'i64';
           },
+        ];
+      };
+    },
+    {
+      name: 'vaultUnpausedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
           {
-            name: 'totalPaidOut';
-            type: 'u64';
+            name: 'vault';
+            type: 'pubkey';
           },
           {
-            name: 'payoutCount';
-            type: 'u32';
+            name: 'guardian';
+            type: 'pubkey';
           },
           {
             name: 'timestamp';
@@ -852,63 +5308,79 @@ export type TreasuryVault = {
       };
     },
     {
-      name: 'treasuryVault';
+      name: 'voucherClaimedEvent';
       type: {
         kind: 'struct';
         fields: [
           {
-            name: 'authority';
-            docs: [
-              'Authority (backend wallet) — only this key can call deposit/payout',
-            ];
+            name: 'vault';
             type: 'pubkey';
           },
           {
-            name: 'payoutWallet';
-            docs: [
-              'Payout wallet — the only allowed recipient of payout instructions',
-            ];
+            name: 'mint';
             type: 'pubkey';
           },
           {
-            name: 'usdtMint';
-            docs: ['USDT SPL mint address'];
+            name: 'user';
             type: 'pubkey';
           },
           {
-            name: 'vaultTokenAccount';
-            docs: ["Vault's token account (ATA owned by this PDA)"];
-            type: 'pubkey';
+            name: 'nonce';
+            type: 'u64';
           },
           {
-            name: 'totalDeposited';
-            docs: ['Total USDT deposited (raw units, 6 decimals)'];
+            name: 'amount';
             type: 'u64';
           },
           {
-            name: 'totalPaidOut';
-            docs: ['Total USDT paid out (raw units, 6 decimals)'];
-            type: 'u64';
+            name: 'expiresAt';
+            type: 'i64';
           },
           {
-            name: 'depositCount';
-            docs: ['Number of deposit transactions (u32 = up to 4B ops)'];
-            type: 'u32';
+            name: 'totalPaidOut';
+            type: 'u64';
           },
           {
             name: 'payoutCount';
-            docs: ['Number of payout transactions (u32 = up to 4B ops)'];
             type: 'u32';
           },
           {
-            name: 'lastDepositAt';
-            docs: ['Last deposit unix timestamp'];
+            name: 'timestamp';
             type: 'i64';
           },
+        ];
+      };
+    },
+    {
+      name: 'voucherNonces';
+      docs: [
+        "Used-nonce bitmap for one user's vouchers, covering nonces",
+        '`page * VOUCHER_NONCES_PER_PAGE ..` the next VOUCHER_NONCES_PER_PAGE',
+      ];
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
           {
-            name: 'lastPayoutAt';
-            docs: ['Last payout unix timestamp'];
-            type: 'i64';
+            name: 'user';
+            type: 'pubkey';
+          },
+          {
+            name: 'page';
+            docs: ['nonce / VOUCHER_NONCES_PER_PAGE'];
+            type: 'u64';
+          },
+          {
+            name: 'bitmap';
+            docs: [
+              'Bit `nonce % VOUCHER_NONCES_PER_PAGE` is set once that nonce is spent',
+            ];
+            type: {
+              array: ['u8', 128];
+            };
           },
           {
             name: 'bump';
@@ -919,7 +5391,7 @@ export type TreasuryVault = {
       };
     },
     {
-      name: 'vaultInitialized';
+      name: 'voucherSignerChangedEvent';
       type: {
         kind: 'struct';
         fields: [
@@ -928,17 +5400,49 @@ export type TreasuryVault = {
             type: 'pubkey';
           },
           {
-            name: 'authority';
+            name: 'previousVoucherSigner';
             type: 'pubkey';
           },
           {
-            name: 'payoutWallet';
+            name: 'newVoucherSigner';
             type: 'pubkey';
           },
           {
-            name: 'usdtMint';
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'withdrawalAmendedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'user';
             type: 'pubkey';
           },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
+          {
+            name: 'previousAmount';
+            type: 'u64';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'totalReserved';
+            type: 'u64';
+          },
           {
             name: 'timestamp';
             type: 'i64';
@@ -959,10 +5463,26 @@ export type TreasuryVault = {
             name: 'user';
             type: 'pubkey';
           },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
           {
             name: 'amount';
             type: 'u64';
           },
+          {
+            name: 'totalReserved';
+            type: 'u64';
+          },
+          {
+            name: 'reason';
+            docs: [
+              '`CANCEL_REASON_EXPIRED` for authority cleanups, otherwise the',
+              'app-defined code the user passed to `user_cancel_withdrawal`',
+            ];
+            type: 'u8';
+          },
           {
             name: 'timestamp';
             type: 'i64';
@@ -979,10 +5499,23 @@ export type TreasuryVault = {
             name: 'vault';
             type: 'pubkey';
           },
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
           {
             name: 'user';
             type: 'pubkey';
           },
+          {
+            name: 'destination';
+            docs: ['Owner of the token account that received the funds'];
+            type: 'pubkey';
+          },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
           {
             name: 'amount';
             type: 'u64';
@@ -991,6 +5524,10 @@ export type TreasuryVault = {
             name: 'totalPaidOut';
             type: 'u64';
           },
+          {
+            name: 'totalReserved';
+            type: 'u64';
+          },
           {
             name: 'payoutCount';
             type: 'u32';
@@ -1011,10 +5548,24 @@ export type TreasuryVault = {
             name: 'vault';
             type: 'pubkey';
           },
+          {
+            name: 'mint';
+            type: 'pubkey';
+          },
           {
             name: 'user';
             type: 'pubkey';
           },
+          {
+            name: 'destination';
+            type: {
+              option: 'pubkey';
+            };
+          },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
           {
             name: 'amount';
             type: 'u64';
@@ -1023,6 +5574,76 @@ export type TreasuryVault = {
             name: 'expiresAt';
             type: 'i64';
           },
+          {
+            name: 'totalReserved';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'withdrawalExtendedEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'user';
+            type: 'pubkey';
+          },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
+          {
+            name: 'previousExpiresAt';
+            type: 'i64';
+          },
+          {
+            name: 'expiresAt';
+            type: 'i64';
+          },
+          {
+            name: 'timestamp';
+            type: 'i64';
+          },
+        ];
+      };
+    },
+    {
+      name: 'withdrawalRelayerSetEvent';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'vault';
+            type: 'pubkey';
+          },
+          {
+            name: 'user';
+            type: 'pubkey';
+          },
+          {
+            name: 'requestId';
+            type: 'u64';
+          },
+          {
+            name: 'relayerTokenAccount';
+            type: {
+              option: 'pubkey';
+            };
+          },
+          {
+            name: 'relayerFee';
+            type: 'u64';
+          },
           {
             name: 'timestamp';
             type: 'i64';
@@ -1045,6 +5666,14 @@ export type TreasuryVault = {
             docs: ['User who can claim this withdrawal'];
             type: 'pubkey';
           },
+          {
+            name: 'requestId';
+            docs: [
+              'Backend-assigned request ID, part of the PDA seeds so a user can',
+              'hold several pending withdrawals at once',
+            ];
+            type: 'u64';
+          },
           {
             name: 'amount';
             docs: ['Amount in raw USDT units (6 decimals)'];
@@ -1060,6 +5689,46 @@ export type TreasuryVault = {
             docs: ['Unix timestamp after which claim is no longer possible'];
             type: 'i64';
           },
+          {
+            name: 'mint';
+            docs: ['Registered mint the claim is paid in'];
+            type: 'pubkey';
+          },
+          {
+            name: 'destination';
+            docs: [
+              'Wallet whose token account receives the claim, approved by the user',
+              "when the request was created. None = the user's own wallet.",
+            ];
+            type: {
+              option: 'pubkey';
+            };
+          },
+          {
+            name: 'relayerTokenAccount';
+            docs: [
+              'Token account that receives `relayer_fee` when the claim is sponsored',
+              'through `claim_withdrawal_relayed`. None = sponsored claims are free.',
+            ];
+            type: {
+              option: 'pubkey';
+            };
+          },
+          {
+            name: 'relayerFee';
+            docs: [
+              'USDT deducted from the claim for the relayer (raw units, <= MAX_RELAYER_FEE)',
+            ];
+            type: 'u64';
+          },
+          {
+            name: 'payer';
+            docs: [
+              "Account that paid the request's rent at creation; a user cancel",
+              'returns the rent here',
+            ];
+            type: 'pubkey';
+          },
           {
             name: 'bump';
             docs: ['PDA bump seed'];
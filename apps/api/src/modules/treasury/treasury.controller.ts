@@ -1,4 +1,5 @@
 import { Controller, Get, Param } from '@nestjs/common';
+import { BN } from '@coral-xyz/anchor';
 import { TreasuryService } from './treasury.service';
 import type {
   VaultInfoResponseDto,
@@ -22,11 +23,15 @@ export class TreasuryController {
     return this.treasuryService.getClaimInfo();
   }
 
-  /** GET /treasury/withdrawal-request/:userPubkey — public, check active PDA */
-  @Get('withdrawal-request/:userPubkey')
+  /** GET /treasury/withdrawal-request/:userPubkey/:requestId — public, check active PDA */
+  @Get('withdrawal-request/:userPubkey/:requestId')
   async getWithdrawalRequest(
     @Param('userPubkey') userPubkey: string,
+    @Param('requestId') requestId: string,
   ): Promise<WithdrawalRequestResponseDto | null> {
-    return this.treasuryService.getWithdrawalRequest(userPubkey);
+    return this.treasuryService.getWithdrawalRequest(
+      userPubkey,
+      new BN(requestId),
+    );
   }
 }
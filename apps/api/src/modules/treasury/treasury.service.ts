@@ -6,6 +6,7 @@ import {
 } from '@nestjs/common';
 import { ConfigService } from '@nestjs/config';
 import { Cron } from '@nestjs/schedule';
+import { createHash } from 'crypto';
 import { AnchorProvider, BN, Program, Wallet } from '@coral-xyz/anchor';
 import { Keypair, PublicKey } from '@solana/web3.js';
 import {
@@ -18,11 +19,16 @@ import { SOLANA_TOKENS } from '../deposits/constants/tokens';
 import { TREASURY_VAULT_IDL } from './idl/treasury_vault';
 import { VaultInfoResponseDto } from './dto';
 
+// WithdrawalCancelledEvent reason for cancels the user starts from the app
+// (0 is reserved for expired requests cleaned up by the authority)
+const USER_CANCEL_REASON = 1;
+
 @Injectable()
 export class TreasuryService implements OnModuleInit {
   private readonly logger = new Logger(TreasuryService.name);
   private program: Program | null = null;
   private vaultPda: PublicKey | null = null;
+  private vaultId = 0;
   private vaultTokenAccount: PublicKey | null = null;
   private authorityKeypair: Keypair | null = null;
   private enabled = false;
@@ -67,11 +73,19 @@ export class TreasuryService implements OnModuleInit {
 
       this.program = new Program(TREASURY_VAULT_IDL, provider);
 
-      // Derive vault PDA
+      // Derive vault PDA: ["treasury_vault", seed_key, vault_id (u16 LE)].
+      // seed_key is the authority that ran initialize and never changes, so
+      // after an authority rotation TREASURY_VAULT_SEED_KEY keeps the address.
+      const seedKeyAddress = this.config.get<string>('TREASURY_VAULT_SEED_KEY');
+      const seedKey = seedKeyAddress
+        ? new PublicKey(seedKeyAddress)
+        : this.authorityKeypair.publicKey;
+      this.vaultId = Number(this.config.get<string>('TREASURY_VAULT_ID') || 0);
       [this.vaultPda] = PublicKey.findProgramAddressSync(
         [
           Buffer.from('treasury_vault'),
-          this.authorityKeypair.publicKey.toBuffer(),
+          seedKey.toBuffer(),
+          new BN(this.vaultId).toArrayLike(Buffer, 'le', 2),
         ],
         programId,
       );
@@ -87,7 +101,7 @@ export class TreasuryService implements OnModuleInit {
 
       this.enabled = true;
       this.logger.log(
-        `Treasury initialized: vault=${this.vaultPda.toBase58()}, programId=${programId.toBase58()}`,
+        `Treasury initialized: vault=${this.vaultPda.toBase58()} (id ${this.vaultId}), programId=${programId.toBase58()}`,
       );
     } catch (error) {
       this.logger.error('Failed to initialize treasury:', error);
@@ -115,7 +129,8 @@ export class TreasuryService implements OnModuleInit {
       const txSignature = await this.program!.methods.deposit(amountRaw)
         .accounts({
           authority: this.authorityKeypair!.publicKey,
-          usdtMint,
+          vault: this.vaultPda!,
+          mint: usdtMint,
           vaultTokenAccount: this.vaultTokenAccount!,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
@@ -154,9 +169,11 @@ export class TreasuryService implements OnModuleInit {
       const txSignature = await this.program!.methods.payout(amountRaw)
         .accounts({
           authority: this.authorityKeypair!.publicKey,
-          usdtMint,
+          vault: this.vaultPda!,
+          mint: usdtMint,
           vaultTokenAccount: this.vaultTokenAccount!,
           payoutWallet,
+          proposal: null,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc();
@@ -202,6 +219,7 @@ export class TreasuryService implements OnModuleInit {
 
       return {
         vaultAddress: this.vaultPda!.toBase58(),
+        vaultId: vault.vaultId,
         authority: vault.authority.toBase58(),
         payoutWallet: vault.payoutWallet.toBase58(),
         usdtMint: vault.usdtMint.toBase58(),
@@ -222,12 +240,22 @@ export class TreasuryService implements OnModuleInit {
 
   // ─── Withdrawal Requests ────────────────────────────────
 
+  /**
+   * On-chain request id (u64) for a withdrawal: the first 8 bytes of
+   * SHA-256 of its database id, so it can be re-derived without storing it.
+   */
+  requestIdFor(withdrawalId: string): BN {
+    const digest = createHash('sha256').update(withdrawalId).digest();
+    return new BN(digest.subarray(0, 8), 'le');
+  }
+
   /**
    * Create on-chain withdrawal request PDA for a user.
    * User can then claim USDT by signing with their wallet.
    */
   async createWithdrawalRequest(
     userPubkey: string,
+    requestId: BN,
     amountUsd: number,
     expiresInSeconds = 3600,
   ): Promise<string> {
@@ -242,20 +270,25 @@ export class TreasuryService implements OnModuleInit {
     const user = new PublicKey(userPubkey);
 
     try {
+      // No destination: the user's own token account receives the claim
       const txSignature = await this.program!.methods.createWithdrawal(
+        requestId,
         amountRaw,
         new BN(expiresInSeconds),
+        null,
       )
         .accounts({
           authority: this.authorityKeypair!.publicKey,
-          usdtMint,
+          vault: this.vaultPda!,
+          mint: usdtMint,
           vaultTokenAccount: this.vaultTokenAccount!,
           user,
+          proposal: null,
         })
         .rpc();
 
       this.logger.log(
-        `Created withdrawal request: $${amountUsd} USDT for ${userPubkey}, tx: ${txSignature}`,
+        `Created withdrawal request ${requestId.toString()}: $${amountUsd} USDT for ${userPubkey}, tx: ${txSignature}`,
       );
       return txSignature;
     } catch (error) {
@@ -273,15 +306,21 @@ export class TreasuryService implements OnModuleInit {
    * Cancel an expired withdrawal request.
    * Returns rent to authority.
    */
-  async cancelWithdrawalRequest(userPubkey: string): Promise<string> {
+  async cancelWithdrawalRequest(
+    userPubkey: string,
+    requestId: BN,
+  ): Promise<string> {
     this.ensureEnabled();
 
     const user = new PublicKey(userPubkey);
 
     try {
-      const txSignature = await this.program!.methods.cancelWithdrawal()
+      const txSignature = await this.program!.methods.cancelWithdrawal(
+        requestId,
+      )
         .accounts({
           authority: this.authorityKeypair!.publicKey,
+          vault: this.vaultPda!,
           user,
         })
         .rpc();
@@ -296,25 +335,107 @@ export class TreasuryService implements OnModuleInit {
     }
   }
 
+  /**
+   * Build an unsigned user_cancel_withdrawal transaction for the user to sign.
+   * The user pays the fee; rent goes back to the request's recorded payer.
+   * Returns the serialized transaction, base64.
+   */
+  async buildUserCancelTransaction(
+    userPubkey: string,
+    requestId: BN,
+    payer: string,
+  ): Promise<string> {
+    this.ensureEnabled();
+
+    const user = new PublicKey(userPubkey);
+    const transaction = await this.program!.methods.userCancelWithdrawal(
+      requestId,
+      USER_CANCEL_REASON,
+    )
+      .accounts({
+        user,
+        payer: new PublicKey(payer),
+        vault: this.vaultPda!,
+      })
+      .transaction();
+
+    const connection = this.solanaRpc.getConnection();
+    transaction.feePayer = user;
+    transaction.recentBlockhash = (
+      await connection.getLatestBlockhash()
+    ).blockhash;
+
+    return transaction
+      .serialize({ requireAllSignatures: false, verifySignatures: false })
+      .toString('base64');
+  }
+
+  /**
+   * Whether `txSignature` is a successful user_cancel_withdrawal of the
+   * user's request. A closed request PDA may also have been claimed, so the
+   * balance is only rolled back against the cancel itself.
+   */
+  async isUserCancelTransaction(
+    userPubkey: string,
+    requestId: BN,
+    txSignature: string,
+  ): Promise<boolean> {
+    this.ensureEnabled();
+
+    const tx = await this.solanaRpc.getTransaction(txSignature);
+    if (!tx || tx.meta?.err) {
+      return false;
+    }
+
+    const programId = this.program!.programId;
+    const withdrawalPda = this.withdrawalRequestAddress(
+      new PublicKey(userPubkey),
+      requestId,
+    );
+    const discriminator = Buffer.from(
+      TREASURY_VAULT_IDL.instructions.find(
+        (ix: { name: string }) => ix.name === 'user_cancel_withdrawal',
+      ).discriminator,
+    );
+    const message = tx.transaction.message;
+    const keys = message.getAccountKeys({
+      accountKeysFromLookups: tx.meta?.loadedAddresses,
+    });
+
+    return message.compiledInstructions.some(
+      (ix) =>
+        keys.get(ix.programIdIndex)?.equals(programId) &&
+        Buffer.from(ix.data).subarray(0, 8).equals(discriminator) &&
+        ix.accountKeyIndexes.some((index) =>
+          keys.get(index)?.equals(withdrawalPda),
+        ),
+    );
+  }
+
   /**
    * Read on-chain withdrawal request PDA state for a user.
    * Returns null if no active request exists.
    */
-  async getWithdrawalRequest(userPubkey: string): Promise<{
+  async getWithdrawalRequest(
+    userPubkey: string,
+    requestId: BN,
+  ): Promise<{
     vault: string;
     user: string;
+    requestId: string;
     amount: number;
     createdAt: string;
     expiresAt: string;
     pdaAddress: string;
+    userLedgerPda: string;
+    payer: string;
   } | null> {
     this.ensureEnabled();
 
     const user = new PublicKey(userPubkey);
-
-    // Derive withdrawal request PDA
-    const [withdrawalPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('withdrawal'), this.vaultPda!.toBuffer(), user.toBuffer()],
+    const withdrawalPda = this.withdrawalRequestAddress(user, requestId);
+    const [userLedgerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('user_ledger'), this.vaultPda!.toBuffer(), user.toBuffer()],
       this.program!.programId,
     );
 
@@ -329,10 +450,13 @@ export class TreasuryService implements OnModuleInit {
       return {
         vault: request.vault.toBase58(),
         user: request.user.toBase58(),
+        requestId: request.requestId.toString(),
         amount: Number(request.amount) / divisor,
         createdAt: new Date(Number(request.createdAt) * 1000).toISOString(),
         expiresAt: new Date(Number(request.expiresAt) * 1000).toISOString(),
         pdaAddress: withdrawalPda.toBase58(),
+        userLedgerPda: userLedgerPda.toBase58(),
+        payer: request.payer.toBase58(),
       };
     } catch {
       // Account doesn't exist — no active withdrawal request
@@ -400,6 +524,19 @@ export class TreasuryService implements OnModuleInit {
     }
   }
 
+  /** Withdrawal request PDA: ["withdrawal", vault, user, request_id (u64 LE)] */
+  private withdrawalRequestAddress(user: PublicKey, requestId: BN): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('withdrawal'),
+        this.vaultPda!.toBuffer(),
+        user.toBuffer(),
+        requestId.toArrayLike(Buffer, 'le', 8),
+      ],
+      this.program!.programId,
+    )[0];
+  }
+
   private getUsdtMint(): PublicKey {
     return new PublicKey(
       this.config.get<string>('USDT_MINT') || SOLANA_TOKENS.USDT.mint,
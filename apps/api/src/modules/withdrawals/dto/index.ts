@@ -1,4 +1,11 @@
-import { IsNumber, IsString, IsEnum, Min, Max } from 'class-validator';
+import {
+  IsNumber,
+  IsString,
+  IsEnum,
+  IsOptional,
+  Min,
+  Max,
+} from 'class-validator';
 
 // ============== Enums ==============
 
@@ -44,6 +51,14 @@ export class ConfirmAtomicWithdrawalDto {
   txSignature: string;
 }
 
+export class CancelWithdrawalDto {
+  // Signature of the user_cancel_withdrawal transaction returned by the
+  // first cancel call, once the user has signed and sent it
+  @IsOptional()
+  @IsString()
+  txSignature?: string;
+}
+
 // ============== Response DTOs ==============
 
 export interface WithdrawalPreviewResponse {
@@ -67,6 +82,9 @@ export interface PreparedAtomicWithdrawalResponse {
     usdtMint: string;
     vaultTokenAccount: string;
     withdrawalRequestPda: string;
+    userLedgerPda: string;
+    // u64 request id as a decimal string (claim_withdrawal argument)
+    requestId: string;
   };
   // Amounts for display
   requestedAmount: number;
@@ -92,6 +110,12 @@ export interface WithdrawalResponse {
   processedAt: string | null;
 }
 
+export interface CancelWithdrawalResponse extends WithdrawalResponse {
+  // Unsigned user_cancel_withdrawal transaction (base64) while the on-chain
+  // claim is active: sign and send it, then cancel again with its signature
+  cancelTransaction?: string;
+}
+
 export interface InstantWithdrawalResponse {
   id: string;
   status: string;
@@ -112,7 +112,11 @@ describe('WithdrawalsController', () => {
         withdrawalsService.cancelAtomicWithdrawal as jest.Mock
       ).mockResolvedValue(mockResult);
 
-      const result = await controller.cancelAtomicWithdrawal(mockUser, 'wd-1');
+      const result = await controller.cancelAtomicWithdrawal(
+        mockUser,
+        'wd-1',
+        {},
+      );
 
       expect(result.status).toBe('cancelled');
     });
@@ -17,10 +17,12 @@ import {
   CreateWithdrawalDto,
   PrepareAtomicWithdrawalDto,
   ConfirmAtomicWithdrawalDto,
+  CancelWithdrawalDto,
   WithdrawalMethodDto,
   WithdrawalPreviewResponse,
   PreparedAtomicWithdrawalResponse,
   WithdrawalResponse,
+  CancelWithdrawalResponse,
   InstantWithdrawalResponse,
 } from './dto';
 
@@ -76,16 +78,19 @@ export class WithdrawalsController {
 
   /**
    * POST /withdrawals/cancel/:id
-   * Cancel pending atomic withdrawal.
+   * Cancel pending atomic withdrawal. While the on-chain claim is active this
+   * returns an unsigned cancel transaction; call again with its signature.
    */
   @Post('cancel/:id')
   async cancelAtomicWithdrawal(
     @CurrentUser() user: JwtPayload,
     @Param('id') withdrawalId: string,
-  ): Promise<WithdrawalResponse> {
+    @Body() dto: CancelWithdrawalDto,
+  ): Promise<CancelWithdrawalResponse> {
     return this.withdrawalsService.cancelAtomicWithdrawal(
       user.sub,
       withdrawalId,
+      dto.txSignature,
     );
   }
 
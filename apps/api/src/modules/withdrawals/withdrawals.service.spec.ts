@@ -13,6 +13,7 @@ describe('WithdrawalsService', () => {
   let prismaService: jest.Mocked<PrismaService>;
   let fundSourceService: jest.Mocked<FundSourceService>;
   let solanaRpcService: jest.Mocked<SolanaRpcService>;
+  let treasuryService: jest.Mocked<TreasuryService>;
 
   const mockUserId = 'user-123';
   const mockWithdrawalId = 'withdrawal-456';
@@ -116,6 +117,11 @@ describe('WithdrawalsService', () => {
           useValue: {
             getHotWalletPublicKey: jest.fn(),
             transferFromTreasury: jest.fn(),
+            isEnabled: jest.fn().mockReturnValue(false),
+            requestIdFor: jest.fn(),
+            getWithdrawalRequest: jest.fn(),
+            buildUserCancelTransaction: jest.fn(),
+            isUserCancelTransaction: jest.fn(),
           },
         },
       ],
@@ -125,6 +131,7 @@ describe('WithdrawalsService', () => {
     prismaService = module.get(PrismaService);
     fundSourceService = module.get(FundSourceService);
     solanaRpcService = module.get(SolanaRpcService);
+    treasuryService = module.get(TreasuryService);
 
     jest.clearAllMocks();
   });
@@ -452,6 +459,114 @@ describe('WithdrawalsService', () => {
         data: { status: 'cancelled' },
       });
     });
+
+    describe('with an active on-chain claim', () => {
+      const onChainRequest = {
+        vault: 'vault',
+        user: 'So11111111111111111111111111111111111111112',
+        requestId: '7',
+        amount: 82.5,
+        createdAt: new Date().toISOString(),
+        expiresAt: new Date(Date.now() + 60_000).toISOString(),
+        pdaAddress: 'request-pda',
+        userLedgerPda: 'ledger-pda',
+        payer: 'authority',
+      };
+
+      beforeEach(() => {
+        (treasuryService.isEnabled as jest.Mock).mockReturnValue(true);
+        (treasuryService.requestIdFor as jest.Mock).mockReturnValue(7);
+        (prismaService.withdrawal.findUnique as jest.Mock).mockResolvedValue(
+          createMockWithdrawal(),
+        );
+        (prismaService.$transaction as jest.Mock).mockImplementation((cb) =>
+          cb(prismaService),
+        );
+      });
+
+      it('should return an unsigned user cancel transaction and keep the balance', async () => {
+        (
+          treasuryService.getWithdrawalRequest as jest.Mock
+        ).mockResolvedValue(onChainRequest);
+        (
+          treasuryService.buildUserCancelTransaction as jest.Mock
+        ).mockResolvedValue('base64-tx');
+
+        const result = await service.cancelAtomicWithdrawal(
+          mockUserId,
+          mockWithdrawalId,
+        );
+
+        expect(result.status).toBe('pending');
+        expect(result.cancelTransaction).toBe('base64-tx');
+        expect(treasuryService.buildUserCancelTransaction).toHaveBeenCalledWith(
+          onChainRequest.user,
+          7,
+          'authority',
+        );
+        expect(prismaService.user.update).not.toHaveBeenCalled();
+        expect(prismaService.withdrawal.update).not.toHaveBeenCalled();
+      });
+
+      it('should rollback once the user cancel has closed the request', async () => {
+        (
+          treasuryService.getWithdrawalRequest as jest.Mock
+        ).mockResolvedValue(null);
+        (
+          treasuryService.isUserCancelTransaction as jest.Mock
+        ).mockResolvedValue(true);
+        (prismaService.withdrawal.update as jest.Mock).mockResolvedValue(
+          createMockWithdrawal({ status: 'cancelled' }),
+        );
+
+        const result = await service.cancelAtomicWithdrawal(
+          mockUserId,
+          mockWithdrawalId,
+          'cancel-sig',
+        );
+
+        expect(result.status).toBe('cancelled');
+        expect(treasuryService.isUserCancelTransaction).toHaveBeenCalledWith(
+          onChainRequest.user,
+          7,
+          'cancel-sig',
+        );
+        expect(prismaService.user.update).toHaveBeenCalled();
+      });
+
+      it('should not rollback against a transaction that did not cancel', async () => {
+        (
+          treasuryService.getWithdrawalRequest as jest.Mock
+        ).mockResolvedValue(null);
+        (
+          treasuryService.isUserCancelTransaction as jest.Mock
+        ).mockResolvedValue(false);
+
+        await expect(
+          service.cancelAtomicWithdrawal(
+            mockUserId,
+            mockWithdrawalId,
+            'claim-sig',
+          ),
+        ).rejects.toThrow('Transaction did not cancel this withdrawal request');
+        expect(prismaService.user.update).not.toHaveBeenCalled();
+      });
+
+      it('should refuse a signature while the request is still open', async () => {
+        (
+          treasuryService.getWithdrawalRequest as jest.Mock
+        ).mockResolvedValue(onChainRequest);
+
+        await expect(
+          service.cancelAtomicWithdrawal(
+            mockUserId,
+            mockWithdrawalId,
+            'cancel-sig',
+          ),
+        ).rejects.toThrow(BadRequestException);
+        expect(prismaService.user.update).not.toHaveBeenCalled();
+      });
+    });
   });
 
   describe('getUserWithdrawals', () => {
@@ -18,6 +18,7 @@ import {
   WithdrawalPreviewResponse,
   PreparedAtomicWithdrawalResponse,
   WithdrawalResponse,
+  CancelWithdrawalResponse,
   InstantWithdrawalResponse,
 } from './dto';
 import { Withdrawal } from '@prisma/client';
@@ -173,9 +174,11 @@ export class WithdrawalsService {
     });
 
     // Create on-chain WithdrawalRequest PDA
+    const requestId = this.treasury.requestIdFor(withdrawal.id);
     try {
       await this.treasury.createWithdrawalRequest(
         userWalletAddress,
+        requestId,
         preview.usdtAmount,
         WITHDRAWAL_EXPIRY_SECONDS,
       );
@@ -198,7 +201,10 @@ export class WithdrawalsService {
 
     // Get claim info for frontend
     const claimInfo = this.treasury.getClaimInfo();
-    const request = await this.treasury.getWithdrawalRequest(userWalletAddress);
+    const request = await this.treasury.getWithdrawalRequest(
+      userWalletAddress,
+      requestId,
+    );
 
     const expiresAt = request
       ? request.expiresAt
@@ -213,6 +219,8 @@ export class WithdrawalsService {
       claimInfo: {
         ...claimInfo,
         withdrawalRequestPda: request?.pdaAddress || '',
+        userLedgerPda: request?.userLedgerPda || '',
+        requestId: requestId.toString(),
       },
       requestedAmount: amount,
       netAmount: preview.netAmount,
@@ -301,12 +309,16 @@ export class WithdrawalsService {
 
   /**
    * Cancel pending atomic withdrawal.
-   * For wallet_connect: only allowed after on-chain PDA expires (prevents double-spend).
+   * For wallet_connect with an active on-chain claim the user cancels it
+   * themselves: the first call returns an unsigned user_cancel_withdrawal
+   * transaction, and once it lands a second call with its signature rolls
+   * back the balance. Expired requests are cancelled by the authority.
    */
   async cancelAtomicWithdrawal(
     userId: string,
     withdrawalId: string,
-  ): Promise<WithdrawalResponse> {
+    txSignature?: string,
+  ): Promise<CancelWithdrawalResponse> {
     const withdrawal = await this.prisma.withdrawal.findUnique({
       where: { id: withdrawalId },
     });
@@ -323,23 +335,40 @@ export class WithdrawalsService {
       throw new BadRequestException('Withdrawal already processed');
     }
 
-    // For wallet_connect: must cancel on-chain PDA first (requires expiry)
     if (withdrawal.method === 'wallet_connect' && this.treasury.isEnabled()) {
+      const requestId = this.treasury.requestIdFor(withdrawal.id);
       const request = await this.treasury.getWithdrawalRequest(
         withdrawal.walletAddress,
+        requestId,
       );
 
-      if (request) {
-        const expiresAt = new Date(request.expiresAt).getTime();
-        if (Date.now() < expiresAt) {
+      if (request && Date.now() < new Date(request.expiresAt).getTime()) {
+        if (txSignature) {
           throw new BadRequestException(
-            `Cannot cancel: on-chain claim is active until ${request.expiresAt}. Claim or wait for auto-expiry.`,
+            'Cancel transaction has not closed the on-chain request yet',
           );
         }
 
+        // Active claim: only the user's own signature can close it
+        const cancelTransaction =
+          await this.treasury.buildUserCancelTransaction(
+            withdrawal.walletAddress,
+            requestId,
+            request.payer,
+          );
+        return {
+          ...this.mapWithdrawalToResponse(withdrawal),
+          cancelTransaction,
+        };
+      }
+
+      if (request) {
         // PDA expired — cancel on-chain (returns rent to authority)
         try {
-          await this.treasury.cancelWithdrawalRequest(withdrawal.walletAddress);
+          await this.treasury.cancelWithdrawalRequest(
+            withdrawal.walletAddress,
+            requestId,
+          );
         } catch (error) {
           this.logger.error(
             `On-chain cancel failed for ${withdrawal.walletAddress}:`,
@@ -349,6 +378,18 @@ export class WithdrawalsService {
             'Failed to cancel on-chain withdrawal request',
           );
         }
+      } else if (txSignature) {
+        // PDA closed — roll back only if it was the user's cancel, not a claim
+        const cancelled = await this.treasury.isUserCancelTransaction(
+          withdrawal.walletAddress,
+          requestId,
+          txSignature,
+        );
+        if (!cancelled) {
+          throw new BadRequestException(
+            'Transaction did not cancel this withdrawal request',
+          );
+        }
       }
       // PDA doesn't exist — already cancelled by cron or never created
     }
@@ -605,14 +646,17 @@ export class WithdrawalsService {
     for (const withdrawal of expired) {
       try {
         // Try to cancel on-chain PDA if it still exists
+        const requestId = this.treasury.requestIdFor(withdrawal.id);
         const request = await this.treasury.getWithdrawalRequest(
           withdrawal.walletAddress,
+          requestId,
         );
 
         if (request) {
           try {
             await this.treasury.cancelWithdrawalRequest(
               withdrawal.walletAddress,
+              requestId,
             );
             this.logger.log(
               `Cancelled on-chain PDA for withdrawal ${withdrawal.id}`,
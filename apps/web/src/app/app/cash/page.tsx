@@ -53,7 +53,7 @@ const FORTUNE_MINT = process.env.NEXT_PUBLIC_FORTUNE_MINT_ADDRESS;
 const CLAIM_WITHDRAWAL_DISCRIMINATOR = Buffer.from([118, 206, 173, 38, 239, 165, 65, 30]);
 
 /**
- * Build claim_withdrawal TransactionInstruction.
+ * Build claim_withdrawal(request_id) TransactionInstruction.
  * User signs → USDT from vault → user ATA, PDA closed.
  */
 async function buildClaimWithdrawalInstruction(
@@ -66,6 +66,7 @@ async function buildClaimWithdrawalInstruction(
   const usdtMint = new PublicKey(claimInfo.usdtMint);
   const vaultTokenAccount = new PublicKey(claimInfo.vaultTokenAccount);
   const withdrawalRequestPda = new PublicKey(claimInfo.withdrawalRequestPda);
+  const userLedgerPda = new PublicKey(claimInfo.userLedgerPda);
 
   // Derive user's ATA for USDT
   const userTokenAccount = await getAssociatedTokenAddress(
@@ -75,6 +76,11 @@ async function buildClaimWithdrawalInstruction(
     TOKEN_PROGRAM_ID,
   );
 
+  // Discriminator followed by request_id (u64 LE)
+  const data = Buffer.alloc(16);
+  CLAIM_WITHDRAWAL_DISCRIMINATOR.copy(data);
+  data.writeBigUInt64LE(BigInt(claimInfo.requestId), 8);
+
   return new TransactionInstruction({
     programId,
     keys: [
@@ -82,14 +88,17 @@ async function buildClaimWithdrawalInstruction(
       { pubkey: authority, isSigner: false, isWritable: true },
       { pubkey: vault, isSigner: false, isWritable: true },
       { pubkey: withdrawalRequestPda, isSigner: false, isWritable: true },
+      { pubkey: userLedgerPda, isSigner: false, isWritable: true },
       { pubkey: usdtMint, isSigner: false, isWritable: false },
       { pubkey: vaultTokenAccount, isSigner: false, isWritable: true },
+      // Destination: the user, as requests are created without one
+      { pubkey: userPubkey, isSigner: false, isWritable: false },
       { pubkey: userTokenAccount, isSigner: false, isWritable: true },
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
-    data: CLAIM_WITHDRAWAL_DISCRIMINATOR,
+    data,
   });
 }
 
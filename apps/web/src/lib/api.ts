@@ -512,16 +512,23 @@ class ApiClient {
   }
 
   /**
-   * Cancel pending atomic withdrawal
+   * Cancel pending atomic withdrawal.
+   * While the on-chain claim is active the response carries an unsigned
+   * cancel transaction; call again with its signature once it is sent.
    */
   async cancelAtomicWithdrawal(
     token: string,
     withdrawalId: string,
-  ): Promise<WithdrawalData> {
-    return this.request<WithdrawalData>(`/withdrawals/cancel/${withdrawalId}`, {
-      method: 'POST',
-      token,
-    });
+    txSignature?: string,
+  ): Promise<CancelWithdrawalData> {
+    return this.request<CancelWithdrawalData>(
+      `/withdrawals/cancel/${withdrawalId}`,
+      {
+        method: 'POST',
+        token,
+        body: JSON.stringify({ txSignature }),
+      },
+    );
   }
 
   /**
@@ -1556,6 +1563,9 @@ export interface ClaimInfo {
   usdtMint: string;
   vaultTokenAccount: string;
   withdrawalRequestPda: string;
+  userLedgerPda: string;
+  /** u64 request id as a decimal string */
+  requestId: string;
 }
 
 export interface PreparedAtomicWithdrawalData {
@@ -1582,6 +1592,11 @@ export interface WithdrawalData {
   processedAt: string | null;
 }
 
+export interface CancelWithdrawalData extends WithdrawalData {
+  // Unsigned user_cancel_withdrawal transaction (base64) for the user to sign
+  cancelTransaction?: string;
+}
+
 export interface InstantWithdrawalData {
   id: string;
   status: string;
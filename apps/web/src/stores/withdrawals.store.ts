@@ -1,6 +1,7 @@
 'use client';
 
 import { create } from 'zustand';
+import { Transaction } from '@solana/web3.js';
 import {
   api,
   WithdrawalData,
@@ -38,7 +39,11 @@ interface WithdrawalsState {
     withdrawalId: string,
     txSignature: string,
   ) => Promise<WithdrawalData>;
-  cancelAtomicWithdrawal: (token: string, withdrawalId: string) => Promise<void>;
+  cancelAtomicWithdrawal: (
+    token: string,
+    withdrawalId: string,
+    signAndSend: (transaction: Transaction) => Promise<string>,
+  ) => Promise<void>;
   createInstantWithdrawal: (
     token: string,
     amount: number,
@@ -129,10 +134,19 @@ export const useWithdrawalsStore = create<WithdrawalsState>()((set) => ({
     }
   },
 
-  cancelAtomicWithdrawal: async (token, withdrawalId) => {
+  cancelAtomicWithdrawal: async (token, withdrawalId, signAndSend) => {
     set({ isLoading: true, error: null });
     try {
-      await api.cancelAtomicWithdrawal(token, withdrawalId);
+      const result = await api.cancelAtomicWithdrawal(token, withdrawalId);
+      // Active on-chain claim: the user signs the cancel, then the API
+      // returns the balance once the request is closed
+      if (result.cancelTransaction) {
+        const transaction = Transaction.from(
+          Buffer.from(result.cancelTransaction, 'base64'),
+        );
+        const signature = await signAndSend(transaction);
+        await api.cancelAtomicWithdrawal(token, withdrawalId, signature);
+      }
       // Refresh withdrawals list
       const withdrawals = await api.getWithdrawals(token);
       set({
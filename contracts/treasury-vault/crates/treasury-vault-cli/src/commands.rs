@@ -0,0 +1,500 @@
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use anyhow::{anyhow, bail, Context, Result};
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use treasury_vault_client::errors::from_log;
+use treasury_vault_client::rpc::{RpcClient, RpcError};
+use treasury_vault_client::{instructions, pda, VaultKeys, WithdrawalRequest};
+
+use crate::{Cli, Command};
+
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+/// Expired requests closed per `cancel_expired_batch` transaction; keeps
+/// the transaction under the size limit without lookup tables
+const CANCEL_BATCH: usize = 10;
+
+struct CliContext {
+    rpc: RpcClient,
+    payer: Keypair,
+    vault: Pubkey,
+    dry_run: bool,
+}
+
+pub fn run(cli: Cli) -> Result<()> {
+    let payer = read_keypair_file(expand_home(&cli.keypair))
+        .map_err(|err| anyhow!("reading keypair {}: {err}", cli.keypair))?;
+    let vault = vault_address(&cli, &payer.pubkey())?;
+    let ctx = CliContext {
+        rpc: RpcClient::new(cluster_url(&cli.url)),
+        payer,
+        vault,
+        dry_run: cli.dry_run,
+    };
+
+    match cli.command {
+        Command::Init {
+            mint,
+            payout_wallet,
+            guardian,
+            transfer_allowlist,
+        } => {
+            let token_program = ctx.token_program(&mint)?;
+            let ix = instructions::initialize(
+                ctx.payer.pubkey(),
+                cli.vault_id,
+                mint,
+                payout_wallet,
+                guardian,
+                transfer_allowlist,
+                token_program,
+            );
+            println!("vault: {}", ctx.vault);
+            ctx.execute("init", vec![ix])
+        }
+        Command::CancelExpired { user } => {
+            let keys = ctx.vault_keys(None)?;
+            let now = ctx.rpc.get_clock()?.unix_timestamp;
+            let expired: Vec<_> = ctx
+                .rpc
+                .get_withdrawal_requests(&ctx.vault, user.as_ref())?
+                .into_iter()
+                .filter(|(_, request)| now > request.expires_at)
+                .collect();
+            if expired.is_empty() {
+                println!("no expired requests");
+            }
+            for batch in expired.chunks(CANCEL_BATCH) {
+                for (address, request) in batch {
+                    println!(
+                        "cancel {address} user={} request_id={} amount={}",
+                        request.user, request.request_id, request.amount
+                    );
+                }
+                let requests: Vec<_> = batch
+                    .iter()
+                    .map(|(_, request)| (request.user, request.request_id))
+                    .collect();
+                ctx.execute(
+                    "cancel-expired-batch",
+                    vec![keys.cancel_expired_batch(&requests)],
+                )?;
+            }
+            Ok(())
+        }
+        Command::CrankExpired { user } => {
+            let keys = ctx.vault_keys(None)?;
+            let grace_period = ctx
+                .rpc
+                .get_vault(&ctx.vault)?
+                .with_context(|| format!("vault {} not found", ctx.vault))?
+                .crank_grace_period;
+            let now = ctx.rpc.get_clock()?.unix_timestamp;
+            let crankable: Vec<_> = ctx
+                .rpc
+                .get_withdrawal_requests(&ctx.vault, user.as_ref())?
+                .into_iter()
+                .filter(|(_, request)| now > request.expires_at.saturating_add(grace_period))
+                .collect();
+            if crankable.is_empty() {
+                println!("no requests past the grace period");
+            }
+            for (address, request) in crankable {
+                println!(
+                    "crank {address} user={} request_id={} amount={}",
+                    request.user, request.request_id, request.amount
+                );
+                let ix =
+                    keys.crank_cancel_expired(ctx.payer.pubkey(), request.user, request.request_id);
+                ctx.execute("crank-cancel-expired", vec![ix])?;
+            }
+            Ok(())
+        }
+        Command::ShowVault => ctx.show_vault(),
+        Command::ListWithdrawals { user } => {
+            let now = ctx.rpc.get_clock()?.unix_timestamp;
+            let mut requests = ctx.rpc.get_withdrawal_requests(&ctx.vault, user.as_ref())?;
+            requests.sort_by_key(|(_, request)| (request.created_at, request.request_id));
+            for (address, request) in &requests {
+                print_request(address, request, now);
+            }
+            println!("{} open request(s)", requests.len());
+            Ok(())
+        }
+        command => {
+            let keys = ctx.vault_keys(command.mint())?;
+            match &command {
+                Command::AddMint { .. } => {
+                    println!("token account: {}", keys.vault_token_account);
+                }
+                Command::CreateWithdrawal {
+                    user, request_id, ..
+                } => {
+                    println!("request: {}", keys.withdrawal_address(user, *request_id));
+                }
+                _ => {}
+            }
+            let (label, instructions) = instructions(&command, &keys, &ctx.payer.pubkey())
+                .context("subcommand sends no instructions")?;
+            ctx.execute(label, instructions)
+        }
+    }
+}
+
+/// Instructions a single-transaction subcommand sends, labelled for output.
+/// `keys` are the vault's keys for [`Command::mint`] and `signer` is the
+/// keypair's key. `None` for subcommands that read chain state first.
+pub fn instructions(
+    command: &Command,
+    keys: &VaultKeys,
+    signer: &Pubkey,
+) -> Option<(&'static str, Vec<Instruction>)> {
+    let proposal = |proposal_id: &Option<u64>| proposal_id.map(|id| keys.proposal_address(id));
+    let (label, instruction) = match command {
+        Command::AddMint { proposal_id, .. } => ("add-mint", keys.add_mint(proposal(proposal_id))),
+        Command::SetMintEnabled { mint, enabled } => {
+            ("set-mint-enabled", keys.set_mint_enabled(*mint, *enabled))
+        }
+        Command::Deposit { amount, .. } => ("deposit", keys.deposit(*amount)),
+        Command::Payout {
+            amount,
+            proposal_id,
+            ..
+        } => ("payout", keys.payout(*amount, proposal(proposal_id))),
+        Command::InternalTransfer {
+            amount,
+            to,
+            proposal_id,
+            ..
+        } => (
+            "internal-transfer",
+            keys.internal_transfer(*to, *amount, proposal(proposal_id)),
+        ),
+        Command::ProposeTransferAllowlist {
+            vaults,
+            proposal_id,
+        } => (
+            "propose-transfer-allowlist",
+            keys.propose_transfer_allowlist(vaults.clone(), proposal(proposal_id)),
+        ),
+        Command::ApplyTransferAllowlist { proposal_id } => (
+            "apply-transfer-allowlist",
+            keys.apply_transfer_allowlist(proposal(proposal_id)),
+        ),
+        Command::CancelTransferAllowlist => (
+            "cancel-transfer-allowlist",
+            keys.cancel_transfer_allowlist(*signer),
+        ),
+        Command::CreateWithdrawal {
+            user,
+            request_id,
+            amount,
+            expires_in,
+            destination,
+            proposal_id,
+            ..
+        } => (
+            "create-withdrawal",
+            keys.create_withdrawal(
+                *user,
+                *request_id,
+                *amount,
+                *expires_in,
+                *destination,
+                proposal(proposal_id),
+            ),
+        ),
+        Command::ExtendWithdrawal {
+            user,
+            request_id,
+            expires_in,
+        } => (
+            "extend-withdrawal",
+            keys.extend_withdrawal(*user, *request_id, *expires_in),
+        ),
+        Command::AmendWithdrawal {
+            user,
+            request_id,
+            amount,
+        } => (
+            "amend-withdrawal",
+            keys.amend_withdrawal(*user, *request_id, *amount),
+        ),
+        Command::SetWithdrawalRelayer {
+            user,
+            request_id,
+            relayer_token_account,
+            fee,
+        } => (
+            "set-withdrawal-relayer",
+            keys.set_withdrawal_relayer(*user, *request_id, *relayer_token_account, *fee),
+        ),
+        Command::SetCrankConfig {
+            grace_period,
+            bounty,
+        } => (
+            "set-crank-config",
+            keys.set_crank_config(*grace_period, *bounty),
+        ),
+        Command::SetVoucherSigner {
+            signer,
+            proposal_id,
+        } => (
+            "set-voucher-signer",
+            keys.set_voucher_signer(signer.unwrap_or_default(), proposal(proposal_id)),
+        ),
+        Command::Init { .. }
+        | Command::CancelExpired { .. }
+        | Command::CrankExpired { .. }
+        | Command::ShowVault
+        | Command::ListWithdrawals { .. } => return None,
+    };
+    Some((label, vec![instruction]))
+}
+
+/// The signed wire transaction `--dry-run` simulates and a real run sends
+pub fn transaction(
+    instructions: &[Instruction],
+    payer: &Keypair,
+    blockhash: Hash,
+) -> Result<Vec<u8>> {
+    let mut transaction = Transaction::new_with_payer(instructions, Some(&payer.pubkey()));
+    transaction
+        .try_sign(&[payer], blockhash)
+        .with_context(|| format!("signing as {}", payer.pubkey()))?;
+    Ok(bincode::serialize(&transaction)?)
+}
+
+/// Vault the subcommand targets: `--vault`, or the vault PDA of `signer` and
+/// `--vault-id`. `init` always creates the signer's vault, so a different
+/// `--vault` is an error; `crank-expired` is run by keepers, whose keypair
+/// has no vault of its own, so `--vault` is required.
+pub fn vault_address(cli: &Cli, signer: &Pubkey) -> Result<Pubkey> {
+    let derived = pda::vault_address(signer, cli.vault_id).0;
+    match (&cli.command, cli.vault) {
+        (Command::Init { .. }, Some(vault)) if vault != derived => bail!(
+            "init creates vault {derived} (keypair {signer}, vault id {}), not --vault {vault}",
+            cli.vault_id
+        ),
+        (Command::CrankExpired { .. }, None) => {
+            bail!("crank-expired needs --vault: the keypair only pays fees")
+        }
+        (_, vault) => Ok(vault.unwrap_or(derived)),
+    }
+}
+
+impl Command {
+    /// Registered mint the subcommand moves; `None` means the primary USDT mint
+    pub fn mint(&self) -> Option<Pubkey> {
+        match self {
+            Command::AddMint { mint, .. } => Some(*mint),
+            Command::Deposit { mint, .. }
+            | Command::Payout { mint, .. }
+            | Command::InternalTransfer { mint, .. }
+            | Command::CreateWithdrawal { mint, .. } => *mint,
+            _ => None,
+        }
+    }
+}
+
+impl CliContext {
+    /// Keys for `mint`, or the primary USDT mint when None
+    fn vault_keys(&self, mint: Option<Pubkey>) -> Result<VaultKeys> {
+        let state = self
+            .rpc
+            .get_vault(&self.vault)?
+            .with_context(|| format!("vault {} not found", self.vault))?;
+        let token_program = self.token_program(&state.usdt_mint)?;
+        let keys = VaultKeys::from_state(self.vault, &state, token_program);
+        match mint {
+            Some(mint) if mint != state.usdt_mint => {
+                Ok(keys.with_mint(mint, self.token_program(&mint)?))
+            }
+            _ => Ok(keys),
+        }
+    }
+
+    /// Mint owner decides between SPL Token and Token-2022
+    fn token_program(&self, mint: &Pubkey) -> Result<Pubkey> {
+        Ok(self
+            .rpc
+            .get_account(mint)?
+            .with_context(|| format!("mint {mint} not found"))?
+            .owner)
+    }
+
+    fn execute(&self, label: &str, instructions: Vec<Instruction>) -> Result<()> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let wire = transaction(&instructions, &self.payer, blockhash)?;
+
+        if self.dry_run {
+            let simulation = self.rpc.simulate_transaction(&wire)?;
+            let units = simulation
+                .units_consumed
+                .map_or_else(|| "unknown".to_string(), |units| units.to_string());
+            match simulation.err {
+                None => println!("{label}: simulation ok, {units} compute units"),
+                Some(err) => {
+                    for log in &simulation.logs {
+                        println!("  {log}");
+                    }
+                    bail!(
+                        "{label}: simulation failed ({units} compute units): {}",
+                        describe_error(&err.to_string(), &simulation.logs)
+                    );
+                }
+            }
+            return Ok(());
+        }
+
+        let signature = match self.rpc.send_transaction(&wire) {
+            Ok(signature) => signature,
+            Err(RpcError::Rpc { message, logs, .. }) => {
+                for log in &logs {
+                    println!("  {log}");
+                }
+                bail!("{label}: {}", describe_error(&message, &logs));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        self.rpc.confirm_transaction(&signature, CONFIRM_TIMEOUT)?;
+        println!("{label}: {signature}");
+        Ok(())
+    }
+
+    fn show_vault(&self) -> Result<()> {
+        let vault = self
+            .rpc
+            .get_vault(&self.vault)?
+            .with_context(|| format!("vault {} not found", self.vault))?;
+        println!("vault:                  {}", self.vault);
+        println!("vault id:               {}", vault.vault_id);
+        println!("authority:              {}", vault.authority);
+        if let Some(pending) = vault.pending_authority {
+            println!("pending authority:      {pending}");
+        }
+        println!("guardian:               {}", vault.guardian);
+        println!("paused:                 {}", vault.paused);
+        println!("payout wallet:          {}", vault.payout_wallet);
+        for entry in &vault.mints {
+            let balance = self.rpc.get_token_balance(&entry.token_account)?;
+            let status = if entry.enabled { "" } else { " (disabled)" };
+            println!("mint:                   {}{status}", entry.mint);
+            println!("  token account:        {}", entry.token_account);
+            println!("  balance:              {balance}");
+            println!("  reserved:             {}", entry.total_reserved);
+            println!(
+                "  available:            {}",
+                vault.available_balance(&entry.mint, balance)
+            );
+            println!(
+                "  deposited:            {} ({} deposits)",
+                entry.total_deposited, entry.deposit_count
+            );
+            println!(
+                "  paid out:             {} ({} payouts)",
+                entry.total_paid_out, entry.payout_count
+            );
+        }
+        println!("reserved:               {}", vault.total_reserved);
+        println!(
+            "deposited:              {} ({} deposits)",
+            vault.total_deposited, vault.deposit_count
+        );
+        println!(
+            "paid out:               {} ({} payouts)",
+            vault.total_paid_out, vault.payout_count
+        );
+        println!(
+            "rate limits:            per tx {}, per window {}, per request {}",
+            vault.max_payout_per_tx, vault.max_outflow_per_window, vault.max_withdrawal_amount
+        );
+        if vault.multisig_enabled() {
+            println!(
+                "multisig:               {}-of-{} (large withdrawal > {})",
+                vault.multisig_threshold,
+                vault.multisig_signers.len(),
+                vault.large_withdrawal_threshold
+            );
+            for signer in &vault.multisig_signers {
+                println!("  signer:               {signer}");
+            }
+        } else {
+            println!("multisig:               off");
+        }
+        if vault.vouchers_enabled() {
+            println!("voucher signer:         {}", vault.voucher_signer);
+        } else {
+            println!("voucher signer:         off");
+        }
+        println!(
+            "crank:                  after {}s grace, {} lamport bounty",
+            vault.crank_grace_period, vault.crank_bounty
+        );
+        println!(
+            "transfer allowlist:     {} vaults",
+            vault.transfer_allowlist.len()
+        );
+        for destination in &vault.transfer_allowlist {
+            println!("  vault:                {destination}");
+        }
+        if vault.transfer_allowlist_effective_at != 0 {
+            println!(
+                "pending allowlist:      {} vaults, applicable at {}",
+                vault.pending_transfer_allowlist.len(),
+                vault.transfer_allowlist_effective_at
+            );
+            for destination in &vault.pending_transfer_allowlist {
+                println!("  vault:                {destination}");
+            }
+        }
+        println!("liability epoch:        {}", vault.liability_epoch);
+        Ok(())
+    }
+}
+
+fn print_request(address: &Pubkey, request: &WithdrawalRequest, now: i64) {
+    let status = if now > request.expires_at {
+        "expired"
+    } else {
+        "open"
+    };
+    let destination = request
+        .destination
+        .map(|destination| format!(" destination={destination}"))
+        .unwrap_or_default();
+    println!(
+        "{address} user={} request_id={} mint={} amount={} expires_at={}{destination} {status}",
+        request.user, request.request_id, request.mint, request.amount, request.expires_at
+    );
+}
+
+/// Append the program error name when the failure is a `TreasuryError`
+fn describe_error(message: &str, logs: &[String]) -> String {
+    let error = std::iter::once(message)
+        .chain(logs.iter().map(String::as_str))
+        .find_map(from_log);
+    match error {
+        Some(error) => format!("{message} [{}: {error}]", error.name()),
+        None => message.to_string(),
+    }
+}
+
+fn cluster_url(url: &str) -> String {
+    match url {
+        "localnet" | "l" => "http://127.0.0.1:8899".into(),
+        "devnet" | "d" => "https://api.devnet.solana.com".into(),
+        "mainnet-beta" | "m" => "https://api.mainnet-beta.solana.com".into(),
+        url => url.into(),
+    }
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
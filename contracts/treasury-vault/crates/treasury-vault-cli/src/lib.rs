@@ -0,0 +1,229 @@
+//! `treasury-vault-cli` — operator tool for inspecting and operating the vault.
+//!
+//! Every state-changing subcommand accepts `--dry-run`, which signs and
+//! simulates the transaction and prints compute units instead of sending it.
+
+mod commands;
+
+pub use commands::{instructions, run, transaction, vault_address};
+
+use anchor_lang::prelude::Pubkey;
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "treasury-vault-cli",
+    version,
+    about = "Operate the Treasury Vault"
+)]
+pub struct Cli {
+    /// RPC URL or moniker: localnet, devnet, mainnet-beta
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        env = "TREASURY_RPC_URL",
+        default_value = "localnet"
+    )]
+    pub url: String,
+
+    /// Signer keypair file (authority for most commands)
+    #[arg(
+        long,
+        short = 'k',
+        global = true,
+        env = "TREASURY_KEYPAIR",
+        default_value = "~/.config/solana/id.json"
+    )]
+    pub keypair: String,
+
+    /// Vault address. Defaults to the vault PDA of the keypair and --vault-id;
+    /// required by crank-expired.
+    #[arg(long, global = true)]
+    pub vault: Option<Pubkey>,
+
+    /// Id of the keypair's vault (e.g. treasury, jackpot pool, reserves)
+    #[arg(long, global = true, env = "TREASURY_VAULT_ID", default_value_t = 0)]
+    pub vault_id: u16,
+
+    /// Simulate and print compute units instead of sending
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Create the keypair's vault with --vault-id
+    Init {
+        /// USDT mint (SPL Token or Token-2022)
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long)]
+        payout_wallet: Pubkey,
+        /// Emergency pause key, must differ from the authority
+        #[arg(long)]
+        guardian: Pubkey,
+        /// Vaults internal-transfer may send to, comma-separated
+        #[arg(long, value_delimiter = ',')]
+        transfer_allowlist: Vec<Pubkey>,
+    },
+    /// Register another mint with the vault (same decimals as USDT)
+    AddMint {
+        #[arg(long)]
+        mint: Pubkey,
+        /// Approved multisig proposal to consume
+        #[arg(long)]
+        proposal_id: Option<u64>,
+    },
+    /// Enable or disable deposits and new withdrawals in a registered mint
+    SetMintEnabled {
+        #[arg(long)]
+        mint: Pubkey,
+        #[arg(long, action = clap::ArgAction::Set)]
+        enabled: bool,
+    },
+    /// Deposit from the authority's token account (base units)
+    Deposit {
+        amount: u64,
+        /// Registered mint to deposit; defaults to USDT
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Pay out to the configured payout wallet (base units)
+    Payout {
+        amount: u64,
+        /// Registered mint to pay out; defaults to USDT
+        #[arg(long)]
+        mint: Option<Pubkey>,
+        /// Approved multisig proposal to consume
+        #[arg(long)]
+        proposal_id: Option<u64>,
+    },
+    /// Move tokens to an allowlisted vault of the same program (base units)
+    InternalTransfer {
+        amount: u64,
+        /// Destination vault address
+        #[arg(long)]
+        to: Pubkey,
+        /// Registered mint to move; defaults to USDT
+        #[arg(long)]
+        mint: Option<Pubkey>,
+        /// Approved multisig proposal to consume
+        #[arg(long)]
+        proposal_id: Option<u64>,
+    },
+    /// Propose a new transfer allowlist, applicable after the 48h timelock
+    ProposeTransferAllowlist {
+        /// Destination vaults, comma-separated; omit to clear the allowlist
+        #[arg(long, value_delimiter = ',')]
+        vaults: Vec<Pubkey>,
+        /// Approved multisig proposal to consume
+        #[arg(long)]
+        proposal_id: Option<u64>,
+    },
+    /// Apply the pending transfer allowlist once its timelock has elapsed
+    ApplyTransferAllowlist {
+        /// Approved multisig proposal to consume
+        #[arg(long)]
+        proposal_id: Option<u64>,
+    },
+    /// Drop the pending transfer allowlist (signed as guardian or authority)
+    CancelTransferAllowlist,
+    /// Reserve funds for a user-claimable withdrawal
+    CreateWithdrawal {
+        #[arg(long)]
+        user: Pubkey,
+        #[arg(long)]
+        request_id: u64,
+        /// Base units
+        #[arg(long)]
+        amount: u64,
+        /// Seconds until the request expires
+        #[arg(long)]
+        expires_in: i64,
+        /// Wallet the user asked to be paid to, instead of their own
+        #[arg(long)]
+        destination: Option<Pubkey>,
+        /// Registered mint the request pays in; defaults to USDT
+        #[arg(long)]
+        mint: Option<Pubkey>,
+        /// Approved multisig proposal to consume
+        #[arg(long)]
+        proposal_id: Option<u64>,
+    },
+    /// Move a request's deadline to now + expires-in (later than the current one)
+    ExtendWithdrawal {
+        #[arg(long)]
+        user: Pubkey,
+        #[arg(long)]
+        request_id: u64,
+        /// Seconds from now until the request expires
+        #[arg(long)]
+        expires_in: i64,
+    },
+    /// Lower a request's amount, releasing the difference
+    AmendWithdrawal {
+        #[arg(long)]
+        user: Pubkey,
+        #[arg(long)]
+        request_id: u64,
+        /// New amount in base units, below the current one
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Set the relayer fee taken from a sponsored (gasless) claim
+    SetWithdrawalRelayer {
+        #[arg(long)]
+        user: Pubkey,
+        #[arg(long)]
+        request_id: u64,
+        /// Token account receiving the fee; omit to clear
+        #[arg(long)]
+        relayer_token_account: Option<Pubkey>,
+        /// Base units, at most 1 USDT
+        #[arg(long, default_value_t = 0)]
+        fee: u64,
+    },
+    /// Set the crank grace period and bounty for expired-request cleanup
+    SetCrankConfig {
+        /// Seconds after expiry before anyone may crank a request
+        #[arg(long)]
+        grace_period: i64,
+        /// Lamports paid to the cranker, at most 1_000_000
+        #[arg(long)]
+        bounty: u64,
+    },
+    /// Set the key that signs voucher claims
+    SetVoucherSigner {
+        /// Omit to disable voucher claims
+        #[arg(long)]
+        signer: Option<Pubkey>,
+        /// Approved multisig proposal to consume
+        #[arg(long)]
+        proposal_id: Option<u64>,
+    },
+    /// Cancel every expired withdrawal request
+    CancelExpired {
+        /// Only this user's requests
+        #[arg(long)]
+        user: Option<Pubkey>,
+    },
+    /// Crank every request past its grace period, earning the bounty.
+    /// Needs no vault role: the keypair only pays fees, so --vault is required.
+    CrankExpired {
+        /// Only this user's requests
+        #[arg(long)]
+        user: Option<Pubkey>,
+    },
+    /// Print vault state and token balance
+    ShowVault,
+    /// List open withdrawal requests
+    ListWithdrawals {
+        /// Only this user's requests
+        #[arg(long)]
+        user: Option<Pubkey>,
+    },
+}
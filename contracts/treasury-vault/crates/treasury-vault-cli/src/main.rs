@@ -0,0 +1,9 @@
+//! `treasury-vault-cli` — operator tool for inspecting and operating the vault.
+
+use anyhow::Result;
+use clap::Parser;
+use treasury_vault_cli::Cli;
+
+fn main() -> Result<()> {
+    treasury_vault_cli::run(Cli::parse())
+}
@@ -0,0 +1,429 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use clap::{CommandFactory, Parser};
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use treasury_vault_cli::{instructions, transaction, vault_address, Cli, Command};
+use treasury_vault_client::{pda, VaultKeys};
+
+/// Packet size a transaction must fit in
+const MAX_TRANSACTION_SIZE: usize = 1232;
+
+fn parse(args: &[String]) -> Cli {
+    Cli::try_parse_from(std::iter::once("treasury-vault-cli".to_string()).chain(args.to_vec()))
+        .unwrap_or_else(|err| panic!("{args:?}: {err}"))
+}
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(str::to_string).collect()
+}
+
+fn keys() -> VaultKeys {
+    let authority = Pubkey::new_unique();
+    VaultKeys {
+        vault: pda::vault_address(&authority, 0).0,
+        vault_id: 0,
+        authority,
+        guardian: Pubkey::new_unique(),
+        payout_wallet: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        vault_token_account: Pubkey::new_unique(),
+        token_program: Pubkey::new_unique(),
+    }
+}
+
+#[test]
+fn cli_definition_is_consistent() {
+    Cli::command().debug_assert();
+}
+
+#[test]
+fn global_flags_parse_on_either_side_of_the_subcommand() {
+    let vault = Pubkey::new_unique();
+    let cli = parse(&args(&format!(
+        "-u devnet --vault {vault} show-vault --vault-id 3 --dry-run -k /tmp/id.json"
+    )));
+    assert_eq!(cli.url, "devnet");
+    assert_eq!(cli.vault, Some(vault));
+    assert_eq!(cli.vault_id, 3);
+    assert!(cli.dry_run);
+    assert_eq!(cli.keypair, "/tmp/id.json");
+    assert!(matches!(cli.command, Command::ShowVault));
+
+    let cli = parse(&args("list-withdrawals"));
+    assert!(!cli.dry_run);
+    assert_eq!((cli.vault, cli.vault_id), (None, 0));
+}
+
+#[test]
+fn init_and_crank_expired_check_the_vault_flag() {
+    let signer = Pubkey::new_unique();
+    let derived = pda::vault_address(&signer, 2).0;
+    let other = Pubkey::new_unique();
+    let init = format!(
+        "init --vault-id 2 --mint {} --payout-wallet {} --guardian {}",
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique()
+    );
+
+    let cli = parse(&args(&init));
+    assert_eq!(vault_address(&cli, &signer).unwrap(), derived);
+    let cli = parse(&args(&format!("{init} --vault {derived}")));
+    assert_eq!(vault_address(&cli, &signer).unwrap(), derived);
+    let cli = parse(&args(&format!("{init} --vault {other}")));
+    assert!(vault_address(&cli, &signer).is_err());
+
+    let cli = parse(&args("crank-expired"));
+    assert!(vault_address(&cli, &signer).is_err());
+    let cli = parse(&args(&format!("crank-expired --vault {other}")));
+    assert_eq!(vault_address(&cli, &signer).unwrap(), other);
+
+    let cli = parse(&args("show-vault --vault-id 2"));
+    assert_eq!(vault_address(&cli, &signer).unwrap(), derived);
+}
+
+#[test]
+fn parses_vault_setup_subcommands() {
+    let [mint, payout_wallet, guardian, a, b] = [(); 5].map(|_| Pubkey::new_unique());
+    let cli = parse(&args(&format!(
+        "init --mint {mint} --payout-wallet {payout_wallet} --guardian {guardian} \
+         --transfer-allowlist {a},{b}"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::Init { mint: m, payout_wallet: p, guardian: g, transfer_allowlist }
+            if m == mint && p == payout_wallet && g == guardian && transfer_allowlist == [a, b]
+    ));
+    let cli = parse(&args(&format!(
+        "init --mint {mint} --payout-wallet {payout_wallet} --guardian {guardian}"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::Init { transfer_allowlist, .. } if transfer_allowlist.is_empty()
+    ));
+
+    let cli = parse(&args(&format!("add-mint --mint {mint}")));
+    assert!(matches!(
+        cli.command,
+        Command::AddMint { mint: m, proposal_id: None } if m == mint
+    ));
+
+    let cli = parse(&args(&format!(
+        "set-mint-enabled --mint {mint} --enabled false"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::SetMintEnabled { mint: m, enabled: false } if m == mint
+    ));
+    // The flag takes an explicit value
+    let line = args(&format!("x set-mint-enabled --mint {mint} --enabled"));
+    assert!(Cli::try_parse_from(line).is_err());
+}
+
+#[test]
+fn parses_fund_movement_subcommands() {
+    let [mint, to] = [(); 2].map(|_| Pubkey::new_unique());
+    let cli = parse(&args("deposit 1000000"));
+    assert!(matches!(
+        cli.command,
+        Command::Deposit {
+            amount: 1_000_000,
+            mint: None
+        }
+    ));
+
+    let cli = parse(&args(&format!("payout 5 --mint {mint} --proposal-id 7")));
+    assert!(matches!(
+        cli.command,
+        Command::Payout { amount: 5, mint: Some(m), proposal_id: Some(7) } if m == mint
+    ));
+
+    let cli = parse(&args(&format!("internal-transfer 9 --to {to}")));
+    assert!(matches!(
+        cli.command,
+        Command::InternalTransfer { amount: 9, to: t, mint: None, proposal_id: None } if t == to
+    ));
+
+    for line in ["deposit", "deposit -1", "payout 1.5", "internal-transfer 9"] {
+        let line = std::iter::once("x").chain(line.split_whitespace());
+        assert!(Cli::try_parse_from(line).is_err());
+    }
+}
+
+#[test]
+fn parses_transfer_allowlist_subcommands() {
+    let [a, b] = [(); 2].map(|_| Pubkey::new_unique());
+    let cli = parse(&args(&format!(
+        "propose-transfer-allowlist --vaults {a},{b} --proposal-id 2"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::ProposeTransferAllowlist { vaults, proposal_id: Some(2) } if vaults == [a, b]
+    ));
+    let cli = parse(&args("propose-transfer-allowlist"));
+    assert!(matches!(
+        cli.command,
+        Command::ProposeTransferAllowlist { vaults, proposal_id: None } if vaults.is_empty()
+    ));
+
+    let cli = parse(&args("apply-transfer-allowlist --proposal-id 4"));
+    assert!(matches!(
+        cli.command,
+        Command::ApplyTransferAllowlist {
+            proposal_id: Some(4)
+        }
+    ));
+
+    let cli = parse(&args("cancel-transfer-allowlist"));
+    assert!(matches!(cli.command, Command::CancelTransferAllowlist));
+}
+
+#[test]
+fn parses_withdrawal_subcommands() {
+    let [user, destination, mint, relayer] = [(); 4].map(|_| Pubkey::new_unique());
+    let cli = parse(&args(&format!(
+        "create-withdrawal --user {user} --request-id 1 --amount 50 --expires-in 3600 \
+         --destination {destination} --mint {mint} --proposal-id 3"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::CreateWithdrawal {
+            user: u,
+            request_id: 1,
+            amount: 50,
+            expires_in: 3600,
+            destination: Some(d),
+            mint: Some(m),
+            proposal_id: Some(3),
+        } if u == user && d == destination && m == mint
+    ));
+    let missing = args(&format!(
+        "x create-withdrawal --user {user} --amount 50 --expires-in 60"
+    ));
+    assert!(Cli::try_parse_from(missing).is_err());
+
+    let cli = parse(&args(&format!(
+        "extend-withdrawal --user {user} --request-id 2 --expires-in 600"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::ExtendWithdrawal { user: u, request_id: 2, expires_in: 600 } if u == user
+    ));
+
+    let cli = parse(&args(&format!(
+        "amend-withdrawal --user {user} --request-id 2 --amount 10"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::AmendWithdrawal { user: u, request_id: 2, amount: 10 } if u == user
+    ));
+
+    let cli = parse(&args(&format!(
+        "set-withdrawal-relayer --user {user} --request-id 2 --relayer-token-account {relayer} --fee 100"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::SetWithdrawalRelayer {
+            user: u,
+            request_id: 2,
+            relayer_token_account: Some(r),
+            fee: 100,
+        } if u == user && r == relayer
+    ));
+    let cli = parse(&args(&format!(
+        "set-withdrawal-relayer --user {user} --request-id 2"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::SetWithdrawalRelayer {
+            relayer_token_account: None,
+            fee: 0,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parses_config_subcommands() {
+    let signer = Pubkey::new_unique();
+    let cli = parse(&args("set-crank-config --grace-period 86400 --bounty 5000"));
+    assert!(matches!(
+        cli.command,
+        Command::SetCrankConfig {
+            grace_period: 86_400,
+            bounty: 5_000
+        }
+    ));
+
+    let cli = parse(&args(&format!(
+        "set-voucher-signer --signer {signer} --proposal-id 1"
+    )));
+    assert!(matches!(
+        cli.command,
+        Command::SetVoucherSigner { signer: Some(s), proposal_id: Some(1) } if s == signer
+    ));
+    let cli = parse(&args("set-voucher-signer"));
+    assert!(matches!(
+        cli.command,
+        Command::SetVoucherSigner {
+            signer: None,
+            proposal_id: None
+        }
+    ));
+    assert!(Cli::try_parse_from(["x", "set-voucher-signer", "--signer", "not-a-key"]).is_err());
+}
+
+#[test]
+fn parses_read_and_cleanup_subcommands() {
+    let user = Pubkey::new_unique();
+    let cli = parse(&args(&format!("cancel-expired --user {user}")));
+    assert!(matches!(cli.command, Command::CancelExpired { user: Some(u) } if u == user));
+    let cli = parse(&args("crank-expired"));
+    assert!(matches!(cli.command, Command::CrankExpired { user: None }));
+    let cli = parse(&args("show-vault"));
+    assert!(matches!(cli.command, Command::ShowVault));
+    let cli = parse(&args(&format!("list-withdrawals --user {user}")));
+    assert!(matches!(cli.command, Command::ListWithdrawals { user: Some(u) } if u == user));
+    assert!(Cli::try_parse_from(["x", "no-such-command"]).is_err());
+}
+
+#[test]
+fn dry_run_builds_the_client_instructions() {
+    let keys = keys();
+    let signer = Pubkey::new_unique();
+    let [user, to, destination, voucher_signer] = [(); 4].map(|_| Pubkey::new_unique());
+    let proposal = |id| Some(keys.proposal_address(id));
+
+    let cases = [
+        (
+            "add-mint",
+            format!("add-mint --mint {} --proposal-id 6", keys.mint),
+            keys.add_mint(proposal(6)),
+        ),
+        (
+            "set-mint-enabled",
+            format!("set-mint-enabled --mint {} --enabled true", keys.mint),
+            keys.set_mint_enabled(keys.mint, true),
+        ),
+        ("deposit", "deposit 10".to_string(), keys.deposit(10)),
+        (
+            "payout",
+            "payout 10 --proposal-id 2".to_string(),
+            keys.payout(10, proposal(2)),
+        ),
+        (
+            "internal-transfer",
+            format!("internal-transfer 10 --to {to}"),
+            keys.internal_transfer(to, 10, None),
+        ),
+        (
+            "propose-transfer-allowlist",
+            format!("propose-transfer-allowlist --vaults {to} --proposal-id 3"),
+            keys.propose_transfer_allowlist(vec![to], proposal(3)),
+        ),
+        (
+            "apply-transfer-allowlist",
+            "apply-transfer-allowlist".to_string(),
+            keys.apply_transfer_allowlist(None),
+        ),
+        (
+            "cancel-transfer-allowlist",
+            "cancel-transfer-allowlist".to_string(),
+            keys.cancel_transfer_allowlist(signer),
+        ),
+        (
+            "create-withdrawal",
+            format!(
+                "create-withdrawal --user {user} --request-id 4 --amount 10 --expires-in 60 \
+                 --destination {destination} --proposal-id 5"
+            ),
+            keys.create_withdrawal(user, 4, 10, 60, Some(destination), proposal(5)),
+        ),
+        (
+            "extend-withdrawal",
+            format!("extend-withdrawal --user {user} --request-id 4 --expires-in 120"),
+            keys.extend_withdrawal(user, 4, 120),
+        ),
+        (
+            "amend-withdrawal",
+            format!("amend-withdrawal --user {user} --request-id 4 --amount 5"),
+            keys.amend_withdrawal(user, 4, 5),
+        ),
+        (
+            "set-withdrawal-relayer",
+            format!("set-withdrawal-relayer --user {user} --request-id 4 --fee 1"),
+            keys.set_withdrawal_relayer(user, 4, None, 1),
+        ),
+        (
+            "set-crank-config",
+            "set-crank-config --grace-period 60 --bounty 1".to_string(),
+            keys.set_crank_config(60, 1),
+        ),
+        (
+            "set-voucher-signer",
+            format!("set-voucher-signer --signer {voucher_signer}"),
+            keys.set_voucher_signer(voucher_signer, None),
+        ),
+    ];
+    for (label, line, expected) in cases {
+        let cli = parse(&args(&format!("--dry-run {line}")));
+        assert!(cli.dry_run, "{line}");
+        let built = instructions(&cli.command, &keys, &signer);
+        assert_eq!(built, Some((label, vec![expected])), "{line}");
+    }
+
+    // Subcommands that read chain state first build nothing up front
+    for line in [
+        "show-vault",
+        "list-withdrawals",
+        "cancel-expired",
+        "crank-expired",
+    ] {
+        let cli = parse(&args(&format!("--dry-run {line}")));
+        assert_eq!(instructions(&cli.command, &keys, &signer), None, "{line}");
+    }
+}
+
+#[test]
+fn dry_run_simulates_the_signed_transaction_it_would_send() {
+    let payer = Keypair::new();
+    let keys = VaultKeys {
+        authority: payer.pubkey(),
+        ..keys()
+    };
+    let user = Pubkey::new_unique();
+    // The largest single-instruction subcommand: every optional account set
+    let cli = parse(&args(&format!(
+        "--dry-run create-withdrawal --user {user} --request-id 1 --amount 10 --expires-in 60 \
+         --destination {} --mint {} --proposal-id 9",
+        Pubkey::new_unique(),
+        keys.mint
+    )));
+    let (_, built) = instructions(&cli.command, &keys, &payer.pubkey()).unwrap();
+
+    let wire = transaction(&built, &payer, Hash::new_unique()).unwrap();
+    assert!(wire.len() <= MAX_TRANSACTION_SIZE, "{} bytes", wire.len());
+    let sent: Transaction = bincode::deserialize(&wire).unwrap();
+    sent.verify().unwrap();
+    assert_eq!(sent.message.account_keys[0], payer.pubkey());
+    let [compiled] = sent.message.instructions.as_slice() else {
+        panic!("one instruction");
+    };
+    assert_eq!(
+        sent.message.account_keys[usize::from(compiled.program_id_index)],
+        built[0].program_id
+    );
+    assert_eq!(compiled.data, built[0].data);
+    let accounts: Vec<_> = compiled
+        .accounts
+        .iter()
+        .map(|index| sent.message.account_keys[usize::from(*index)])
+        .collect();
+    let expected: Vec<_> = built[0].accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(accounts, expected);
+
+    // A keypair that isn't the authority fails before anything is sent
+    let err = transaction(&built, &Keypair::new(), Hash::new_unique()).unwrap_err();
+    assert!(format!("{err:#}").contains("not enough signers"), "{err:#}");
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+
+use anchor_lang::AccountDeserialize;
+
+use crate::{
+    LiabilitySnapshot, Proposal, TreasuryVault, UserLedger, VoucherNonces, WithdrawalRequest,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError(pub String);
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to decode account: {}", self.0)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Byte offset of `WithdrawalRequest::vault` in account data, for memcmp filters
+pub const WITHDRAWAL_REQUEST_VAULT_OFFSET: usize = 8;
+/// Byte offset of `WithdrawalRequest::user` in account data, for memcmp filters
+pub const WITHDRAWAL_REQUEST_USER_OFFSET: usize = WITHDRAWAL_REQUEST_VAULT_OFFSET + 32;
+
+/// Decodes any program account from raw data (8-byte discriminator included)
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T, DecodeError> {
+    let mut data = data;
+    T::try_deserialize(&mut data).map_err(|err| DecodeError(err.to_string()))
+}
+
+pub fn decode_vault(data: &[u8]) -> Result<TreasuryVault, DecodeError> {
+    decode(data)
+}
+
+pub fn decode_withdrawal_request(data: &[u8]) -> Result<WithdrawalRequest, DecodeError> {
+    decode(data)
+}
+
+pub fn decode_user_ledger(data: &[u8]) -> Result<UserLedger, DecodeError> {
+    decode(data)
+}
+
+pub fn decode_voucher_nonces(data: &[u8]) -> Result<VoucherNonces, DecodeError> {
+    decode(data)
+}
+
+pub fn decode_proposal(data: &[u8]) -> Result<Proposal, DecodeError> {
+    decode(data)
+}
+
+pub fn decode_liability_snapshot(data: &[u8]) -> Result<LiabilitySnapshot, DecodeError> {
+    decode(data)
+}
@@ -0,0 +1,79 @@
+use crate::TreasuryError;
+
+macro_rules! treasury_errors {
+    ($($variant:ident),* $(,)?) => {
+        /// Every `TreasuryError` variant, in declaration order
+        pub const ALL_ERRORS: &[TreasuryError] = &[$(TreasuryError::$variant),*];
+
+        // Stops compiling when TreasuryError gains a variant missing above
+        #[allow(dead_code)]
+        fn assert_exhaustive(error: TreasuryError) {
+            match error {
+                $(TreasuryError::$variant)|* => {}
+            }
+        }
+    };
+}
+
+treasury_errors!(
+    Unauthorized,
+    InvalidMint,
+    InvalidVaultAccount,
+    InvalidPayoutWallet,
+    ZeroAmount,
+    InsufficientBalance,
+    Overflow,
+    WithdrawalExpired,
+    WithdrawalNotExpired,
+    InvalidExpiration,
+    InvalidNewAuthority,
+    MultisigRequired,
+    NotMultisigSigner,
+    InvalidMultisigConfig,
+    InvalidProposal,
+    StaleProposal,
+    ProposalAlreadyExecuted,
+    ProposalNotApproved,
+    AlreadyApproved,
+    RateLimitExceeded,
+    VaultPaused,
+    NotGuardian,
+    InvalidGuardian,
+    InvalidEpoch,
+    AmountNotReduced,
+    InvalidCancelReason,
+    RelayerFeeTooHigh,
+    InvalidRelayerAccount,
+    InvalidDestination,
+    VouchersDisabled,
+    InvalidVoucher,
+    VoucherAlreadyUsed,
+    InvalidBatchSize,
+    InvalidBatchAccounts,
+    CrankTooEarly,
+    InvalidCrankConfig,
+    MintDisabled,
+    MintRegistryFull,
+    MintAlreadyRegistered,
+    InvalidTransferAllowlist,
+    TransferNotAllowed,
+    NoPendingTransferAllowlist,
+    TransferAllowlistTimelocked,
+    InvalidPayer,
+    ProposalStillActive,
+);
+
+/// Maps a custom program error code (`InstructionError::Custom`) to `TreasuryError`
+pub fn from_code(code: u32) -> Option<TreasuryError> {
+    ALL_ERRORS
+        .iter()
+        .copied()
+        .find(|error| u32::from(*error) == code)
+}
+
+/// Extracts a `TreasuryError` from a `custom program error: 0x…` log line
+pub fn from_log(line: &str) -> Option<TreasuryError> {
+    let hex = line.split("custom program error: 0x").nth(1)?;
+    let hex: String = hex.chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+    u32::from_str_radix(&hex, 16).ok().and_then(from_code)
+}
@@ -0,0 +1,754 @@
+use anchor_lang::prelude::{Pubkey, System};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::{Id, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{get_associated_token_address_with_program_id, AssociatedToken};
+use treasury_vault::state::VOUCHER_NONCES_PER_PAGE;
+use treasury_vault::{accounts, instruction};
+
+use crate::pda::{
+    liability_snapshot_address, proposal_address, user_ledger_address, vault_address,
+    voucher_nonces_address, withdrawal_address,
+};
+use crate::{BatchWithdrawal, ProposalAction, TreasuryVault, Voucher, PROGRAM_ID};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn ata(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
+
+/// Ed25519 precompile instruction verifying `signature` over the voucher
+/// message, to place right before [`VaultKeys::claim_with_voucher`]
+pub fn voucher_signature(
+    voucher_signer: &Pubkey,
+    signature: &[u8; 64],
+    voucher: &Voucher,
+) -> Instruction {
+    solana_ed25519_program::new_ed25519_instruction_with_signature(
+        &voucher.message(),
+        signature,
+        &voucher_signer.to_bytes(),
+    )
+}
+
+/// Build `initialize`. The vault PDA is derived from `authority` and
+/// `vault_id`. `token_program` is SPL Token or Token-2022, matching the mint.
+/// `transfer_allowlist` holds the vaults `internal_transfer` may send to.
+pub fn initialize(
+    authority: Pubkey,
+    vault_id: u16,
+    usdt_mint: Pubkey,
+    payout_wallet: Pubkey,
+    guardian: Pubkey,
+    transfer_allowlist: Vec<Pubkey>,
+    token_program: Pubkey,
+) -> Instruction {
+    let (vault, _) = vault_address(&authority, vault_id);
+    build(
+        accounts::Initialize {
+            authority,
+            vault,
+            usdt_mint,
+            vault_token_account: ata(&vault, &usdt_mint, &token_program),
+            payout_wallet,
+            guardian,
+            token_program,
+            associated_token_program: AssociatedToken::id(),
+            system_program: System::id(),
+        },
+        instruction::Initialize {
+            vault_id,
+            transfer_allowlist,
+        },
+    )
+}
+
+/// Addresses of an initialized vault, used to fill in instruction accounts.
+/// Build it from on-chain state with [`VaultKeys::from_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VaultKeys {
+    pub vault: Pubkey,
+    /// Id the vault was created with, one of its PDA seeds
+    pub vault_id: u16,
+    pub authority: Pubkey,
+    pub guardian: Pubkey,
+    pub payout_wallet: Pubkey,
+    /// Mint that token instructions move, and the vault's token account for it
+    pub mint: Pubkey,
+    pub vault_token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl VaultKeys {
+    /// Keys for the primary USDT mint. `token_program` is the owner of the
+    /// mint account (SPL Token or Token-2022).
+    pub fn from_state(vault: Pubkey, state: &TreasuryVault, token_program: Pubkey) -> Self {
+        Self {
+            vault,
+            vault_id: state.vault_id,
+            authority: state.authority,
+            guardian: state.guardian,
+            payout_wallet: state.payout_wallet,
+            mint: state.usdt_mint,
+            vault_token_account: state.vault_token_account,
+            token_program,
+        }
+    }
+
+    /// The same vault's keys for another registered mint, owned by `token_program`
+    pub fn with_mint(&self, mint: Pubkey, token_program: Pubkey) -> Self {
+        Self {
+            mint,
+            vault_token_account: ata(&self.vault, &mint, &token_program),
+            token_program,
+            ..*self
+        }
+    }
+
+    pub fn withdrawal_address(&self, user: &Pubkey, request_id: u64) -> Pubkey {
+        withdrawal_address(&self.vault, user, request_id).0
+    }
+
+    pub fn user_ledger_address(&self, user: &Pubkey) -> Pubkey {
+        user_ledger_address(&self.vault, user).0
+    }
+
+    /// Bitmap page that tracks `nonce` for `user`
+    pub fn voucher_nonces_address(&self, user: &Pubkey, nonce: u64) -> Pubkey {
+        voucher_nonces_address(&self.vault, user, nonce / VOUCHER_NONCES_PER_PAGE).0
+    }
+
+    pub fn proposal_address(&self, proposal_id: u64) -> Pubkey {
+        proposal_address(&self.vault, proposal_id).0
+    }
+
+    pub fn token_account(&self, owner: &Pubkey) -> Pubkey {
+        ata(owner, &self.mint, &self.token_program)
+    }
+
+    /// Registers `self.mint`, creating the vault's token account for it.
+    /// Use on keys from [`VaultKeys::with_mint`].
+    pub fn add_mint(&self, proposal: Option<Pubkey>) -> Instruction {
+        build(
+            accounts::AddMint {
+                authority: self.authority,
+                vault: self.vault,
+                mint: self.mint,
+                vault_token_account: self.vault_token_account,
+                proposal,
+                token_program: self.token_program,
+                associated_token_program: AssociatedToken::id(),
+                system_program: System::id(),
+            },
+            instruction::AddMint {},
+        )
+    }
+
+    pub fn set_mint_enabled(&self, mint: Pubkey, enabled: bool) -> Instruction {
+        build(
+            accounts::SetMintEnabled {
+                authority: self.authority,
+                vault: self.vault,
+            },
+            instruction::SetMintEnabled { mint, enabled },
+        )
+    }
+
+    pub fn deposit(&self, amount: u64) -> Instruction {
+        build(
+            accounts::Deposit {
+                authority: self.authority,
+                vault: self.vault,
+                mint: self.mint,
+                authority_token_account: self.token_account(&self.authority),
+                vault_token_account: self.vault_token_account,
+                token_program: self.token_program,
+            },
+            instruction::Deposit { amount },
+        )
+    }
+
+    /// Deposit from `depositor`'s own token account, attributed to `user_ref`
+    pub fn user_deposit(&self, depositor: Pubkey, amount: u64, user_ref: [u8; 32]) -> Instruction {
+        build(
+            accounts::UserDeposit {
+                depositor,
+                vault: self.vault,
+                mint: self.mint,
+                depositor_token_account: self.token_account(&depositor),
+                vault_token_account: self.vault_token_account,
+                user_ledger: self.user_ledger_address(&depositor),
+                token_program: self.token_program,
+                system_program: System::id(),
+            },
+            instruction::UserDeposit { amount, user_ref },
+        )
+    }
+
+    /// `proposal` is required once multisig mode is enabled
+    pub fn payout(&self, amount: u64, proposal: Option<Pubkey>) -> Instruction {
+        build(
+            accounts::Payout {
+                authority: self.authority,
+                vault: self.vault,
+                mint: self.mint,
+                vault_token_account: self.vault_token_account,
+                payout_token_account: self.token_account(&self.payout_wallet),
+                payout_wallet: self.payout_wallet,
+                proposal,
+                token_program: self.token_program,
+                associated_token_program: AssociatedToken::id(),
+                system_program: System::id(),
+            },
+            instruction::Payout { amount },
+        )
+    }
+
+    /// Moves `self.mint` to `destination_vault`'s token account for it.
+    /// `proposal` is required in multisig mode.
+    pub fn internal_transfer(
+        &self,
+        destination_vault: Pubkey,
+        amount: u64,
+        proposal: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            accounts::InternalTransfer {
+                authority: self.authority,
+                vault: self.vault,
+                destination_vault,
+                mint: self.mint,
+                vault_token_account: self.vault_token_account,
+                destination_token_account: ata(&destination_vault, &self.mint, &self.token_program),
+                proposal,
+                token_program: self.token_program,
+            },
+            instruction::InternalTransfer { amount },
+        )
+    }
+
+    /// `destination` pays the claim to another wallet the user chose (None =
+    /// the user's own). `proposal` is required in multisig mode for amounts
+    /// above `large_withdrawal_threshold`.
+    pub fn create_withdrawal(
+        &self,
+        user: Pubkey,
+        request_id: u64,
+        amount: u64,
+        expires_in: i64,
+        destination: Option<Pubkey>,
+        proposal: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            accounts::CreateWithdrawal {
+                authority: self.authority,
+                vault: self.vault,
+                mint: self.mint,
+                vault_token_account: self.vault_token_account,
+                user,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+                user_ledger: self.user_ledger_address(&user),
+                proposal,
+                system_program: System::id(),
+            },
+            instruction::CreateWithdrawal {
+                request_id,
+                amount,
+                expires_in,
+                destination,
+            },
+        )
+    }
+
+    /// Creates every request in `withdrawals` (at most `MAX_WITHDRAWAL_BATCH`),
+    /// passing each one's request and ledger PDAs as remaining accounts
+    pub fn create_withdrawals_batch(&self, withdrawals: &[BatchWithdrawal]) -> Instruction {
+        let mut instruction = build(
+            accounts::CreateWithdrawalsBatch {
+                authority: self.authority,
+                vault: self.vault,
+                mint: self.mint,
+                vault_token_account: self.vault_token_account,
+                system_program: System::id(),
+            },
+            instruction::CreateWithdrawalsBatch {
+                withdrawals: withdrawals.to_vec(),
+            },
+        );
+        for withdrawal in withdrawals {
+            instruction.accounts.extend([
+                AccountMeta::new(
+                    self.withdrawal_address(&withdrawal.user, withdrawal.request_id),
+                    false,
+                ),
+                AccountMeta::new(self.user_ledger_address(&withdrawal.user), false),
+            ]);
+        }
+        instruction
+    }
+
+    /// Signed by `user`. `destination` must match the request's.
+    pub fn claim_withdrawal(
+        &self,
+        user: Pubkey,
+        request_id: u64,
+        destination: Option<Pubkey>,
+    ) -> Instruction {
+        let destination = destination.unwrap_or(user);
+        build(
+            accounts::ClaimWithdrawal {
+                user,
+                authority: self.authority,
+                vault: self.vault,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+                user_ledger: self.user_ledger_address(&user),
+                mint: self.mint,
+                vault_token_account: self.vault_token_account,
+                destination,
+                destination_token_account: self.token_account(&destination),
+                token_program: self.token_program,
+                associated_token_program: AssociatedToken::id(),
+                system_program: System::id(),
+            },
+            instruction::ClaimWithdrawal { request_id },
+        )
+    }
+
+    /// Signed by `fee_payer` and `user`. `destination` must match the
+    /// request's. Pass `relayer_token_account` when the request carries a
+    /// relayer fee; `max_fee` bounds it.
+    pub fn claim_withdrawal_relayed(
+        &self,
+        fee_payer: Pubkey,
+        user: Pubkey,
+        request_id: u64,
+        destination: Option<Pubkey>,
+        relayer_token_account: Option<Pubkey>,
+        max_fee: u64,
+    ) -> Instruction {
+        let destination = destination.unwrap_or(user);
+        build(
+            accounts::ClaimWithdrawalRelayed {
+                fee_payer,
+                user,
+                authority: self.authority,
+                vault: self.vault,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+                user_ledger: self.user_ledger_address(&user),
+                mint: self.mint,
+                vault_token_account: self.vault_token_account,
+                destination,
+                destination_token_account: self.token_account(&destination),
+                relayer_token_account,
+                token_program: self.token_program,
+                associated_token_program: AssociatedToken::id(),
+                system_program: System::id(),
+            },
+            instruction::ClaimWithdrawalRelayed {
+                request_id,
+                max_fee,
+            },
+        )
+    }
+
+    /// `relayer_fee` is taken from sponsored claims of the request and sent
+    /// to `relayer_token_account`
+    pub fn set_withdrawal_relayer(
+        &self,
+        user: Pubkey,
+        request_id: u64,
+        relayer_token_account: Option<Pubkey>,
+        relayer_fee: u64,
+    ) -> Instruction {
+        build(
+            accounts::SetWithdrawalRelayer {
+                authority: self.authority,
+                vault: self.vault,
+                user,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+            },
+            instruction::SetWithdrawalRelayer {
+                request_id,
+                relayer_token_account,
+                relayer_fee,
+            },
+        )
+    }
+
+    /// The voucher the backend signs for `claim_with_voucher`
+    pub fn voucher(&self, user: Pubkey, amount: u64, nonce: u64, expires_at: i64) -> Voucher {
+        Voucher {
+            vault: self.vault,
+            user,
+            mint: self.mint,
+            amount,
+            nonce,
+            expires_at,
+        }
+    }
+
+    /// Signed by `user`. Must directly follow [`voucher_signature`] for the
+    /// same voucher in the transaction.
+    pub fn claim_with_voucher(&self, voucher: &Voucher) -> Instruction {
+        build(
+            accounts::ClaimWithVoucher {
+                user: voucher.user,
+                vault: self.vault,
+                voucher_nonces: self.voucher_nonces_address(&voucher.user, voucher.nonce),
+                user_ledger: self.user_ledger_address(&voucher.user),
+                mint: self.mint,
+                vault_token_account: self.vault_token_account,
+                user_token_account: self.token_account(&voucher.user),
+                instructions_sysvar: sysvar::instructions::ID,
+                token_program: self.token_program,
+                associated_token_program: AssociatedToken::id(),
+                system_program: System::id(),
+            },
+            instruction::ClaimWithVoucher {
+                amount: voucher.amount,
+                nonce: voucher.nonce,
+                expires_at: voucher.expires_at,
+            },
+        )
+    }
+
+    pub fn set_crank_config(&self, grace_period: i64, bounty: u64) -> Instruction {
+        build(
+            accounts::SetCrankConfig {
+                authority: self.authority,
+                vault: self.vault,
+            },
+            instruction::SetCrankConfig {
+                grace_period,
+                bounty,
+            },
+        )
+    }
+
+    /// `Pubkey::default()` disables voucher claims
+    pub fn set_voucher_signer(
+        &self,
+        new_voucher_signer: Pubkey,
+        proposal: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            accounts::SetVoucherSigner {
+                authority: self.authority,
+                vault: self.vault,
+                proposal,
+            },
+            instruction::SetVoucherSigner { new_voucher_signer },
+        )
+    }
+
+    pub fn cancel_withdrawal(&self, user: Pubkey, request_id: u64) -> Instruction {
+        build(
+            accounts::CancelWithdrawal {
+                authority: self.authority,
+                vault: self.vault,
+                user,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+                user_ledger: self.user_ledger_address(&user),
+            },
+            instruction::CancelWithdrawal { request_id },
+        )
+    }
+
+    /// Cancels each `(user, request_id)` that has expired; the rest are skipped
+    pub fn cancel_expired_batch(&self, requests: &[(Pubkey, u64)]) -> Instruction {
+        let mut instruction = build(
+            accounts::CancelExpiredBatch {
+                authority: self.authority,
+                vault: self.vault,
+            },
+            instruction::CancelExpiredBatch {},
+        );
+        for (user, request_id) in requests {
+            instruction.accounts.extend([
+                AccountMeta::new(self.withdrawal_address(user, *request_id), false),
+                AccountMeta::new(self.user_ledger_address(user), false),
+            ]);
+        }
+        instruction
+    }
+
+    /// Signed by `cranker`, who earns the vault's crank bounty; allowed once
+    /// the request is past `expires_at + crank_grace_period`
+    pub fn crank_cancel_expired(
+        &self,
+        cranker: Pubkey,
+        user: Pubkey,
+        request_id: u64,
+    ) -> Instruction {
+        build(
+            accounts::CrankCancelExpired {
+                cranker,
+                vault: self.vault,
+                authority: self.authority,
+                user,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+                user_ledger: self.user_ledger_address(&user),
+            },
+            instruction::CrankCancelExpired { request_id },
+        )
+    }
+
+    /// Signed by `user`. `payer` is the request's recorded rent payer;
+    /// `reason` is an app-defined non-zero code.
+    pub fn user_cancel_withdrawal(
+        &self,
+        user: Pubkey,
+        request_id: u64,
+        payer: Pubkey,
+        reason: u8,
+    ) -> Instruction {
+        build(
+            accounts::UserCancelWithdrawal {
+                user,
+                payer,
+                vault: self.vault,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+                user_ledger: self.user_ledger_address(&user),
+            },
+            instruction::UserCancelWithdrawal { request_id, reason },
+        )
+    }
+
+    /// Moves the deadline to `now + expires_in`, which must be later than the current one
+    pub fn extend_withdrawal(&self, user: Pubkey, request_id: u64, expires_in: i64) -> Instruction {
+        build(
+            accounts::ExtendWithdrawal {
+                authority: self.authority,
+                vault: self.vault,
+                user,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+            },
+            instruction::ExtendWithdrawal {
+                request_id,
+                expires_in,
+            },
+        )
+    }
+
+    /// `amount` must be lower than the request's current amount
+    pub fn amend_withdrawal(&self, user: Pubkey, request_id: u64, amount: u64) -> Instruction {
+        build(
+            accounts::AmendWithdrawal {
+                authority: self.authority,
+                vault: self.vault,
+                user,
+                withdrawal_request: self.withdrawal_address(&user, request_id),
+                user_ledger: self.user_ledger_address(&user),
+            },
+            instruction::AmendWithdrawal { request_id, amount },
+        )
+    }
+
+    pub fn propose_authority(&self, new_authority: Pubkey) -> Instruction {
+        build(
+            accounts::ProposeAuthority {
+                authority: self.authority,
+                vault: self.vault,
+            },
+            instruction::ProposeAuthority { new_authority },
+        )
+    }
+
+    /// Signed by the pending authority
+    pub fn accept_authority(&self, new_authority: Pubkey) -> Instruction {
+        build(
+            accounts::AcceptAuthority {
+                new_authority,
+                vault: self.vault,
+            },
+            instruction::AcceptAuthority {},
+        )
+    }
+
+    pub fn configure_multisig(
+        &self,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        large_withdrawal_threshold: u64,
+        proposal: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            accounts::ConfigureMultisig {
+                authority: self.authority,
+                vault: self.vault,
+                proposal,
+            },
+            instruction::ConfigureMultisig {
+                signers,
+                threshold,
+                large_withdrawal_threshold,
+            },
+        )
+    }
+
+    /// `proposal_id` must be the vault's current `proposal_count`
+    pub fn create_proposal(
+        &self,
+        proposer: Pubkey,
+        proposal_id: u64,
+        action: ProposalAction,
+    ) -> Instruction {
+        build(
+            accounts::CreateProposal {
+                proposer,
+                vault: self.vault,
+                proposal: self.proposal_address(proposal_id),
+                system_program: System::id(),
+            },
+            instruction::CreateProposal { action },
+        )
+    }
+
+    pub fn approve_proposal(&self, signer: Pubkey, proposal_id: u64) -> Instruction {
+        build(
+            accounts::ApproveProposal {
+                signer,
+                vault: self.vault,
+                proposal: self.proposal_address(proposal_id),
+            },
+            instruction::ApproveProposal {},
+        )
+    }
+
+    /// Closes an executed or stale proposal; rent goes back to `proposer`
+    pub fn close_proposal(&self, proposer: Pubkey, proposal_id: u64) -> Instruction {
+        build(
+            accounts::CloseProposal {
+                vault: self.vault,
+                proposer,
+                proposal: self.proposal_address(proposal_id),
+            },
+            instruction::CloseProposal {},
+        )
+    }
+
+    pub fn set_rate_limits(
+        &self,
+        max_payout_per_tx: u64,
+        max_outflow_per_window: u64,
+        max_withdrawal_amount: u64,
+        proposal: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            accounts::SetRateLimits {
+                authority: self.authority,
+                vault: self.vault,
+                proposal,
+            },
+            instruction::SetRateLimits {
+                max_payout_per_tx,
+                max_outflow_per_window,
+                max_withdrawal_amount,
+            },
+        )
+    }
+
+    pub fn pause(&self) -> Instruction {
+        build(self.set_paused_accounts(), instruction::Pause {})
+    }
+
+    pub fn unpause(&self) -> Instruction {
+        build(self.set_paused_accounts(), instruction::Unpause {})
+    }
+
+    fn set_paused_accounts(&self) -> accounts::SetPaused {
+        accounts::SetPaused {
+            guardian: self.guardian,
+            vault: self.vault,
+        }
+    }
+
+    /// Takes effect through [`VaultKeys::apply_transfer_allowlist`] once the
+    /// timelock has elapsed
+    pub fn propose_transfer_allowlist(
+        &self,
+        transfer_allowlist: Vec<Pubkey>,
+        proposal: Option<Pubkey>,
+    ) -> Instruction {
+        build(
+            accounts::ProposeTransferAllowlist {
+                authority: self.authority,
+                vault: self.vault,
+                proposal,
+            },
+            instruction::ProposeTransferAllowlist { transfer_allowlist },
+        )
+    }
+
+    pub fn apply_transfer_allowlist(&self, proposal: Option<Pubkey>) -> Instruction {
+        build(
+            accounts::ApplyTransferAllowlist {
+                authority: self.authority,
+                vault: self.vault,
+                proposal,
+            },
+            instruction::ApplyTransferAllowlist {},
+        )
+    }
+
+    /// Signed by the guardian or the authority
+    pub fn cancel_transfer_allowlist(&self, signer: Pubkey) -> Instruction {
+        build(
+            accounts::CancelTransferAllowlist {
+                signer,
+                vault: self.vault,
+            },
+            instruction::CancelTransferAllowlist {},
+        )
+    }
+
+    pub fn set_guardian(&self, new_guardian: Pubkey) -> Instruction {
+        build(
+            accounts::SetGuardian {
+                guardian: self.guardian,
+                vault: self.vault,
+            },
+            instruction::SetGuardian { new_guardian },
+        )
+    }
+
+    /// Build from primary-mint keys; `mint_token_accounts` are the token
+    /// accounts of `vault.mints[1..]`, in registry order
+    pub fn commit_liabilities(
+        &self,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        total_liabilities: u64,
+        leaf_count: u64,
+        mint_token_accounts: &[Pubkey],
+    ) -> Instruction {
+        let mut instruction = build(
+            accounts::CommitLiabilities {
+                authority: self.authority,
+                vault: self.vault,
+                vault_token_account: self.vault_token_account,
+                liability_snapshot: liability_snapshot_address(&self.vault, epoch).0,
+                system_program: System::id(),
+            },
+            instruction::CommitLiabilities {
+                epoch,
+                merkle_root,
+                total_liabilities,
+                leaf_count,
+            },
+        );
+        instruction.accounts.extend(
+            mint_token_accounts
+                .iter()
+                .map(|token_account| AccountMeta::new_readonly(*token_account, false)),
+        );
+        instruction
+    }
+}
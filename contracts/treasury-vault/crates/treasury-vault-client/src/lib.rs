@@ -0,0 +1,23 @@
+//! Rust client for the Treasury Vault program.
+//!
+//! One source of truth for PDA derivation, instruction encoding, account
+//! decoding and error-code mapping, so Rust services and tests don't
+//! re-derive them from the TS IDL copy.
+
+pub mod decode;
+pub mod errors;
+pub mod instructions;
+pub mod pda;
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+pub use treasury_vault::errors::TreasuryError;
+pub use treasury_vault::state::{
+    BatchWithdrawal, LiabilitySnapshot, MintBalance, Proposal, ProposalAction, TreasuryVault,
+    UserLedger, VaultMint, VoucherNonces, WithdrawalRequest,
+};
+pub use treasury_vault::voucher::Voucher;
+pub use treasury_vault::ID as PROGRAM_ID;
+
+pub use decode::DecodeError;
+pub use instructions::VaultKeys;
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::PROGRAM_ID;
+
+/// Vault PDA: `["treasury_vault", seed_key, vault_id_le]`.
+/// `seed_key` is the authority that called `initialize` and `vault_id` the
+/// id it chose; neither ever changes.
+pub fn vault_address(seed_key: &Pubkey, vault_id: u16) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"treasury_vault",
+            seed_key.as_ref(),
+            &vault_id.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+}
+
+/// Withdrawal request PDA: `["withdrawal", vault, user, request_id_le]`
+pub fn withdrawal_address(vault: &Pubkey, user: &Pubkey, request_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"withdrawal",
+            vault.as_ref(),
+            user.as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+}
+
+/// Per-user ledger PDA: `["user_ledger", vault, user]`
+pub fn user_ledger_address(vault: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"user_ledger", vault.as_ref(), user.as_ref()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Voucher nonce bitmap PDA: `["voucher_nonces", vault, user, page_le]`,
+/// where `page = nonce / VOUCHER_NONCES_PER_PAGE`
+pub fn voucher_nonces_address(vault: &Pubkey, user: &Pubkey, page: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"voucher_nonces",
+            vault.as_ref(),
+            user.as_ref(),
+            &page.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+}
+
+/// Multisig proposal PDA: `["proposal", vault, proposal_id_le]`
+pub fn proposal_address(vault: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"proposal", vault.as_ref(), &proposal_id.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
+
+/// Liability snapshot PDA: `["liabilities", vault, epoch_le]`
+pub fn liability_snapshot_address(vault: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"liabilities", vault.as_ref(), &epoch.to_le_bytes()],
+        &PROGRAM_ID,
+    )
+}
@@ -0,0 +1,448 @@
+//! Minimal blocking JSON-RPC client, enough for operator tooling.
+//! Transactions are passed in as serialized bytes so this module stays
+//! independent of any particular signer / transaction crate.
+
+use std::fmt;
+use std::str::FromStr;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::hash::Hash;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::Discriminator;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+
+use crate::decode::{
+    decode_vault, decode_withdrawal_request, WITHDRAWAL_REQUEST_USER_OFFSET,
+    WITHDRAWAL_REQUEST_VAULT_OFFSET,
+};
+use crate::{TreasuryVault, WithdrawalRequest, PROGRAM_ID};
+
+#[derive(Debug)]
+pub enum RpcError {
+    Transport(String),
+    /// JSON-RPC error object. Preflight failures carry simulation logs.
+    Rpc {
+        code: i64,
+        message: String,
+        logs: Vec<String>,
+    },
+    InvalidResponse(String),
+    /// Transaction landed but failed
+    TransactionFailed(String),
+    Timeout(String),
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcError::Transport(err) => write!(f, "transport error: {err}"),
+            RpcError::Rpc { code, message, .. } => write!(f, "rpc error {code}: {message}"),
+            RpcError::InvalidResponse(err) => write!(f, "invalid rpc response: {err}"),
+            RpcError::TransactionFailed(err) => write!(f, "transaction failed: {err}"),
+            RpcError::Timeout(signature) => write!(f, "timed out confirming {signature}"),
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+pub type RpcResult<T> = Result<T, RpcError>;
+
+#[derive(Debug, Clone)]
+pub struct RpcAccount {
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Filter {
+    DataSize(u64),
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    /// `None` when the simulated transaction succeeded
+    pub err: Option<Value>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+/// Entry of `getSignaturesForAddress`
+#[derive(Debug, Clone)]
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub failed: bool,
+}
+
+/// The parts of `getTransaction` an event consumer needs
+#[derive(Debug, Clone)]
+pub struct TransactionLogs {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub failed: bool,
+    pub logs: Vec<String>,
+}
+
+pub struct RpcClient {
+    url: String,
+    commitment: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    /// Client at `confirmed` commitment
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::new_with_commitment(url, "confirmed")
+    }
+
+    pub fn new_with_commitment(url: impl Into<String>, commitment: &str) -> Self {
+        Self {
+            url: url.into(),
+            commitment: commitment.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn call(&self, method: &str, params: Value) -> RpcResult<Value> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = match self.agent.post(&self.url).send_json(request) {
+            Ok(response) => response,
+            // Error bodies are still JSON-RPC responses
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(RpcError::Transport(err.to_string())),
+        }
+        .into_json()
+        .map_err(|err| RpcError::InvalidResponse(err.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            let logs = string_array(error.pointer("/data/logs"));
+            return Err(RpcError::Rpc {
+                code: error
+                    .get("code")
+                    .and_then(Value::as_i64)
+                    .unwrap_or_default(),
+                message: error
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                logs,
+            });
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| RpcError::InvalidResponse(format!("{method}: missing result")))
+    }
+
+    pub fn get_account(&self, address: &Pubkey) -> RpcResult<Option<RpcAccount>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": self.commitment }]),
+        )?;
+        match result.get("value") {
+            Some(Value::Null) | None => Ok(None),
+            Some(value) => parse_account(value).map(Some),
+        }
+    }
+
+    pub fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[Filter],
+    ) -> RpcResult<Vec<(Pubkey, RpcAccount)>> {
+        let filters: Vec<Value> = filters
+            .iter()
+            .map(|filter| match filter {
+                Filter::DataSize(size) => json!({ "dataSize": size }),
+                Filter::Memcmp { offset, bytes } => json!({
+                    "memcmp": { "offset": offset, "bytes": BASE64.encode(bytes), "encoding": "base64" }
+                }),
+            })
+            .collect();
+        let result = self.call(
+            "getProgramAccounts",
+            json!([program_id.to_string(), {
+                "encoding": "base64",
+                "commitment": self.commitment,
+                "filters": filters,
+            }]),
+        )?;
+        result
+            .as_array()
+            .ok_or_else(|| RpcError::InvalidResponse("getProgramAccounts: not an array".into()))?
+            .iter()
+            .map(|entry| {
+                let pubkey = parse_pubkey(entry.get("pubkey"))?;
+                let account = parse_account(entry.get("account").unwrap_or(&Value::Null))?;
+                Ok((pubkey, account))
+            })
+            .collect()
+    }
+
+    pub fn get_latest_blockhash(&self) -> RpcResult<Hash> {
+        let result = self.call(
+            "getLatestBlockhash",
+            json!([{ "commitment": self.commitment }]),
+        )?;
+        let blockhash = result
+            .pointer("/value/blockhash")
+            .and_then(Value::as_str)
+            .ok_or_else(|| RpcError::InvalidResponse("getLatestBlockhash".into()))?;
+        Hash::from_str(blockhash).map_err(|err| RpcError::InvalidResponse(err.to_string()))
+    }
+
+    /// Token account balance in base units
+    pub fn get_token_balance(&self, token_account: &Pubkey) -> RpcResult<u64> {
+        let result = self.call(
+            "getTokenAccountBalance",
+            json!([token_account.to_string(), { "commitment": self.commitment }]),
+        )?;
+        result
+            .pointer("/value/amount")
+            .and_then(Value::as_str)
+            .and_then(|amount| amount.parse().ok())
+            .ok_or_else(|| RpcError::InvalidResponse("getTokenAccountBalance".into()))
+    }
+
+    /// Cluster clock, the same one `Clock::get()` sees on-chain
+    pub fn get_clock(&self) -> RpcResult<Clock> {
+        let account = self
+            .get_account(&sysvar::clock::ID)?
+            .ok_or_else(|| RpcError::InvalidResponse("clock sysvar missing".into()))?;
+        if account.data.len() < 40 {
+            return Err(RpcError::InvalidResponse("clock sysvar too short".into()));
+        }
+        let field = |i: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&account.data[i * 8..i * 8 + 8]);
+            bytes
+        };
+        Ok(Clock {
+            slot: u64::from_le_bytes(field(0)),
+            epoch_start_timestamp: i64::from_le_bytes(field(1)),
+            epoch: u64::from_le_bytes(field(2)),
+            leader_schedule_epoch: u64::from_le_bytes(field(3)),
+            unix_timestamp: i64::from_le_bytes(field(4)),
+        })
+    }
+
+    /// Simulate a signed transaction without sending it
+    pub fn simulate_transaction(&self, transaction: &[u8]) -> RpcResult<Simulation> {
+        let result = self.call(
+            "simulateTransaction",
+            json!([BASE64.encode(transaction), {
+                "encoding": "base64",
+                "commitment": self.commitment,
+                "sigVerify": false,
+                "replaceRecentBlockhash": true,
+            }]),
+        )?;
+        let value = result.get("value").cloned().unwrap_or(Value::Null);
+        Ok(Simulation {
+            err: value.get("err").filter(|err| !err.is_null()).cloned(),
+            logs: string_array(value.get("logs")),
+            units_consumed: value.get("unitsConsumed").and_then(Value::as_u64),
+        })
+    }
+
+    /// Send a signed transaction; returns its signature
+    pub fn send_transaction(&self, transaction: &[u8]) -> RpcResult<String> {
+        let result = self.call(
+            "sendTransaction",
+            json!([BASE64.encode(transaction), {
+                "encoding": "base64",
+                "preflightCommitment": self.commitment,
+            }]),
+        )?;
+        result
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| RpcError::InvalidResponse("sendTransaction".into()))
+    }
+
+    /// Poll until the signature reaches the client's commitment
+    pub fn confirm_transaction(&self, signature: &str, timeout: Duration) -> RpcResult<()> {
+        let started = Instant::now();
+        while started.elapsed() < timeout {
+            let result = self.call("getSignatureStatuses", json!([[signature]]))?;
+            if let Some(status) = result.pointer("/value/0").filter(|s| !s.is_null()) {
+                if let Some(err) = status.get("err").filter(|err| !err.is_null()) {
+                    return Err(RpcError::TransactionFailed(err.to_string()));
+                }
+                let reached = match status.get("confirmationStatus").and_then(Value::as_str) {
+                    Some("finalized") => true,
+                    Some("confirmed") => self.commitment != "finalized",
+                    Some(_) => self.commitment == "processed",
+                    None => false,
+                };
+                if reached {
+                    return Ok(());
+                }
+            }
+            sleep(Duration::from_millis(500));
+        }
+        Err(RpcError::Timeout(signature.to_string()))
+    }
+
+    /// Signatures touching `address`, newest first. `before` / `until` are
+    /// exclusive bounds; at most `limit` (max 1000) entries are returned.
+    pub fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> RpcResult<Vec<SignatureInfo>> {
+        let mut config = json!({ "commitment": self.commitment, "limit": limit });
+        if let Some(before) = before {
+            config["before"] = json!(before);
+        }
+        if let Some(until) = until {
+            config["until"] = json!(until);
+        }
+        let result = self.call(
+            "getSignaturesForAddress",
+            json!([address.to_string(), config]),
+        )?;
+        result
+            .as_array()
+            .ok_or_else(|| RpcError::InvalidResponse("getSignaturesForAddress".into()))?
+            .iter()
+            .map(|entry| {
+                Ok(SignatureInfo {
+                    signature: entry
+                        .get("signature")
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| RpcError::InvalidResponse("missing signature".into()))?
+                        .to_string(),
+                    slot: entry
+                        .get("slot")
+                        .and_then(Value::as_u64)
+                        .unwrap_or_default(),
+                    block_time: entry.get("blockTime").and_then(Value::as_i64),
+                    failed: entry.get("err").is_some_and(|err| !err.is_null()),
+                })
+            })
+            .collect()
+    }
+
+    /// `None` if the transaction is unknown at the client's commitment
+    pub fn get_transaction(&self, signature: &str) -> RpcResult<Option<TransactionLogs>> {
+        let result = self.call(
+            "getTransaction",
+            json!([signature, {
+                "encoding": "json",
+                "commitment": self.commitment,
+                "maxSupportedTransactionVersion": 0,
+            }]),
+        )?;
+        if result.is_null() {
+            return Ok(None);
+        }
+        let meta = result.get("meta").cloned().unwrap_or(Value::Null);
+        Ok(Some(TransactionLogs {
+            signature: signature.to_string(),
+            slot: result
+                .get("slot")
+                .and_then(Value::as_u64)
+                .unwrap_or_default(),
+            block_time: result.get("blockTime").and_then(Value::as_i64),
+            failed: meta.get("err").is_some_and(|err| !err.is_null()),
+            logs: string_array(meta.get("logMessages")),
+        }))
+    }
+
+    pub fn get_vault(&self, vault: &Pubkey) -> RpcResult<Option<TreasuryVault>> {
+        self.get_account(vault)?
+            .map(|account| {
+                decode_vault(&account.data)
+                    .map_err(|err| RpcError::InvalidResponse(err.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Open withdrawal requests of a vault, optionally for one user
+    pub fn get_withdrawal_requests(
+        &self,
+        vault: &Pubkey,
+        user: Option<&Pubkey>,
+    ) -> RpcResult<Vec<(Pubkey, WithdrawalRequest)>> {
+        let mut filters = vec![
+            Filter::Memcmp {
+                offset: 0,
+                bytes: WithdrawalRequest::DISCRIMINATOR.to_vec(),
+            },
+            Filter::Memcmp {
+                offset: WITHDRAWAL_REQUEST_VAULT_OFFSET,
+                bytes: vault.to_bytes().to_vec(),
+            },
+        ];
+        if let Some(user) = user {
+            filters.push(Filter::Memcmp {
+                offset: WITHDRAWAL_REQUEST_USER_OFFSET,
+                bytes: user.to_bytes().to_vec(),
+            });
+        }
+        self.get_program_accounts(&PROGRAM_ID, &filters)?
+            .into_iter()
+            .map(|(address, account)| {
+                decode_withdrawal_request(&account.data)
+                    .map(|request| (address, request))
+                    .map_err(|err| RpcError::InvalidResponse(err.to_string()))
+            })
+            .collect()
+    }
+}
+
+fn string_array(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_pubkey(value: Option<&Value>) -> RpcResult<Pubkey> {
+    value
+        .and_then(Value::as_str)
+        .and_then(|s| Pubkey::from_str(s).ok())
+        .ok_or_else(|| RpcError::InvalidResponse("expected base58 pubkey".into()))
+}
+
+fn parse_account(value: &Value) -> RpcResult<RpcAccount> {
+    let data = value
+        .pointer("/data/0")
+        .and_then(Value::as_str)
+        .ok_or_else(|| RpcError::InvalidResponse("expected base64 account data".into()))?;
+    Ok(RpcAccount {
+        lamports: value
+            .get("lamports")
+            .and_then(Value::as_u64)
+            .unwrap_or_default(),
+        owner: parse_pubkey(value.get("owner"))?,
+        data: BASE64
+            .decode(data)
+            .map_err(|err| RpcError::InvalidResponse(err.to_string()))?,
+    })
+}
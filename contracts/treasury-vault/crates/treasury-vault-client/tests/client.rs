@@ -0,0 +1,168 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountSerialize, Discriminator};
+use treasury_vault_client::{
+    decode, errors, instructions, pda, TreasuryError, VaultKeys, WithdrawalRequest, PROGRAM_ID,
+};
+
+fn keys() -> VaultKeys {
+    let authority = Pubkey::new_unique();
+    VaultKeys {
+        vault: pda::vault_address(&authority, 0).0,
+        vault_id: 0,
+        authority,
+        guardian: Pubkey::new_unique(),
+        payout_wallet: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        vault_token_account: Pubkey::new_unique(),
+        token_program: anchor_spl::token::ID,
+    }
+}
+
+#[test]
+fn vault_address_is_unique_per_vault_id() {
+    let authority = Pubkey::new_unique();
+    let treasury = pda::vault_address(&authority, 0).0;
+    let jackpot = pda::vault_address(&authority, 1).0;
+    assert_ne!(treasury, jackpot);
+    assert_ne!(treasury, pda::vault_address(&Pubkey::new_unique(), 0).0);
+}
+
+#[test]
+fn withdrawal_address_is_unique_per_request_id() {
+    let keys = keys();
+    let user = Pubkey::new_unique();
+    let a = pda::withdrawal_address(&keys.vault, &user, 1).0;
+    let b = pda::withdrawal_address(&keys.vault, &user, 2).0;
+    assert_ne!(a, b);
+    assert_eq!(keys.withdrawal_address(&user, 1), a);
+}
+
+#[test]
+fn builders_fill_pdas_and_optional_accounts() {
+    let keys = keys();
+    let user = Pubkey::new_unique();
+
+    let ix = keys.create_withdrawal(user, 7, 1_000_000, 3600, None, None);
+    assert_eq!(ix.program_id, PROGRAM_ID);
+    assert_eq!(ix.accounts[1].pubkey, keys.vault);
+    assert_eq!(ix.accounts[5].pubkey, keys.withdrawal_address(&user, 7));
+    assert_eq!(ix.accounts[6].pubkey, keys.user_ledger_address(&user));
+    // Absent optional accounts are encoded as the program id
+    assert_eq!(ix.accounts[7].pubkey, PROGRAM_ID);
+    assert!(ix.accounts[0].is_signer);
+
+    let proposal = keys.proposal_address(0);
+    let ix = keys.payout(5, Some(proposal));
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == proposal));
+
+    let ix = instructions::initialize(
+        keys.authority,
+        keys.vault_id,
+        keys.mint,
+        keys.payout_wallet,
+        keys.guardian,
+        Vec::new(),
+        keys.token_program,
+    );
+    assert_eq!(ix.accounts[1].pubkey, keys.vault);
+}
+
+#[test]
+fn with_mint_targets_the_mints_vault_token_account() {
+    let keys = keys();
+    let usdc = Pubkey::new_unique();
+    let usdc_keys = keys.with_mint(usdc, anchor_spl::token_2022::ID);
+    assert_eq!((usdc_keys.vault, usdc_keys.mint), (keys.vault, usdc));
+    assert_eq!(
+        usdc_keys.vault_token_account,
+        anchor_spl::associated_token::get_associated_token_address_with_program_id(
+            &keys.vault,
+            &usdc,
+            &anchor_spl::token_2022::ID,
+        )
+    );
+
+    let ix = usdc_keys.deposit(5);
+    assert_eq!(ix.accounts[2].pubkey, usdc);
+    assert_eq!(ix.accounts[4].pubkey, usdc_keys.vault_token_account);
+    let voucher = usdc_keys.voucher(Pubkey::new_unique(), 5, 0, 100);
+    assert_eq!(voucher.mint, usdc);
+}
+
+#[test]
+fn decodes_withdrawal_request() {
+    let request = WithdrawalRequest {
+        vault: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        request_id: 3,
+        amount: 42,
+        created_at: 100,
+        expires_at: 200,
+        mint: Pubkey::new_unique(),
+        destination: None,
+        relayer_token_account: Some(Pubkey::new_unique()),
+        relayer_fee: 7,
+        payer: Pubkey::new_unique(),
+        bump: 254,
+    };
+    let mut data = Vec::new();
+    request.try_serialize(&mut data).unwrap();
+
+    let decoded = decode::decode_withdrawal_request(&data).unwrap();
+    assert_eq!(decoded.request_id, 3);
+    assert_eq!(decoded.amount, 42);
+    assert_eq!(decoded.relayer_fee, 7);
+
+    data[..8].copy_from_slice(&[0; 8]);
+    assert!(decode::decode_withdrawal_request(&data).is_err());
+    assert_ne!(WithdrawalRequest::DISCRIMINATOR, &[0; 8]);
+}
+
+#[test]
+fn withdrawal_request_filter_offsets_match_the_layout() {
+    let request = WithdrawalRequest {
+        vault: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        request_id: u64::MAX,
+        amount: u64::MAX,
+        created_at: -1,
+        expires_at: -1,
+        mint: Pubkey::new_unique(),
+        destination: Some(Pubkey::new_unique()),
+        relayer_token_account: Some(Pubkey::new_unique()),
+        relayer_fee: u64::MAX,
+        payer: Pubkey::new_unique(),
+        bump: 255,
+    };
+    let mut data = Vec::new();
+    request.try_serialize(&mut data).unwrap();
+
+    let field = |offset: usize| &data[offset..offset + 32];
+    assert_eq!(
+        field(decode::WITHDRAWAL_REQUEST_VAULT_OFFSET),
+        request.vault.as_ref()
+    );
+    assert_eq!(
+        field(decode::WITHDRAWAL_REQUEST_USER_OFFSET),
+        request.user.as_ref()
+    );
+    assert_eq!(decode::WITHDRAWAL_REQUEST_VAULT_OFFSET, 8);
+    assert_eq!(decode::WITHDRAWAL_REQUEST_USER_OFFSET, 40);
+}
+
+#[test]
+fn maps_custom_error_codes() {
+    assert!(matches!(
+        errors::from_code(6000),
+        Some(TreasuryError::Unauthorized)
+    ));
+    for error in errors::ALL_ERRORS {
+        let code = u32::from(*error);
+        assert_eq!(errors::from_code(code).map(u32::from), Some(code));
+    }
+    assert!(errors::from_code(42).is_none());
+
+    let code = u32::from(TreasuryError::VaultPaused);
+    let log = format!("Program log: failed: custom program error: {:#x}", code);
+    assert_eq!(errors::from_log(&log).map(u32::from), Some(code));
+}
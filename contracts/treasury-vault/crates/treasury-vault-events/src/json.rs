@@ -0,0 +1,136 @@
+use anchor_lang::prelude::Pubkey;
+use serde_json::{json, Value};
+use treasury_vault::state::ProposalAction;
+
+pub(crate) trait ToJson {
+    fn to_json(&self) -> Value;
+}
+
+macro_rules! number_to_json {
+    ($($ty:ty),*) => {
+        $(impl ToJson for $ty {
+            fn to_json(&self) -> Value {
+                json!(self)
+            }
+        })*
+    };
+}
+
+number_to_json!(u8, u16, u32, i64, bool);
+
+/// Amounts and ids can exceed 2^53, past which JavaScript loses precision
+impl ToJson for u64 {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl ToJson for Pubkey {
+    fn to_json(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl ToJson for [u8] {
+    fn to_json(&self) -> Value {
+        Value::String(self.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+}
+
+impl<const N: usize> ToJson for [u8; N] {
+    fn to_json(&self) -> Value {
+        self.as_slice().to_json()
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> Value {
+        self.as_ref().map_or(Value::Null, ToJson::to_json)
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn to_json(&self) -> Value {
+        Value::Array(self.iter().map(ToJson::to_json).collect())
+    }
+}
+
+impl ToJson for ProposalAction {
+    fn to_json(&self) -> Value {
+        match self {
+            ProposalAction::Payout { mint, amount } => json!({
+                "type": "Payout",
+                "mint": mint.to_string(),
+                "amount": amount.to_json(),
+            }),
+            ProposalAction::CreateWithdrawal {
+                mint,
+                user,
+                request_id,
+                amount,
+                expires_in,
+                destination,
+            } => json!({
+                "type": "CreateWithdrawal",
+                "mint": mint.to_string(),
+                "user": user.to_string(),
+                "request_id": request_id.to_json(),
+                "amount": amount.to_json(),
+                "expires_in": expires_in,
+                "destination": destination.to_json(),
+            }),
+            ProposalAction::ConfigureMultisig {
+                signers,
+                threshold,
+                large_withdrawal_threshold,
+            } => json!({
+                "type": "ConfigureMultisig",
+                "signers": signers.to_json(),
+                "threshold": threshold,
+                "large_withdrawal_threshold": large_withdrawal_threshold.to_json(),
+            }),
+            ProposalAction::SetRateLimits {
+                max_payout_per_tx,
+                max_outflow_per_window,
+                max_withdrawal_amount,
+            } => json!({
+                "type": "SetRateLimits",
+                "max_payout_per_tx": max_payout_per_tx.to_json(),
+                "max_outflow_per_window": max_outflow_per_window.to_json(),
+                "max_withdrawal_amount": max_withdrawal_amount.to_json(),
+            }),
+            ProposalAction::InternalTransfer {
+                mint,
+                destination_vault,
+                amount,
+            } => json!({
+                "type": "InternalTransfer",
+                "mint": mint.to_string(),
+                "destination_vault": destination_vault.to_string(),
+                "amount": amount.to_json(),
+            }),
+            ProposalAction::SetVoucherSigner { voucher_signer } => json!({
+                "type": "SetVoucherSigner",
+                "voucher_signer": voucher_signer.to_string(),
+            }),
+            ProposalAction::ProposeTransferAllowlist { transfer_allowlist } => json!({
+                "type": "ProposeTransferAllowlist",
+                "transfer_allowlist": transfer_allowlist.to_json(),
+            }),
+            ProposalAction::ApplyTransferAllowlist { transfer_allowlist } => json!({
+                "type": "ApplyTransferAllowlist",
+                "transfer_allowlist": transfer_allowlist.to_json(),
+            }),
+            ProposalAction::AddMint { mint } => json!({
+                "type": "AddMint",
+                "mint": mint.to_string(),
+            }),
+        }
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn to_json(&self) -> Value {
+        (**self).to_json()
+    }
+}
@@ -0,0 +1,231 @@
+//! Decodes `emit!`-ed Treasury Vault events from transaction log lines.
+//!
+//! Anchor writes each event as `Program data: <base64>` where the payload is
+//! the 8-byte event discriminator followed by the Borsh-encoded struct.
+//! Only lines logged while the vault program itself is executing are decoded,
+//! so a CPI'd program can't spoof vault events.
+
+mod json;
+
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{Map, Value};
+use treasury_vault::events::*;
+
+use crate::json::ToJson;
+
+pub use treasury_vault::ID as PROGRAM_ID;
+
+const DATA_PREFIX: &str = "Program data: ";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventError {
+    InvalidBase64(String),
+    InvalidEvent {
+        name: &'static str,
+        reason: String,
+    },
+    /// Logs ended up with an unbalanced invoke / success stack
+    MalformedLogs(String),
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventError::InvalidBase64(err) => write!(f, "invalid base64 event data: {err}"),
+            EventError::InvalidEvent { name, reason } => write!(f, "invalid {name}: {reason}"),
+            EventError::MalformedLogs(line) => write!(f, "malformed logs at {line:?}"),
+        }
+    }
+}
+
+impl std::error::Error for EventError {}
+
+macro_rules! vault_events {
+    ($($name:ident { $($field:ident),* $(,)? }),* $(,)?) => {
+        /// Every event the vault program emits
+        pub enum VaultEvent {
+            $($name($name),)*
+            /// Discriminator not known to this decoder (newer program version)
+            Unknown { discriminator: [u8; 8], data: Vec<u8> },
+        }
+
+        impl VaultEvent {
+            /// Decodes a raw event payload (discriminator + Borsh data)
+            pub fn decode(data: &[u8]) -> Result<Self, EventError> {
+                if data.len() < 8 {
+                    return Err(EventError::InvalidEvent {
+                        name: "event",
+                        reason: "shorter than discriminator".into(),
+                    });
+                }
+                let (discriminator, mut body) = data.split_at(8);
+                $(
+                    if discriminator == $name::DISCRIMINATOR {
+                        return $name::deserialize(&mut body)
+                            .map(VaultEvent::$name)
+                            .map_err(|err| EventError::InvalidEvent {
+                                name: stringify!($name),
+                                reason: err.to_string(),
+                            });
+                    }
+                )*
+                let mut unknown = [0u8; 8];
+                unknown.copy_from_slice(discriminator);
+                Ok(VaultEvent::Unknown { discriminator: unknown, data: body.to_vec() })
+            }
+
+            /// Event struct name, e.g. `DepositEvent`
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(VaultEvent::$name(_) => stringify!($name),)*
+                    VaultEvent::Unknown { .. } => "Unknown",
+                }
+            }
+
+            /// Event fields as a JSON object. Pubkeys are base58, byte
+            /// arrays hex, `u64` amounts and ids decimal strings, other
+            /// integers plain JSON numbers.
+            pub fn to_json(&self) -> Value {
+                let mut map = Map::new();
+                match self {
+                    $(
+                        VaultEvent::$name(event) => {
+                            // Destructuring fails to compile if a field is missing here
+                            let $name { $($field),* } = event;
+                            $(map.insert(stringify!($field).into(), $field.to_json());)*
+                        }
+                    )*
+                    VaultEvent::Unknown { discriminator, data } => {
+                        map.insert("discriminator".into(), discriminator.to_json());
+                        map.insert("data".into(), data.as_slice().to_json());
+                    }
+                }
+                Value::Object(map)
+            }
+        }
+    };
+}
+
+vault_events! {
+    VaultInitialized {
+        vault, vault_id, authority, payout_wallet, guardian, usdt_mint, transfer_allowlist, timestamp,
+    },
+    DepositEvent { vault, mint, amount, total_deposited, deposit_count, timestamp },
+    UserDepositEvent {
+        vault, mint, depositor, user_ref, amount, total_deposited, deposit_count, timestamp,
+    },
+    PayoutEvent { vault, mint, payout_wallet, amount, total_paid_out, payout_count, timestamp },
+    WithdrawalCreatedEvent {
+        vault, mint, user, destination, request_id, amount, expires_at, total_reserved, timestamp,
+    },
+    WithdrawalClaimedEvent {
+        vault, mint, user, destination, request_id, amount, total_paid_out, total_reserved, payout_count, timestamp,
+    },
+    WithdrawalCancelledEvent { vault, user, request_id, amount, total_reserved, reason, timestamp },
+    WithdrawalExtendedEvent { vault, user, request_id, previous_expires_at, expires_at, timestamp },
+    WithdrawalAmendedEvent {
+        vault, user, request_id, previous_amount, amount, total_reserved, timestamp,
+    },
+    WithdrawalRelayerSetEvent {
+        vault, user, request_id, relayer_token_account, relayer_fee, timestamp,
+    },
+    RelayerFeePaidEvent {
+        vault, user, request_id, fee_payer, relayer_token_account, fee, timestamp,
+    },
+    CrankBountyPaidEvent { vault, user, request_id, cranker, bounty, timestamp },
+    VoucherClaimedEvent {
+        vault, mint, user, nonce, amount, expires_at, total_paid_out, payout_count, timestamp,
+    },
+    AuthorityProposedEvent { vault, authority, pending_authority, timestamp },
+    AuthorityTransferredEvent { vault, previous_authority, new_authority, timestamp },
+    MultisigConfiguredEvent {
+        vault, signers, threshold, large_withdrawal_threshold, multisig_version, timestamp,
+    },
+    ProposalCreatedEvent { vault, proposal, proposal_id, proposer, action, timestamp },
+    ProposalApprovedEvent {
+        vault, proposal, proposal_id, signer, approval_count, threshold, timestamp,
+    },
+    ProposalExecutedEvent { vault, proposal, proposal_id, approval_count, timestamp },
+    RateLimitsUpdatedEvent {
+        vault, max_payout_per_tx, max_outflow_per_window, max_withdrawal_amount, timestamp,
+    },
+    VaultPausedEvent { vault, guardian, timestamp },
+    VaultUnpausedEvent { vault, guardian, timestamp },
+    GuardianChangedEvent { vault, previous_guardian, new_guardian, timestamp },
+    CrankConfigUpdatedEvent { vault, grace_period, bounty, timestamp },
+    VoucherSignerChangedEvent { vault, previous_voucher_signer, new_voucher_signer, timestamp },
+    MintAddedEvent { vault, mint, token_account, timestamp },
+    MintStatusChangedEvent { vault, mint, enabled, timestamp },
+    TransferAllowlistProposedEvent { vault, pending_transfer_allowlist, effective_at, timestamp },
+    TransferAllowlistChangedEvent {
+        vault, previous_transfer_allowlist, transfer_allowlist, timestamp,
+    },
+    TransferAllowlistCancelledEvent {
+        vault, cancelled_by, cancelled_transfer_allowlist, timestamp,
+    },
+    InternalTransferEvent {
+        vault, destination_vault, mint, amount, total_paid_out, destination_total_deposited, timestamp,
+    },
+    LiabilitiesCommittedEvent {
+        vault, epoch, merkle_root, total_liabilities, leaf_count, vault_balance, timestamp,
+    },
+}
+
+impl fmt::Debug for VaultEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", self.name(), self.to_json())
+    }
+}
+
+/// Decodes one `Program data:` line; `None` for any other log line
+pub fn decode_log_line(line: &str) -> Option<Result<VaultEvent, EventError>> {
+    let data = line.strip_prefix(DATA_PREFIX)?;
+    Some(
+        BASE64
+            .decode(data.trim())
+            .map_err(|err| EventError::InvalidBase64(err.to_string()))
+            .and_then(|bytes| VaultEvent::decode(&bytes)),
+    )
+}
+
+/// Extracts vault events from a transaction's log messages, in emit order.
+/// `program_id` is the deployed vault program (usually [`PROGRAM_ID`]).
+pub fn parse_logs<S: AsRef<str>>(
+    program_id: &Pubkey,
+    logs: &[S],
+) -> Result<Vec<VaultEvent>, EventError> {
+    let program_id = program_id.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs {
+        let line = line.as_ref();
+        if let Some(rest) = line.strip_prefix("Program ") {
+            let mut words = rest.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some(program), Some("invoke")) => {
+                    stack.push(program);
+                    continue;
+                }
+                (Some(program), Some("success")) | (Some(program), Some("failed:")) => {
+                    if stack.pop() != Some(program) {
+                        return Err(EventError::MalformedLogs(line.to_string()));
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        if stack.last() == Some(&program_id.as_str()) {
+            if let Some(event) = decode_log_line(line) {
+                events.push(event?);
+            }
+        }
+    }
+    Ok(events)
+}
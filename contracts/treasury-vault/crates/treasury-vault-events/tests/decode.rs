@@ -0,0 +1,96 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Event;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use treasury_vault::events::{DepositEvent, WithdrawalClaimedEvent};
+use treasury_vault_events::{parse_logs, EventError, VaultEvent, PROGRAM_ID};
+
+fn data_line(event: &impl Event) -> String {
+    format!("Program data: {}", BASE64.encode(event.data()))
+}
+
+fn deposit() -> DepositEvent {
+    DepositEvent {
+        vault: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        amount: 5_000_000,
+        total_deposited: 7_000_000,
+        deposit_count: 2,
+        timestamp: 1_700_000_000,
+    }
+}
+
+#[test]
+fn decodes_events_emitted_by_the_vault_program() {
+    let token_program: Pubkey = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        .parse()
+        .unwrap();
+    let user = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let claimed = WithdrawalClaimedEvent {
+        vault: Pubkey::new_unique(),
+        mint,
+        user,
+        destination: user,
+        request_id: 9,
+        amount: 1_000_000,
+        total_paid_out: 1_000_000,
+        total_reserved: 0,
+        payout_count: 1,
+        timestamp: 1_700_000_100,
+    };
+    let logs = vec![
+        format!("Program {PROGRAM_ID} invoke [1]"),
+        "Program log: Instruction: ClaimWithdrawal".to_string(),
+        format!("Program {token_program} invoke [2]"),
+        // Emitted inside the CPI: must not be attributed to the vault
+        data_line(&deposit()),
+        format!("Program {token_program} success"),
+        data_line(&claimed),
+        format!("Program {PROGRAM_ID} consumed 30000 of 200000 compute units"),
+        format!("Program {PROGRAM_ID} success"),
+    ];
+
+    let events = parse_logs(&PROGRAM_ID, &logs).unwrap();
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        VaultEvent::WithdrawalClaimedEvent(event) => {
+            assert_eq!(event.user, user);
+            assert_eq!(event.request_id, 9);
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+
+    let json = events[0].to_json();
+    assert_eq!(json["user"], user.to_string());
+    assert_eq!(json["mint"], mint.to_string());
+    assert_eq!(json["amount"], "1000000");
+    assert_eq!(json["request_id"], "9");
+    assert_eq!(events[0].name(), "WithdrawalClaimedEvent");
+}
+
+#[test]
+fn unknown_discriminator_is_kept() {
+    let mut data = deposit().data();
+    data[..8].copy_from_slice(&[1; 8]);
+    let event = VaultEvent::decode(&data).unwrap();
+    assert_eq!(event.name(), "Unknown");
+}
+
+#[test]
+fn rejects_truncated_event_and_unbalanced_logs() {
+    let data = deposit().data();
+    assert!(matches!(
+        VaultEvent::decode(&data[..20]),
+        Err(EventError::InvalidEvent {
+            name: "DepositEvent",
+            ..
+        })
+    ));
+
+    let logs = [format!("Program {PROGRAM_ID} success")];
+    assert!(matches!(
+        parse_logs(&PROGRAM_ID, &logs),
+        Err(EventError::MalformedLogs(_))
+    ));
+}
@@ -0,0 +1,16 @@
+//! Execution harness for Treasury Vault integration tests.
+//!
+//! Runs the compiled program (`treasury_vault.so`, from `anchor build`) in
+//! LiteSVM next to the SPL Token, Token-2022 and Associated Token programs,
+//! so tests exercise the same bytecode, loader, account rules, compute meter
+//! and fees as the cluster. The `Clock` is fully controlled by the test, and
+//! transactions over the cluster's packet size are rejected.
+
+mod svm;
+mod vault;
+
+pub use svm::{
+    transaction_size, Account, InstructionError, Svm, TxError, TxLogs, TxResult,
+    MAX_TRANSACTION_SIZE, SIGNATURE_FEE,
+};
+pub use vault::{Mint, TestVault, AUTHORITY_BALANCE, DECIMALS, TOKEN_PROGRAMS};
@@ -0,0 +1,369 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use anchor_lang::prelude::{Clock, ProgramError, Pubkey, Rent};
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::message::Message;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use anchor_lang::solana_program::program_pack::Pack;
+use litesvm::types::FailedTransactionMetadata;
+use litesvm::LiteSVM;
+use solana_system_interface::instruction as system_instruction;
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+use spl_token_2022::extension::StateWithExtensions;
+use treasury_vault_client::TreasuryError;
+use treasury_vault_events::{parse_logs, VaultEvent};
+
+pub use solana_account::Account;
+
+const GENESIS_TIMESTAMP: i64 = 1_700_000_000;
+/// Slots advance at the cluster's ~400ms target when the clock is warped
+const MS_PER_SLOT: i64 = 400;
+/// Fee per signature, charged to the fee payer even when the transaction fails
+pub const SIGNATURE_FEE: u64 = 5_000;
+/// Largest serialized transaction the cluster accepts (`PACKET_DATA_SIZE`)
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstructionError {
+    /// Returned by a program. Anchor errors are `Custom(6000 + n)`.
+    Program(ProgramError),
+    /// Rejected by the runtime: account rules, a missing signature, a failed
+    /// precompile or the transaction as a whole
+    Runtime(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct TxError {
+    /// Index of the failing top-level instruction
+    pub instruction: usize,
+    pub error: InstructionError,
+    pub logs: Vec<String>,
+}
+
+impl TxError {
+    pub fn treasury_error(&self) -> Option<TreasuryError> {
+        match self.error {
+            InstructionError::Program(ProgramError::Custom(code)) => {
+                treasury_vault_client::errors::from_code(code)
+            }
+            _ => None,
+        }
+    }
+
+    /// True when the transaction failed with exactly `expected`
+    pub fn is(&self, expected: TreasuryError) -> bool {
+        self.error == InstructionError::Program(ProgramError::Custom(expected.into()))
+    }
+}
+
+impl fmt::Display for TxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.error, self.treasury_error()) {
+            (_, Some(error)) => write!(f, "instruction {}: {}", self.instruction, error.name()),
+            (InstructionError::Program(err), None) => {
+                write!(f, "instruction {}: {err}", self.instruction)
+            }
+            (InstructionError::Runtime(reason), None) => {
+                write!(f, "instruction {}: {reason}", self.instruction)
+            }
+        }
+    }
+}
+
+/// Logs of a successful transaction
+#[derive(Debug, Clone)]
+pub struct TxLogs {
+    pub logs: Vec<String>,
+    pub compute_units: u64,
+}
+
+impl TxLogs {
+    pub fn events(&self) -> Vec<VaultEvent> {
+        parse_logs(&treasury_vault::ID, &self.logs).expect("well-formed logs")
+    }
+}
+
+pub type TxResult = Result<TxLogs, TxError>;
+
+/// `treasury_vault.so` from `SBF_OUT_DIR`, else `target/deploy`. The suite
+/// only runs against the compiled program, so a missing binary panics.
+fn program_bytes() -> &'static [u8] {
+    static PROGRAM: OnceLock<Vec<u8>> = OnceLock::new();
+    PROGRAM.get_or_init(|| {
+        let dir = std::env::var_os("SBF_OUT_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy")
+            });
+        let path = dir.join("treasury_vault.so");
+        std::fs::read(&path).unwrap_or_else(|err| {
+            panic!(
+                "{}: {err}; run `anchor build` (or `cargo build-sbf`) first",
+                path.display()
+            )
+        })
+    })
+}
+
+/// LiteSVM running the compiled program next to the SPL Token, Token-2022
+/// and Associated Token programs it ships, with a test-controlled clock.
+///
+/// Signers are given as keys: signature verification is off, and instead
+/// every account an instruction marks as a signer must be listed. Fees are
+/// charged as on the cluster, to the first signer.
+pub struct Svm {
+    svm: LiteSVM,
+    payer: Pubkey,
+}
+
+impl Default for Svm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Svm {
+    pub fn new() -> Self {
+        let mut svm = LiteSVM::new()
+            .with_sigverify(false)
+            // Unsigned transactions share a signature; allow repeats
+            .with_transaction_history(0)
+            .with_log_bytes_limit(None);
+        svm.set_sysvar(&Clock {
+            slot: 1,
+            unix_timestamp: GENESIS_TIMESTAMP,
+            epoch_start_timestamp: GENESIS_TIMESTAMP,
+            ..Clock::default()
+        });
+        svm.add_program(treasury_vault::ID, program_bytes())
+            .expect("load treasury_vault.so");
+        let mut svm = Self {
+            svm,
+            payer: Pubkey::new_unique(),
+        };
+        svm.airdrop(&svm.payer.clone(), 1_000 * LAMPORTS_PER_SOL);
+        svm
+    }
+
+    /// Funded key that pays for accounts created by the token helpers
+    pub fn payer(&self) -> Pubkey {
+        self.payer
+    }
+
+    pub fn account(&self, address: &Pubkey) -> Option<Account> {
+        self.svm.get_account(address)
+    }
+
+    pub fn set_account(&mut self, address: Pubkey, account: Account) {
+        self.svm
+            .set_account(address, account)
+            .expect("valid account");
+    }
+
+    pub fn airdrop(&mut self, address: &Pubkey, lamports: u64) {
+        let mut account = self.account(address).unwrap_or_default();
+        account.lamports += lamports;
+        self.set_account(*address, account);
+    }
+
+    pub fn lamports(&self, address: &Pubkey) -> u64 {
+        self.svm.get_balance(address).unwrap_or_default()
+    }
+
+    pub fn rent(&self) -> Rent {
+        self.svm.get_sysvar()
+    }
+
+    pub fn clock(&self) -> Clock {
+        self.svm.get_sysvar()
+    }
+
+    pub fn now(&self) -> i64 {
+        self.clock().unix_timestamp
+    }
+
+    /// Sets `Clock::unix_timestamp`, moving the slot forward accordingly
+    pub fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock = self.clock();
+        let elapsed_ms = (unix_timestamp - clock.unix_timestamp).max(0) * 1000;
+        clock.slot += (elapsed_ms / MS_PER_SLOT) as u64;
+        clock.unix_timestamp = unix_timestamp;
+        self.svm.set_sysvar(&clock);
+    }
+
+    pub fn advance(&mut self, seconds: i64) {
+        self.warp_to(self.now() + seconds);
+    }
+
+    /// Creates a mint owned by `token_program` (SPL Token or Token-2022)
+    pub fn create_mint(
+        &mut self,
+        authority: &Pubkey,
+        decimals: u8,
+        token_program: &Pubkey,
+    ) -> Pubkey {
+        let mint = Pubkey::new_unique();
+        let space = spl_token_2022::state::Mint::LEN;
+        let instructions = [
+            system_instruction::create_account(
+                &self.payer,
+                &mint,
+                self.rent().minimum_balance(space),
+                space as u64,
+                token_program,
+            ),
+            spl_token_2022::instruction::initialize_mint2(
+                token_program,
+                &mint,
+                authority,
+                None,
+                decimals,
+            )
+            .expect("valid token program"),
+        ];
+        self.process(&instructions, &[self.payer, mint])
+            .expect("create mint");
+        mint
+    }
+
+    /// Creates `owner`'s associated token account for `mint`
+    pub fn create_ata(&mut self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let token_program = self.account(mint).expect("mint exists").owner;
+        let instruction =
+            spl_associated_token_account::instruction::create_associated_token_account(
+                &self.payer,
+                owner,
+                mint,
+                &token_program,
+            );
+        self.process(&[instruction], &[self.payer])
+            .expect("create associated token account");
+        spl_associated_token_account::get_associated_token_address_with_program_id(
+            owner,
+            mint,
+            &token_program,
+        )
+    }
+
+    /// Mints to `destination`; the payer covers the fee, not `authority`
+    pub fn mint_to(
+        &mut self,
+        mint: &Pubkey,
+        authority: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) {
+        let token_program = self.account(mint).expect("mint exists").owner;
+        let instruction = spl_token_2022::instruction::mint_to(
+            &token_program,
+            mint,
+            destination,
+            authority,
+            &[],
+            amount,
+        )
+        .expect("valid token program");
+        self.process(&[instruction], &[self.payer, *authority])
+            .expect("mint to");
+    }
+
+    /// Token amount of an SPL Token or Token-2022 account (0 if missing)
+    pub fn token_balance(&self, token_account: &Pubkey) -> u64 {
+        self.account(token_account)
+            .and_then(|account| {
+                StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+                    .map(|state| state.base.amount)
+                    .ok()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn mint_supply(&self, mint: &Pubkey) -> u64 {
+        self.account(mint)
+            .and_then(|account| spl_token_2022::state::Mint::unpack(&account.data[..82]).ok())
+            .map_or(0, |mint| mint.supply)
+    }
+
+    /// Runs `instructions` as one transaction signed by `signers`, the first
+    /// of them paying the fee
+    pub fn process(&mut self, instructions: &[Instruction], signers: &[Pubkey]) -> TxResult {
+        let fee_payer = *signers.first().unwrap_or(&self.payer);
+        let size = transaction_size(instructions, &fee_payer);
+        if size > MAX_TRANSACTION_SIZE {
+            return Err(TxError {
+                instruction: 0,
+                error: InstructionError::Runtime(format!(
+                    "transaction is {size} bytes, over the {MAX_TRANSACTION_SIZE} byte limit"
+                )),
+                logs: Vec::new(),
+            });
+        }
+        // Stands in for signature verification, which is off
+        for (index, instruction) in instructions.iter().enumerate() {
+            if let Some(meta) = instruction
+                .accounts
+                .iter()
+                .find(|meta| meta.is_signer && !signers.contains(&meta.pubkey))
+            {
+                return Err(TxError {
+                    instruction: index,
+                    error: InstructionError::Runtime(format!(
+                        "missing signature for {}",
+                        meta.pubkey
+                    )),
+                    logs: Vec::new(),
+                });
+            }
+        }
+
+        let message = Message::new_with_blockhash(
+            instructions,
+            Some(&fee_payer),
+            &self.svm.latest_blockhash(),
+        );
+        match self
+            .svm
+            .send_transaction(Transaction::new_unsigned(message))
+        {
+            Ok(meta) => Ok(TxLogs {
+                logs: meta.logs,
+                compute_units: meta.compute_units_consumed,
+            }),
+            Err(FailedTransactionMetadata { err, meta }) => {
+                let (instruction, error) = match err {
+                    TransactionError::InstructionError(index, error) => {
+                        let index = usize::from(index);
+                        // Precompile failures come back as custom codes too
+                        let precompile = instructions[index].program_id == ed25519_program::ID;
+                        match ProgramError::try_from(error.clone()) {
+                            Ok(error) if !precompile => (index, InstructionError::Program(error)),
+                            _ => (index, InstructionError::Runtime(error.to_string())),
+                        }
+                    }
+                    // Checked after the last instruction, e.g. rent
+                    err => (
+                        instructions.len().saturating_sub(1),
+                        InstructionError::Runtime(err.to_string()),
+                    ),
+                };
+                Err(TxError {
+                    instruction,
+                    error,
+                    logs: meta.logs,
+                })
+            }
+        }
+    }
+}
+
+/// Wire size of `instructions` as a signed legacy transaction
+pub fn transaction_size(instructions: &[Instruction], fee_payer: &Pubkey) -> usize {
+    let message = Message::new(instructions, Some(fee_payer)).serialize();
+    let signatures = usize::from(message[0]);
+    // Compact-u16 signature count, one byte below 128
+    1 + signatures * 64 + message.len()
+}
@@ -0,0 +1,364 @@
+//! A funded, initialized vault for integration tests
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use treasury_vault_client::decode::{decode_user_ledger, decode_vault, decode_withdrawal_request};
+use treasury_vault_client::instructions::{initialize, voucher_signature};
+use treasury_vault_client::pda::vault_address;
+use treasury_vault_client::{
+    BatchWithdrawal, ProposalAction, TreasuryVault, UserLedger, VaultKeys, Voucher,
+    WithdrawalRequest,
+};
+
+use crate::svm::{Svm, TxResult};
+
+/// Both token programs the vault supports, for tests that run against each
+pub const TOKEN_PROGRAMS: [Pubkey; 2] = [spl_token::ID, spl_token_2022::ID];
+
+/// USDT-like mint decimals
+pub const DECIMALS: u8 = 6;
+/// Tokens minted to the authority's token account at setup (1M USDT)
+pub const AUTHORITY_BALANCE: u64 = 1_000_000_000_000;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Mint {
+    pub address: Pubkey,
+    pub authority: Pubkey,
+    pub token_program: Pubkey,
+    pub decimals: u8,
+}
+
+/// An [`Svm`] with a vault initialized over a fresh mint. The authority's
+/// token account holds [`AUTHORITY_BALANCE`]; the vault starts empty.
+pub struct TestVault {
+    pub svm: Svm,
+    pub authority: Pubkey,
+    pub guardian: Pubkey,
+    pub payout_wallet: Pubkey,
+    pub mint: Mint,
+    pub keys: VaultKeys,
+}
+
+impl TestVault {
+    /// `token_program` is `spl_token::ID` or `spl_token_2022::ID`
+    pub fn new(token_program: Pubkey) -> Self {
+        let mut svm = Svm::new();
+        let authority = Pubkey::new_unique();
+        let guardian = Pubkey::new_unique();
+        let payout_wallet = Pubkey::new_unique();
+        svm.airdrop(&authority, 100 * LAMPORTS_PER_SOL);
+        svm.airdrop(&guardian, LAMPORTS_PER_SOL);
+
+        let mint = Mint {
+            address: svm.create_mint(&authority, DECIMALS, &token_program),
+            authority,
+            token_program,
+            decimals: DECIMALS,
+        };
+        let authority_tokens = svm.create_ata(&authority, &mint.address);
+        svm.mint_to(
+            &mint.address,
+            &authority,
+            &authority_tokens,
+            AUTHORITY_BALANCE,
+        );
+
+        svm.process(
+            &[initialize(
+                authority,
+                0,
+                mint.address,
+                payout_wallet,
+                guardian,
+                Vec::new(),
+                token_program,
+            )],
+            &[authority],
+        )
+        .expect("initialize vault");
+
+        let vault = vault_address(&authority, 0).0;
+        let state =
+            decode_vault(&svm.account(&vault).expect("vault exists").data).expect("vault decodes");
+        Self {
+            svm,
+            authority,
+            guardian,
+            payout_wallet,
+            mint,
+            keys: VaultKeys::from_state(vault, &state, token_program),
+        }
+    }
+
+    /// Initializes another vault under the same authority, over the primary
+    /// mint and with the same payout wallet and guardian. Assign the result
+    /// to `self.keys` to drive that vault with the other helpers.
+    pub fn init_vault(&mut self, vault_id: u16, transfer_allowlist: Vec<Pubkey>) -> VaultKeys {
+        let instruction = initialize(
+            self.authority,
+            vault_id,
+            self.mint.address,
+            self.payout_wallet,
+            self.guardian,
+            transfer_allowlist,
+            self.mint.token_program,
+        );
+        self.svm
+            .process(&[instruction], &[self.authority])
+            .expect("initialize vault");
+        let vault = vault_address(&self.authority, vault_id).0;
+        let state = decode_vault(&self.svm.account(&vault).expect("vault exists").data)
+            .expect("vault decodes");
+        VaultKeys::from_state(vault, &state, self.mint.token_program)
+    }
+
+    pub fn state(&self) -> TreasuryVault {
+        let account = self.svm.account(&self.keys.vault).expect("vault exists");
+        decode_vault(&account.data).expect("vault decodes")
+    }
+
+    /// `None` once the request is claimed or cancelled
+    pub fn withdrawal(&self, user: &Pubkey, request_id: u64) -> Option<WithdrawalRequest> {
+        let address = self.keys.withdrawal_address(user, request_id);
+        self.svm
+            .account(&address)
+            .map(|account| decode_withdrawal_request(&account.data).expect("request decodes"))
+    }
+
+    /// Keys for any registered mint, `self.keys` being the primary one's
+    pub fn mint_keys(&self, mint: &Pubkey) -> VaultKeys {
+        let token_program = self.svm.account(mint).expect("mint exists").owner;
+        self.keys.with_mint(*mint, token_program)
+    }
+
+    /// Keys for the mint a pending request pays in
+    fn request_keys(&self, request: Option<&WithdrawalRequest>) -> VaultKeys {
+        request.map_or(self.keys, |request| self.mint_keys(&request.mint))
+    }
+
+    pub fn user_ledger(&self, user: &Pubkey) -> Option<UserLedger> {
+        let address = self.keys.user_ledger_address(user);
+        self.svm
+            .account(&address)
+            .map(|account| decode_user_ledger(&account.data).expect("ledger decodes"))
+    }
+
+    pub fn vault_balance(&self) -> u64 {
+        self.svm.token_balance(&self.keys.vault_token_account)
+    }
+
+    /// Balance of `owner`'s associated token account for the vault mint
+    pub fn token_balance(&self, owner: &Pubkey) -> u64 {
+        self.svm.token_balance(&self.keys.token_account(owner))
+    }
+
+    /// A fresh wallet with enough SOL to pay for its token account
+    pub fn new_user(&mut self) -> Pubkey {
+        let user = Pubkey::new_unique();
+        self.svm.airdrop(&user, LAMPORTS_PER_SOL);
+        user
+    }
+
+    /// Registers a fresh mint with [`DECIMALS`] under `token_program` and
+    /// funds the authority's token account for it with [`AUTHORITY_BALANCE`]
+    pub fn add_mint(&mut self, token_program: Pubkey) -> Mint {
+        let mint = Mint {
+            address: self
+                .svm
+                .create_mint(&self.authority, DECIMALS, &token_program),
+            authority: self.authority,
+            token_program,
+            decimals: DECIMALS,
+        };
+        let authority_tokens = self.svm.create_ata(&self.authority, &mint.address);
+        self.svm.mint_to(
+            &mint.address,
+            &self.authority,
+            &authority_tokens,
+            AUTHORITY_BALANCE,
+        );
+
+        let instruction = self.mint_keys(&mint.address).add_mint(None);
+        self.svm
+            .process(&[instruction], &[self.authority])
+            .expect("add mint");
+        mint
+    }
+
+    pub fn deposit(&mut self, amount: u64) -> TxResult {
+        let instruction = self.keys.deposit(amount);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    pub fn payout(&mut self, amount: u64) -> TxResult {
+        let instruction = self.keys.payout(amount, None);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    /// Moves the primary mint to another vault of the program
+    pub fn internal_transfer(&mut self, destination_vault: Pubkey, amount: u64) -> TxResult {
+        let instruction = self.keys.internal_transfer(destination_vault, amount, None);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    pub fn create_withdrawal(
+        &mut self,
+        user: Pubkey,
+        request_id: u64,
+        amount: u64,
+        expires_in: i64,
+    ) -> TxResult {
+        self.create_withdrawal_to(user, request_id, amount, expires_in, None)
+    }
+
+    /// Request paid out to `destination`'s token account on claim
+    pub fn create_withdrawal_to(
+        &mut self,
+        user: Pubkey,
+        request_id: u64,
+        amount: u64,
+        expires_in: i64,
+        destination: Option<Pubkey>,
+    ) -> TxResult {
+        let instruction =
+            self.keys
+                .create_withdrawal(user, request_id, amount, expires_in, destination, None);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    pub fn create_withdrawals_batch(&mut self, withdrawals: &[BatchWithdrawal]) -> TxResult {
+        let instruction = self.keys.create_withdrawals_batch(withdrawals);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    /// Signed by `user`; pays out to the request's destination
+    pub fn claim(&mut self, user: Pubkey, request_id: u64) -> TxResult {
+        let request = self.withdrawal(&user, request_id);
+        let destination = request.as_ref().and_then(|request| request.destination);
+        let instruction =
+            self.request_keys(request.as_ref())
+                .claim_withdrawal(user, request_id, destination);
+        self.svm.process(&[instruction], &[user])
+    }
+
+    /// Signed by `fee_payer` and `user`; the fee goes to the request's recorded relayer account
+    pub fn claim_relayed(
+        &mut self,
+        fee_payer: Pubkey,
+        user: Pubkey,
+        request_id: u64,
+        max_fee: u64,
+    ) -> TxResult {
+        let request = self.withdrawal(&user, request_id);
+        let instruction = self
+            .request_keys(request.as_ref())
+            .claim_withdrawal_relayed(
+                fee_payer,
+                user,
+                request_id,
+                request.as_ref().and_then(|request| request.destination),
+                request.and_then(|request| request.relayer_token_account),
+                max_fee,
+            );
+        self.svm.process(&[instruction], &[fee_payer, user])
+    }
+
+    pub fn set_relayer(
+        &mut self,
+        user: Pubkey,
+        request_id: u64,
+        relayer_token_account: Option<Pubkey>,
+        relayer_fee: u64,
+    ) -> TxResult {
+        let instruction =
+            self.keys
+                .set_withdrawal_relayer(user, request_id, relayer_token_account, relayer_fee);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    /// Turns on 2-of-2 multisig mode and returns the two signers
+    pub fn enable_multisig(&mut self) -> [Pubkey; 2] {
+        let signers = [self.new_user(), self.new_user()];
+        let instruction = self.keys.configure_multisig(signers.to_vec(), 2, 0, None);
+        self.svm
+            .process(&[instruction], &[self.authority])
+            .expect("configure multisig");
+        signers
+    }
+
+    /// Proposes `action` and approves it with every one of `signers`,
+    /// returning the proposal address
+    pub fn approved_proposal(&mut self, signers: &[Pubkey], action: ProposalAction) -> Pubkey {
+        let proposal_id = self.state().proposal_count;
+        let create = self.keys.create_proposal(signers[0], proposal_id, action);
+        self.svm
+            .process(&[create], &[signers[0]])
+            .expect("create proposal");
+        for signer in &signers[1..] {
+            let approve = self.keys.approve_proposal(*signer, proposal_id);
+            self.svm
+                .process(&[approve], &[*signer])
+                .expect("approve proposal");
+        }
+        self.keys.proposal_address(proposal_id)
+    }
+
+    /// Registers a fresh voucher signer key and returns it
+    pub fn enable_vouchers(&mut self) -> Keypair {
+        let signer = Keypair::new();
+        let instruction = self.keys.set_voucher_signer(signer.pubkey(), None);
+        self.svm
+            .process(&[instruction], &[self.authority])
+            .expect("set voucher signer");
+        signer
+    }
+
+    /// `voucher` signed by `signer`, submitted and paid for by its user
+    pub fn claim_with_voucher(&mut self, signer: &Keypair, voucher: &Voucher) -> TxResult {
+        let signature = signer.sign_message(&voucher.message());
+        let instructions = [
+            voucher_signature(&signer.pubkey(), &signature.into(), voucher),
+            self.mint_keys(&voucher.mint).claim_with_voucher(voucher),
+        ];
+        self.svm.process(&instructions, &[voucher.user])
+    }
+
+    pub fn cancel(&mut self, user: Pubkey, request_id: u64) -> TxResult {
+        let instruction = self.keys.cancel_withdrawal(user, request_id);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    pub fn cancel_expired_batch(&mut self, requests: &[(Pubkey, u64)]) -> TxResult {
+        let instruction = self.keys.cancel_expired_batch(requests);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    /// Signed by `cranker`, who needs no vault role
+    pub fn crank(&mut self, cranker: Pubkey, user: Pubkey, request_id: u64) -> TxResult {
+        let instruction = self.keys.crank_cancel_expired(cranker, user, request_id);
+        self.svm.process(&[instruction], &[cranker])
+    }
+
+    /// Signed by `user`, refunding the rent payer recorded on the request
+    pub fn user_cancel(&mut self, user: Pubkey, request_id: u64, reason: u8) -> TxResult {
+        let payer = self
+            .withdrawal(&user, request_id)
+            .map_or(self.authority, |request| request.payer);
+        let instruction = self
+            .keys
+            .user_cancel_withdrawal(user, request_id, payer, reason);
+        self.svm.process(&[instruction], &[user])
+    }
+
+    pub fn extend(&mut self, user: Pubkey, request_id: u64, expires_in: i64) -> TxResult {
+        let instruction = self.keys.extend_withdrawal(user, request_id, expires_in);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+
+    pub fn amend(&mut self, user: Pubkey, request_id: u64, amount: u64) -> TxResult {
+        let instruction = self.keys.amend_withdrawal(user, request_id, amount);
+        self.svm.process(&[instruction], &[self.authority])
+    }
+}
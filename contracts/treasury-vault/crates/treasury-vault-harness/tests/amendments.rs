@@ -0,0 +1,138 @@
+use treasury_vault_client::TreasuryError;
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, TOKEN_PROGRAMS};
+
+const AMOUNT: u64 = 300_000_000;
+
+#[test]
+fn extend_keeps_an_expired_request_claimable() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 60).unwrap();
+        let expires_at = vault.withdrawal(&user, 1).unwrap().expires_at;
+        vault.svm.warp_to(expires_at + 30);
+
+        let logs = vault.extend(user, 1, 3600).unwrap();
+
+        let new_expires_at = vault.svm.now() + 3600;
+        assert_eq!(
+            vault.withdrawal(&user, 1).unwrap().expires_at,
+            new_expires_at
+        );
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalExtendedEvent(event)]
+                if event.previous_expires_at == expires_at && event.expires_at == new_expires_at
+        ));
+
+        vault.svm.warp_to(new_expires_at);
+        assert!(vault
+            .cancel(user, 1)
+            .unwrap_err()
+            .is(TreasuryError::WithdrawalNotExpired));
+        vault.claim(user, 1).unwrap();
+        assert_eq!(vault.token_balance(&user), AMOUNT);
+    }
+}
+
+#[test]
+fn extend_never_shortens_the_window() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+
+        for expires_in in [0, -10, 3600, 1800] {
+            let err = vault.extend(user, 1, expires_in).unwrap_err();
+            assert!(
+                err.is(TreasuryError::InvalidExpiration),
+                "{expires_in}: {err}"
+            );
+        }
+        vault.svm.advance(1);
+        vault.extend(user, 1, 3600).unwrap();
+    }
+}
+
+#[test]
+fn amend_lowers_amount_and_releases_reserve() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+
+        let logs = vault.amend(user, 1, 100_000_000).unwrap();
+
+        assert_eq!(vault.withdrawal(&user, 1).unwrap().amount, 100_000_000);
+        assert_eq!(vault.state().total_reserved, 100_000_000);
+        let ledger = vault.user_ledger(&user).unwrap();
+        assert_eq!(
+            (ledger.pending_amount, ledger.pending_count),
+            (100_000_000, 1)
+        );
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalAmendedEvent(event)]
+                if event.previous_amount == AMOUNT && event.amount == 100_000_000
+        ));
+
+        // The released part is available again
+        vault.payout(900_000_000).unwrap();
+        vault.claim(user, 1).unwrap();
+        assert_eq!(vault.token_balance(&user), 100_000_000);
+        assert_eq!(vault.vault_balance(), 0);
+    }
+}
+
+#[test]
+fn amend_cannot_raise_or_zero_the_amount() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+
+        assert!(vault
+            .amend(user, 1, AMOUNT)
+            .unwrap_err()
+            .is(TreasuryError::AmountNotReduced));
+        assert!(vault
+            .amend(user, 1, AMOUNT + 1)
+            .unwrap_err()
+            .is(TreasuryError::AmountNotReduced));
+        assert!(vault
+            .amend(user, 1, 0)
+            .unwrap_err()
+            .is(TreasuryError::ZeroAmount));
+        assert_eq!(vault.state().total_reserved, AMOUNT);
+    }
+}
+
+#[test]
+fn only_the_authority_can_amend_or_extend() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+        let keys = treasury_vault_client::VaultKeys {
+            authority: user,
+            ..vault.keys
+        };
+
+        let err = vault
+            .svm
+            .process(&[keys.extend_withdrawal(user, 1, 7200)], &[user])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+        let err = vault
+            .svm
+            .process(&[keys.amend_withdrawal(user, 1, 1)], &[user])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+    }
+}
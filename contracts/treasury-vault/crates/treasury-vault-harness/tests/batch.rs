@@ -0,0 +1,302 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use treasury_vault::state::MAX_WITHDRAWAL_BATCH;
+use treasury_vault_client::{BatchWithdrawal, TreasuryError};
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, SIGNATURE_FEE, TOKEN_PROGRAMS};
+
+fn entry(user: Pubkey, request_id: u64, amount: u64) -> BatchWithdrawal {
+    BatchWithdrawal {
+        user,
+        request_id,
+        amount,
+        expires_in: 3600,
+        destination: None,
+    }
+}
+
+#[test]
+fn batch_creates_every_request_in_one_transaction() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let alice = vault.new_user();
+        let bob = vault.new_user();
+        let cold_wallet = Pubkey::new_unique();
+        // Bob already has a ledger from an earlier request
+        vault.create_withdrawal(bob, 1, 50_000_000, 3600).unwrap();
+
+        let logs = vault
+            .create_withdrawals_batch(&[
+                entry(alice, 1, 100_000_000),
+                entry(bob, 2, 200_000_000),
+                BatchWithdrawal {
+                    destination: Some(cold_wallet),
+                    ..entry(alice, 2, 300_000_000)
+                },
+            ])
+            .unwrap();
+
+        let events = logs.events();
+        assert_eq!(events.len(), 3);
+        let reserved: Vec<_> = events
+            .iter()
+            .map(|event| match event {
+                VaultEvent::WithdrawalCreatedEvent(event) => event.total_reserved,
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect();
+        assert_eq!(reserved, [150_000_000, 350_000_000, 650_000_000]);
+
+        assert_eq!(vault.state().total_reserved, 650_000_000);
+        let request = vault.withdrawal(&alice, 2).unwrap();
+        assert_eq!(
+            (request.amount, request.destination),
+            (300_000_000, Some(cold_wallet))
+        );
+        assert_eq!(
+            request.expires_at,
+            vault.withdrawal(&alice, 1).unwrap().expires_at
+        );
+
+        let ledger = vault.user_ledger(&alice).unwrap();
+        assert_eq!((ledger.user, ledger.pending_count), (alice, 2));
+        assert_eq!(ledger.pending_amount, 400_000_000);
+        let ledger = vault.user_ledger(&bob).unwrap();
+        assert_eq!(
+            (ledger.pending_count, ledger.pending_amount),
+            (2, 250_000_000)
+        );
+
+        // Batched requests claim like any other
+        vault.claim(alice, 2).unwrap();
+        assert_eq!(vault.token_balance(&cold_wallet), 300_000_000);
+    }
+}
+
+#[test]
+fn batch_is_all_or_nothing() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+
+        // Each entry fits on its own, the batch does not
+        let err = vault
+            .create_withdrawals_batch(&[entry(user, 1, 600_000_000), entry(user, 2, 600_000_000)])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InsufficientBalance), "{err}");
+
+        let err = vault
+            .create_withdrawals_batch(&[entry(user, 1, 100_000_000), entry(user, 2, 0)])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::ZeroAmount), "{err}");
+
+        // A repeated request id fails the whole batch
+        vault
+            .create_withdrawals_batch(&[entry(user, 1, 100_000_000), entry(user, 1, 100_000_000)])
+            .unwrap_err();
+
+        assert!(vault.withdrawal(&user, 1).is_none());
+        assert!(vault.user_ledger(&user).is_none());
+        assert_eq!(vault.state().total_reserved, 0);
+    }
+}
+
+#[test]
+fn batch_checks_size_and_accounts() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+
+        let err = vault.create_withdrawals_batch(&[]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidBatchSize), "{err}");
+        let too_many: Vec<_> = (0..=MAX_WITHDRAWAL_BATCH as u64)
+            .map(|id| entry(user, id, 1))
+            .collect();
+        let err = vault.create_withdrawals_batch(&too_many).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidBatchSize), "{err}");
+
+        let batch = [entry(user, 1, 100_000_000), entry(user, 2, 100_000_000)];
+
+        let mut missing = vault.keys.create_withdrawals_batch(&batch);
+        missing.accounts.pop();
+        let err = vault
+            .svm
+            .process(&[missing], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidBatchAccounts), "{err}");
+
+        // PDAs swapped between entries
+        let mut swapped = vault.keys.create_withdrawals_batch(&batch);
+        let len = swapped.accounts.len();
+        swapped.accounts.swap(len - 4, len - 2);
+        let err = vault
+            .svm
+            .process(&[swapped], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidBatchAccounts), "{err}");
+
+        let mut foreign = vault.keys.create_withdrawals_batch(&batch);
+        foreign.accounts[len - 1] = AccountMeta::new(Pubkey::new_unique(), false);
+        let err = vault
+            .svm
+            .process(&[foreign], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidBatchAccounts), "{err}");
+
+        vault.create_withdrawals_batch(&batch).unwrap();
+    }
+}
+
+#[test]
+fn batch_respects_authority_and_limits() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        let batch = [entry(user, 1, 100_000_000), entry(user, 2, 300_000_000)];
+
+        let attacker = vault.new_user();
+        let mut keys = vault.keys;
+        keys.authority = attacker;
+        let instruction = keys.create_withdrawals_batch(&batch);
+        let err = vault.svm.process(&[instruction], &[attacker]).unwrap_err();
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+
+        let limits = vault.keys.set_rate_limits(0, 0, 200_000_000, None);
+        vault.svm.process(&[limits], &[vault.authority]).unwrap();
+        let err = vault.create_withdrawals_batch(&batch).unwrap_err();
+        assert!(err.is(TreasuryError::RateLimitExceeded), "{err}");
+        let limits = vault.keys.set_rate_limits(0, 350_000_000, 0, None);
+        vault.svm.process(&[limits], &[vault.authority]).unwrap();
+        let err = vault.create_withdrawals_batch(&batch).unwrap_err();
+        assert!(err.is(TreasuryError::RateLimitExceeded), "{err}");
+        let limits = vault.keys.set_rate_limits(0, 0, 0, None);
+        vault.svm.process(&[limits], &[vault.authority]).unwrap();
+
+        // Large amounts need a proposal, which only create_withdrawal takes
+        let multisig = vault.keys.configure_multisig(
+            vec![vault.authority, Pubkey::new_unique()],
+            1,
+            200_000_000,
+            None,
+        );
+        vault.svm.process(&[multisig], &[vault.authority]).unwrap();
+        let err = vault.create_withdrawals_batch(&batch).unwrap_err();
+        assert!(err.is(TreasuryError::MultisigRequired), "{err}");
+        vault.create_withdrawals_batch(&batch[..1]).unwrap();
+
+        let pause = vault.keys.pause();
+        vault.svm.process(&[pause], &[vault.guardian]).unwrap();
+        let err = vault
+            .create_withdrawals_batch(&[entry(user, 3, 1)])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::VaultPaused), "{err}");
+    }
+}
+
+#[test]
+fn cancel_batch_closes_expired_and_skips_the_rest() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let alice = vault.new_user();
+        let bob = vault.new_user();
+        vault.create_withdrawal(alice, 1, 100_000_000, 60).unwrap();
+        vault.create_withdrawal(alice, 2, 200_000_000, 60).unwrap();
+        vault.create_withdrawal(bob, 1, 300_000_000, 60).unwrap();
+        vault.create_withdrawal(bob, 2, 50_000_000, 3600).unwrap();
+        vault.claim(bob, 1).unwrap();
+        vault.svm.advance(61);
+
+        let rent_before = vault.svm.lamports(&vault.authority);
+        let request_rent = vault
+            .svm
+            .lamports(&vault.keys.withdrawal_address(&alice, 1));
+        let logs = vault
+            .cancel_expired_batch(&[(alice, 1), (bob, 1), (bob, 2), (alice, 2), (alice, 1)])
+            .unwrap();
+
+        let cancelled: Vec<_> = logs
+            .events()
+            .into_iter()
+            .map(|event| match event {
+                VaultEvent::WithdrawalCancelledEvent(event) => {
+                    assert_eq!(event.reason, 0);
+                    (event.user, event.request_id, event.total_reserved)
+                }
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect();
+        assert_eq!(cancelled, [(alice, 1, 250_000_000), (alice, 2, 50_000_000)]);
+
+        assert!(vault.withdrawal(&alice, 1).is_none());
+        assert!(vault.withdrawal(&alice, 2).is_none());
+        assert!(vault.withdrawal(&bob, 2).is_some());
+        assert_eq!(vault.state().total_reserved, 50_000_000);
+        assert_eq!(
+            vault.svm.lamports(&vault.authority),
+            rent_before + 2 * request_rent - SIGNATURE_FEE
+        );
+        let ledger = vault.user_ledger(&alice).unwrap();
+        assert_eq!(
+            (
+                ledger.pending_count,
+                ledger.pending_amount,
+                ledger.cancel_count
+            ),
+            (0, 0, 2)
+        );
+
+        // Nothing expired is not an error
+        vault.cancel_expired_batch(&[(bob, 2)]).unwrap();
+        assert!(vault.withdrawal(&bob, 2).is_some());
+    }
+}
+
+#[test]
+fn cancel_batch_checks_accounts() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let alice = vault.new_user();
+        let bob = vault.new_user();
+        vault.create_withdrawal(alice, 1, 100_000_000, 60).unwrap();
+        vault.create_withdrawal(bob, 1, 100_000_000, 60).unwrap();
+        vault.svm.advance(61);
+
+        let err = vault.cancel_expired_batch(&[]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidBatchAccounts), "{err}");
+
+        let attacker = vault.new_user();
+        let mut keys = vault.keys;
+        keys.authority = attacker;
+        let instruction = keys.cancel_expired_batch(&[(alice, 1)]);
+        let err = vault.svm.process(&[instruction], &[attacker]).unwrap_err();
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+
+        // Ledger of another user
+        let mut instruction = vault.keys.cancel_expired_batch(&[(alice, 1)]);
+        instruction.accounts[3].pubkey = vault.keys.user_ledger_address(&bob);
+        let err = vault
+            .svm
+            .process(&[instruction], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidBatchAccounts), "{err}");
+
+        // A request recorded for another vault
+        let address = vault.keys.withdrawal_address(&bob, 1);
+        let mut account = vault.svm.account(&address).unwrap();
+        account.data[8..40].copy_from_slice(Pubkey::new_unique().as_ref());
+        vault.svm.set_account(address, account);
+        let err = vault
+            .cancel_expired_batch(&[(alice, 1), (bob, 1)])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidVaultAccount), "{err}");
+
+        assert!(vault.withdrawal(&alice, 1).is_some());
+        assert_eq!(vault.state().total_reserved, 200_000_000);
+    }
+}
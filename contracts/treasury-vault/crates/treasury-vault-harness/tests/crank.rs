@@ -0,0 +1,135 @@
+use anchor_lang::prelude::Pubkey;
+use treasury_vault::state::{DEFAULT_CRANK_BOUNTY, DEFAULT_CRANK_GRACE_PERIOD, MAX_CRANK_BOUNTY};
+use treasury_vault_client::TreasuryError;
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, SIGNATURE_FEE, TOKEN_PROGRAMS};
+
+const AMOUNT: u64 = 400_000_000;
+
+/// Vault with one request for a fresh user, expiring in 60s
+fn vault_with_request(token_program: Pubkey) -> (TestVault, Pubkey, i64) {
+    let mut vault = TestVault::new(token_program);
+    vault.deposit(1_000_000_000).unwrap();
+    let user = vault.new_user();
+    vault.create_withdrawal(user, 1, AMOUNT, 60).unwrap();
+    let expires_at = vault.withdrawal(&user, 1).unwrap().expires_at;
+    (vault, user, expires_at)
+}
+
+#[test]
+fn anyone_can_crank_after_the_grace_period() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, user, expires_at) = vault_with_request(token_program);
+        let cranker = vault.new_user();
+        let request = vault.keys.withdrawal_address(&user, 1);
+        let rent = vault.svm.lamports(&request);
+        let authority_lamports = vault.svm.lamports(&vault.authority);
+        let cranker_lamports = vault.svm.lamports(&cranker);
+
+        vault.svm.warp_to(expires_at + DEFAULT_CRANK_GRACE_PERIOD);
+        let err = vault.crank(cranker, user, 1).unwrap_err();
+        assert!(err.is(TreasuryError::CrankTooEarly), "{err}");
+
+        vault.svm.advance(1);
+        let logs = vault.crank(cranker, user, 1).unwrap();
+
+        assert!(vault.svm.account(&request).is_none());
+        assert_eq!(
+            vault.svm.lamports(&cranker),
+            cranker_lamports + DEFAULT_CRANK_BOUNTY - 2 * SIGNATURE_FEE
+        );
+        assert_eq!(
+            vault.svm.lamports(&vault.authority),
+            authority_lamports + rent - DEFAULT_CRANK_BOUNTY
+        );
+        assert_eq!(vault.state().total_reserved, 0);
+        let ledger = vault.user_ledger(&user).unwrap();
+        assert_eq!((ledger.pending_amount, ledger.cancel_count), (0, 1));
+        assert!(matches!(
+            logs.events().as_slice(),
+            [
+                VaultEvent::WithdrawalCancelledEvent(cancelled),
+                VaultEvent::CrankBountyPaidEvent(bounty),
+            ] if cancelled.reason == 0
+                && bounty.cranker == cranker
+                && bounty.bounty == DEFAULT_CRANK_BOUNTY
+        ));
+
+        // Closed for good
+        let err = vault.crank(cranker, user, 1).unwrap_err();
+        assert!(err.treasury_error().is_none(), "{err}");
+    }
+}
+
+#[test]
+fn crank_waits_for_the_configured_grace_period() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, user, expires_at) = vault_with_request(token_program);
+        let cranker = vault.new_user();
+
+        let config = vault.keys.set_crank_config(0, 0);
+        let logs = vault.svm.process(&[config], &[vault.authority]).unwrap();
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::CrankConfigUpdatedEvent(event)]
+                if event.grace_period == 0 && event.bounty == 0
+        ));
+
+        // Still claimable: no grace period doesn't mean early
+        vault.svm.warp_to(expires_at);
+        let err = vault.crank(cranker, user, 1).unwrap_err();
+        assert!(err.is(TreasuryError::CrankTooEarly), "{err}");
+
+        vault.svm.advance(1);
+        let cranker_lamports = vault.svm.lamports(&cranker);
+        let logs = vault.crank(cranker, user, 1).unwrap();
+        assert_eq!(
+            vault.svm.lamports(&cranker),
+            cranker_lamports - SIGNATURE_FEE
+        );
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalCancelledEvent(_)]
+        ));
+    }
+}
+
+#[test]
+fn crank_config_is_authority_only_and_capped() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, user, expires_at) = vault_with_request(token_program);
+        let state = vault.state();
+        assert_eq!(
+            (state.crank_grace_period, state.crank_bounty),
+            (DEFAULT_CRANK_GRACE_PERIOD, DEFAULT_CRANK_BOUNTY)
+        );
+
+        for (grace_period, bounty) in [(-1, 0), (0, MAX_CRANK_BOUNTY + 1)] {
+            let config = vault.keys.set_crank_config(grace_period, bounty);
+            let err = vault
+                .svm
+                .process(&[config], &[vault.authority])
+                .unwrap_err();
+            assert!(err.is(TreasuryError::InvalidCrankConfig), "{err}");
+        }
+
+        let attacker = vault.new_user();
+        let mut keys = vault.keys;
+        keys.authority = attacker;
+        let config = keys.set_crank_config(0, MAX_CRANK_BOUNTY);
+        let err = vault.svm.process(&[config], &[attacker]).unwrap_err();
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+
+        // The rest of the rent can't be redirected away from the authority
+        vault
+            .svm
+            .warp_to(expires_at + DEFAULT_CRANK_GRACE_PERIOD + 1);
+        let crank = keys.crank_cancel_expired(attacker, user, 1);
+        let err = vault.svm.process(&[crank], &[attacker]).unwrap_err();
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+
+        let config = vault.keys.set_crank_config(0, MAX_CRANK_BOUNTY);
+        vault.svm.process(&[config], &[vault.authority]).unwrap();
+        vault.crank(attacker, user, 1).unwrap();
+    }
+}
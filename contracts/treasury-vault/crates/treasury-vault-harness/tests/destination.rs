@@ -0,0 +1,95 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use treasury_vault_client::TreasuryError;
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, TOKEN_PROGRAMS};
+
+const AMOUNT: u64 = 400_000_000;
+
+#[test]
+fn claim_pays_the_destination_chosen_at_creation() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        let cold_wallet = Pubkey::new_unique();
+
+        let logs = vault
+            .create_withdrawal_to(user, 1, AMOUNT, 3600, Some(cold_wallet))
+            .unwrap();
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalCreatedEvent(event)] if event.destination == Some(cold_wallet)
+        ));
+        assert_eq!(
+            vault.withdrawal(&user, 1).unwrap().destination,
+            Some(cold_wallet)
+        );
+
+        let logs = vault.claim(user, 1).unwrap();
+
+        // The user paid for the destination's token account and got nothing
+        assert_eq!(vault.token_balance(&cold_wallet), AMOUNT);
+        assert!(vault
+            .svm
+            .account(&vault.keys.token_account(&user))
+            .is_none());
+        assert_eq!(vault.user_ledger(&user).unwrap().total_claimed, AMOUNT);
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalClaimedEvent(event)]
+                if event.user == user && event.destination == cold_wallet
+        ));
+    }
+}
+
+#[test]
+fn claim_rejects_any_other_destination() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        let cold_wallet = Pubkey::new_unique();
+        let attacker = Pubkey::new_unique();
+        vault
+            .create_withdrawal_to(user, 1, AMOUNT, 3600, Some(cold_wallet))
+            .unwrap();
+        vault.create_withdrawal(user, 2, AMOUNT, 3600).unwrap();
+
+        for destination in [None, Some(attacker)] {
+            let claim = vault.keys.claim_withdrawal(user, 1, destination);
+            let err = vault.svm.process(&[claim], &[user]).unwrap_err();
+            assert!(err.is(TreasuryError::InvalidDestination), "{err}");
+        }
+        // Without a destination only the user's own wallet is accepted
+        let claim = vault.keys.claim_withdrawal(user, 2, Some(cold_wallet));
+        let err = vault.svm.process(&[claim], &[user]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidDestination), "{err}");
+
+        assert_eq!(vault.state().total_reserved, 2 * AMOUNT);
+        vault.claim(user, 1).unwrap();
+        vault.claim(user, 2).unwrap();
+        assert_eq!(vault.token_balance(&cold_wallet), AMOUNT);
+        assert_eq!(vault.token_balance(&user), AMOUNT);
+    }
+}
+
+#[test]
+fn relayed_claim_pays_the_destination() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let relayer = Pubkey::new_unique();
+        vault.svm.airdrop(&relayer, LAMPORTS_PER_SOL);
+        let user = Pubkey::new_unique();
+        let exchange = Pubkey::new_unique();
+        vault
+            .create_withdrawal_to(user, 1, AMOUNT, 3600, Some(exchange))
+            .unwrap();
+
+        vault.claim_relayed(relayer, user, 1, 0).unwrap();
+
+        assert_eq!(vault.token_balance(&exchange), AMOUNT);
+        assert_eq!(vault.svm.lamports(&user), 0);
+    }
+}
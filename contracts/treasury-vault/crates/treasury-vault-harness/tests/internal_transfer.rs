@@ -0,0 +1,262 @@
+use anchor_lang::prelude::Pubkey;
+use treasury_vault::state::{MAX_TRANSFER_ALLOWLIST, TRANSFER_ALLOWLIST_TIMELOCK};
+use treasury_vault_client::instructions::initialize;
+use treasury_vault_client::pda::vault_address;
+use treasury_vault_client::{ProposalAction, TreasuryError};
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, TOKEN_PROGRAMS};
+
+#[test]
+fn transfer_moves_tokens_to_an_allowlisted_vault() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let treasury = vault.keys;
+        // Allowlisted at initialize: jackpot may send back to the treasury
+        let jackpot = vault.init_vault(1, vec![treasury.vault]);
+        vault.deposit(500_000_000).unwrap();
+
+        let err = vault
+            .internal_transfer(jackpot.vault, 100_000_000)
+            .unwrap_err();
+        assert!(err.is(TreasuryError::TransferNotAllowed), "{err}");
+
+        let propose = treasury.propose_transfer_allowlist(vec![jackpot.vault], None);
+        vault.svm.process(&[propose], &[vault.authority]).unwrap();
+        assert_eq!(vault.state().pending_transfer_allowlist, [jackpot.vault]);
+        assert!(vault.state().transfer_allowlist.is_empty());
+
+        let apply = treasury.apply_transfer_allowlist(None);
+        vault.svm.advance(TRANSFER_ALLOWLIST_TIMELOCK - 1);
+        let err = vault.svm.process(&[apply], &[vault.authority]).unwrap_err();
+        assert!(err.is(TreasuryError::TransferAllowlistTimelocked), "{err}");
+        vault.svm.advance(1);
+        let apply = treasury.apply_transfer_allowlist(None);
+        vault.svm.process(&[apply], &[vault.authority]).unwrap();
+
+        let state = vault.state();
+        assert_eq!(state.transfer_allowlist, [jackpot.vault]);
+        assert!(state.pending_transfer_allowlist.is_empty());
+        assert_eq!(state.transfer_allowlist_effective_at, 0);
+        let apply = treasury.apply_transfer_allowlist(None);
+        let err = vault.svm.process(&[apply], &[vault.authority]).unwrap_err();
+        assert!(err.is(TreasuryError::NoPendingTransferAllowlist), "{err}");
+
+        let logs = vault.internal_transfer(jackpot.vault, 200_000_000).unwrap();
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::InternalTransferEvent(event)]
+                if event.vault == treasury.vault
+                    && event.destination_vault == jackpot.vault
+                    && event.amount == 200_000_000
+        ));
+        assert_eq!(vault.vault_balance(), 300_000_000);
+        assert_eq!(
+            vault.svm.token_balance(&jackpot.vault_token_account),
+            200_000_000
+        );
+        assert_eq!(vault.state().total_paid_out, 200_000_000);
+
+        // Reserved funds stay put
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, 250_000_000, 3600).unwrap();
+        let err = vault
+            .internal_transfer(jackpot.vault, 100_000_000)
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InsufficientBalance), "{err}");
+
+        vault.keys = jackpot;
+        let state = vault.state();
+        assert_eq!(
+            (state.total_deposited, state.deposit_count),
+            (200_000_000, 1)
+        );
+        vault.internal_transfer(treasury.vault, 50_000_000).unwrap();
+        assert_eq!(vault.vault_balance(), 150_000_000);
+        assert_eq!(
+            vault.svm.token_balance(&treasury.vault_token_account),
+            350_000_000
+        );
+    }
+}
+
+#[test]
+fn allowlist_is_validated() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let other = Pubkey::new_unique();
+
+        let invalid = [
+            vec![vault.keys.vault],
+            vec![other, other],
+            vec![Pubkey::default()],
+            (0..=MAX_TRANSFER_ALLOWLIST)
+                .map(|_| Pubkey::new_unique())
+                .collect(),
+        ];
+        for allowlist in invalid.clone() {
+            let propose = vault.keys.propose_transfer_allowlist(allowlist, None);
+            let err = vault
+                .svm
+                .process(&[propose], &[vault.authority])
+                .unwrap_err();
+            assert!(err.is(TreasuryError::InvalidTransferAllowlist), "{err}");
+        }
+
+        // Initialize checks the list against the new vault's own address
+        let mut allowlist = invalid[0].clone();
+        allowlist[0] = vault_address(&vault.authority, 1).0;
+        let init = initialize(
+            vault.authority,
+            1,
+            vault.mint.address,
+            vault.payout_wallet,
+            vault.guardian,
+            allowlist,
+            token_program,
+        );
+        let err = vault.svm.process(&[init], &[vault.authority]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidTransferAllowlist), "{err}");
+
+        // Only the authority proposes and applies
+        let impostor = vault.new_user();
+        let mut propose = vault.keys.propose_transfer_allowlist(vec![other], None);
+        propose.accounts[0].pubkey = impostor;
+        let err = vault.svm.process(&[propose], &[impostor]).unwrap_err();
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+        assert_eq!(vault.state().transfer_allowlist_effective_at, 0);
+    }
+}
+
+#[test]
+fn destination_must_take_the_mint() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let jackpot = vault.init_vault(1, Vec::new());
+        let propose = vault
+            .keys
+            .propose_transfer_allowlist(vec![jackpot.vault], None);
+        vault.svm.process(&[propose], &[vault.authority]).unwrap();
+        vault.svm.advance(TRANSFER_ALLOWLIST_TIMELOCK);
+        let apply = vault.keys.apply_transfer_allowlist(None);
+        vault.svm.process(&[apply], &[vault.authority]).unwrap();
+        vault.deposit(500_000_000).unwrap();
+
+        // Mint the destination never registered, even with a token account for it
+        let usdc = vault.add_mint(token_program);
+        let usdc_keys = vault.mint_keys(&usdc.address);
+        vault.svm.create_ata(&jackpot.vault, &usdc.address);
+        vault
+            .svm
+            .process(&[usdc_keys.deposit(100_000_000)], &[vault.authority])
+            .unwrap();
+        let transfer = usdc_keys.internal_transfer(jackpot.vault, 100_000_000, None);
+        let err = vault
+            .svm
+            .process(&[transfer], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidMint), "{err}");
+
+        // Paused destination takes no inflows
+        vault
+            .svm
+            .process(&[jackpot.pause()], &[vault.guardian])
+            .unwrap();
+        let err = vault
+            .internal_transfer(jackpot.vault, 100_000_000)
+            .unwrap_err();
+        assert!(err.is(TreasuryError::VaultPaused), "{err}");
+        assert_eq!(vault.vault_balance(), 500_000_000);
+    }
+}
+
+#[test]
+fn allowlist_changes_need_proposals_in_multisig_mode() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let jackpot = vault.init_vault(1, Vec::new());
+        let signers = vault.enable_multisig();
+
+        let propose = vault
+            .keys
+            .propose_transfer_allowlist(vec![jackpot.vault], None);
+        let err = vault
+            .svm
+            .process(&[propose], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::MultisigRequired), "{err}");
+
+        let action = ProposalAction::ProposeTransferAllowlist {
+            transfer_allowlist: vec![jackpot.vault],
+        };
+        let proposal = vault.approved_proposal(&signers, action);
+        let propose = vault
+            .keys
+            .propose_transfer_allowlist(vec![jackpot.vault], Some(proposal));
+        vault.svm.process(&[propose], &[vault.authority]).unwrap();
+        assert_eq!(vault.state().pending_transfer_allowlist, [jackpot.vault]);
+
+        vault.svm.advance(TRANSFER_ALLOWLIST_TIMELOCK);
+        let apply = vault.keys.apply_transfer_allowlist(None);
+        let err = vault.svm.process(&[apply], &[vault.authority]).unwrap_err();
+        assert!(err.is(TreasuryError::MultisigRequired), "{err}");
+
+        // Approval must name the list that is pending
+        let action = ProposalAction::ApplyTransferAllowlist {
+            transfer_allowlist: vec![Pubkey::new_unique()],
+        };
+        let proposal = vault.approved_proposal(&signers, action);
+        let apply = vault.keys.apply_transfer_allowlist(Some(proposal));
+        let err = vault.svm.process(&[apply], &[vault.authority]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidProposal), "{err}");
+
+        let action = ProposalAction::ApplyTransferAllowlist {
+            transfer_allowlist: vec![jackpot.vault],
+        };
+        let proposal = vault.approved_proposal(&signers, action);
+        let apply = vault.keys.apply_transfer_allowlist(Some(proposal));
+        vault.svm.process(&[apply], &[vault.authority]).unwrap();
+        assert_eq!(vault.state().transfer_allowlist, [jackpot.vault]);
+    }
+}
+
+#[test]
+fn guardian_or_authority_cancels_a_pending_allowlist() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let jackpot = vault.init_vault(1, Vec::new());
+
+        let cancel = vault.keys.cancel_transfer_allowlist(vault.guardian);
+        let err = vault.svm.process(&[cancel], &[vault.guardian]).unwrap_err();
+        assert!(err.is(TreasuryError::NoPendingTransferAllowlist), "{err}");
+
+        for signer in [vault.guardian, vault.authority] {
+            let propose = vault
+                .keys
+                .propose_transfer_allowlist(vec![jackpot.vault], None);
+            vault.svm.process(&[propose], &[vault.authority]).unwrap();
+
+            let impostor = vault.new_user();
+            let cancel = vault.keys.cancel_transfer_allowlist(impostor);
+            let err = vault.svm.process(&[cancel], &[impostor]).unwrap_err();
+            assert!(err.is(TreasuryError::Unauthorized), "{err}");
+
+            let cancel = vault.keys.cancel_transfer_allowlist(signer);
+            let logs = vault.svm.process(&[cancel], &[signer]).unwrap();
+            assert!(matches!(
+                logs.events().as_slice(),
+                [VaultEvent::TransferAllowlistCancelledEvent(event)]
+                    if event.cancelled_by == signer
+                        && event.cancelled_transfer_allowlist == [jackpot.vault]
+            ));
+            let state = vault.state();
+            assert!(state.pending_transfer_allowlist.is_empty());
+            assert_eq!(state.transfer_allowlist_effective_at, 0);
+
+            vault.svm.advance(TRANSFER_ALLOWLIST_TIMELOCK);
+            let apply = vault.keys.apply_transfer_allowlist(None);
+            let err = vault.svm.process(&[apply], &[vault.authority]).unwrap_err();
+            assert!(err.is(TreasuryError::NoPendingTransferAllowlist), "{err}");
+            assert!(vault.state().transfer_allowlist.is_empty());
+        }
+    }
+}
@@ -0,0 +1,865 @@
+//! Random interleavings of vault instructions, checked against a shadow model.
+//!
+//! Every operation picks either its legitimate signer or a random actor, and
+//! the model predicts whether it must succeed. After each step the on-chain
+//! state is compared with the model and the accounting invariants are
+//! asserted. Operations run against the compiled program in LiteSVM, so
+//! build it first; raise the case count for a longer run:
+//!
+//!     anchor build
+//!     PROPTEST_CASES=5000 cargo test --release -p treasury-vault-harness --test invariants
+
+use std::collections::BTreeSet;
+
+use anchor_lang::prelude::Pubkey;
+use proptest::prelude::*;
+use solana_keypair::Keypair;
+use solana_signer::Signer as _;
+use treasury_vault_client::instructions::voucher_signature;
+use treasury_vault_client::{BatchWithdrawal, TreasuryVault, VaultKeys};
+use treasury_vault_harness::{TestVault, TxError, TOKEN_PROGRAMS};
+
+const USERS: usize = 3;
+/// authority, guardian, users, outsider
+const ACTORS: usize = USERS + 3;
+const AUTHORITY: usize = 0;
+const GUARDIAN: usize = 1;
+/// Sponsors relayed claims and collects relayer fees into its token account
+const OUTSIDER: usize = ACTORS - 1;
+/// Index of the payout wallet in `Model::balances`
+const PAYOUT: usize = ACTORS;
+/// Short enough for clock jumps to reach, unlike the 24h default
+const CRANK_GRACE_PERIOD: i64 = 30;
+
+#[derive(Debug, Clone, Copy)]
+enum Signer {
+    Legit,
+    Actor(usize),
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Deposit {
+        amount: u64,
+        signer: Signer,
+    },
+    Payout {
+        amount: u64,
+        signer: Signer,
+    },
+    Create {
+        user: usize,
+        amount: u64,
+        expires_in: i64,
+        /// Actor paid on claim instead of the user
+        destination: Option<usize>,
+        signer: Signer,
+    },
+    /// (user, amount, expires_in) per request, created by one instruction
+    CreateBatch {
+        entries: Vec<(usize, u64, i64)>,
+        signer: Signer,
+    },
+    Claim {
+        request: usize,
+        signer: Signer,
+    },
+    ClaimRelayed {
+        request: usize,
+        max_fee: u64,
+        signer: Signer,
+    },
+    SetRelayer {
+        request: usize,
+        fee: u64,
+        signer: Signer,
+    },
+    /// Voucher for `user`, submitted by the signer
+    ClaimVoucher {
+        user: usize,
+        amount: u64,
+        nonce: u64,
+        expires_in: i64,
+        signer: Signer,
+    },
+    Cancel {
+        request: usize,
+        signer: Signer,
+    },
+    /// Any mix of open, closed and live requests
+    CancelBatch {
+        requests: Vec<usize>,
+        signer: Signer,
+    },
+    /// Permissionless cleanup; the legit cranker is the outsider
+    Crank {
+        request: usize,
+        signer: Signer,
+    },
+    UserCancel {
+        request: usize,
+        reason: u8,
+        signer: Signer,
+    },
+    Extend {
+        request: usize,
+        expires_in: i64,
+        signer: Signer,
+    },
+    Amend {
+        request: usize,
+        amount: u64,
+        signer: Signer,
+    },
+    SetPaused {
+        paused: bool,
+        signer: Signer,
+    },
+    Advance {
+        seconds: i64,
+    },
+    /// Jump to a request's `expires_at + offset` to hit the boundary exactly
+    WarpToExpiry {
+        request: usize,
+        offset: i64,
+    },
+}
+
+fn signer() -> impl Strategy<Value = Signer> {
+    prop_oneof![
+        3 => Just(Signer::Legit),
+        1 => (0..ACTORS).prop_map(Signer::Actor),
+    ]
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..2_000u64, signer()).prop_map(|(amount, signer)| Op::Deposit { amount, signer }),
+        (0..1_000u64, signer()).prop_map(|(amount, signer)| Op::Payout { amount, signer }),
+        (
+            0..USERS,
+            0..800u64,
+            -5..120i64,
+            prop::option::weighted(0.25, 0..ACTORS),
+            signer()
+        )
+            .prop_map(
+                |(user, amount, expires_in, destination, signer)| Op::Create {
+                    user,
+                    amount,
+                    expires_in,
+                    destination,
+                    signer,
+                }
+            ),
+        (
+            prop::collection::vec((0..USERS, 0..400u64, -5..120i64), 1..4),
+            signer()
+        )
+            .prop_map(|(entries, signer)| Op::CreateBatch { entries, signer }),
+        (any::<usize>(), signer()).prop_map(|(request, signer)| Op::Claim { request, signer }),
+        (any::<usize>(), 0..200u64, signer()).prop_map(|(request, max_fee, signer)| {
+            Op::ClaimRelayed {
+                request,
+                max_fee,
+                signer,
+            }
+        }),
+        (any::<usize>(), 0..200u64, signer()).prop_map(|(request, fee, signer)| Op::SetRelayer {
+            request,
+            fee,
+            signer,
+        }),
+        (0..USERS, 0..400u64, 0..8u64, -3..60i64, signer()).prop_map(
+            |(user, amount, nonce, expires_in, signer)| Op::ClaimVoucher {
+                user,
+                amount,
+                nonce,
+                expires_in,
+                signer,
+            }
+        ),
+        (any::<usize>(), signer()).prop_map(|(request, signer)| Op::Cancel { request, signer }),
+        (prop::collection::vec(any::<usize>(), 1..5), signer())
+            .prop_map(|(requests, signer)| Op::CancelBatch { requests, signer }),
+        (any::<usize>(), signer()).prop_map(|(request, signer)| Op::Crank { request, signer }),
+        (any::<usize>(), 0..3u8, signer()).prop_map(|(request, reason, signer)| {
+            Op::UserCancel {
+                request,
+                reason,
+                signer,
+            }
+        }),
+        (any::<usize>(), -5..200i64, signer()).prop_map(|(request, expires_in, signer)| {
+            Op::Extend {
+                request,
+                expires_in,
+                signer,
+            }
+        }),
+        (any::<usize>(), 0..800u64, signer()).prop_map(|(request, amount, signer)| Op::Amend {
+            request,
+            amount,
+            signer,
+        }),
+        (any::<bool>(), signer()).prop_map(|(paused, signer)| Op::SetPaused { paused, signer }),
+        (0..150i64).prop_map(|seconds| Op::Advance { seconds }),
+        (any::<usize>(), -1..=1i64)
+            .prop_map(|(request, offset)| Op::WarpToExpiry { request, offset }),
+    ]
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Request {
+    user: usize,
+    id: u64,
+    /// Actor whose token account receives the claim
+    destination: usize,
+    amount: u64,
+    expires_at: i64,
+    relayer_fee: u64,
+}
+
+/// What the chain should look like if the vault behaves as specified
+#[derive(Debug, Default)]
+struct Model {
+    vault: u64,
+    /// Token balances of each actor's ATA, plus the payout wallet at the end
+    balances: [u64; ACTORS + 1],
+    paused: bool,
+    /// Every request ever created, in creation order
+    requests: Vec<Request>,
+    /// Indices into `requests` that are still open
+    pending: BTreeSet<usize>,
+    /// Gross amount each user has claimed, relayer fees and vouchers included
+    claimed: [u64; USERS],
+    /// (user, nonce) pairs already redeemed
+    used_nonces: BTreeSet<(usize, u64)>,
+    next_request_id: u64,
+    total_deposited: u64,
+    total_paid_out: u64,
+    deposit_count: u32,
+    payout_count: u32,
+}
+
+impl Model {
+    fn reserved(&self) -> u64 {
+        self.pending.iter().map(|&i| self.requests[i].amount).sum()
+    }
+
+    fn available(&self) -> u64 {
+        self.vault - self.reserved()
+    }
+}
+
+struct Fuzz {
+    vault: TestVault,
+    actors: [Pubkey; ACTORS],
+    model: Model,
+    last_state: TreasuryVault,
+    voucher_signer: Keypair,
+}
+
+impl Fuzz {
+    fn new(token_program: Pubkey) -> Self {
+        let mut vault = TestVault::new(token_program);
+        let mut actors = [Pubkey::default(); ACTORS];
+        actors[AUTHORITY] = vault.authority;
+        actors[GUARDIAN] = vault.guardian;
+        for actor in &mut actors[2..] {
+            *actor = vault.new_user();
+        }
+        vault.svm.create_ata(&actors[OUTSIDER], &vault.mint.address);
+        let voucher_signer = vault.enable_vouchers();
+        let crank_config = vault.keys.set_crank_config(CRANK_GRACE_PERIOD, 100_000);
+        vault
+            .svm
+            .process(&[crank_config], &[vault.authority])
+            .expect("set crank config");
+        let mut model = Model::default();
+        model.balances[AUTHORITY] = vault.token_balance(&vault.authority);
+        let last_state = vault.state();
+        Self {
+            vault,
+            actors,
+            model,
+            last_state,
+            voucher_signer,
+        }
+    }
+
+    fn signer(&self, signer: Signer, legit: usize) -> (usize, Pubkey) {
+        let index = match signer {
+            Signer::Legit => legit,
+            Signer::Actor(index) => index,
+        };
+        (index, self.actors[index])
+    }
+
+    /// Vault keys with the authority / guardian replaced, to build
+    /// instructions signed by someone else
+    fn keys_as(&self, authority: Pubkey, guardian: Pubkey) -> VaultKeys {
+        VaultKeys {
+            authority,
+            guardian,
+            ..self.vault.keys
+        }
+    }
+
+    fn pick(&self, request: usize) -> Option<(usize, Request)> {
+        if self.model.requests.is_empty() {
+            return None;
+        }
+        let index = request % self.model.requests.len();
+        Some((index, self.model.requests[index]))
+    }
+
+    fn step(&mut self, op: &Op) -> Result<(), TestCaseError> {
+        let now = self.vault.svm.now();
+        let keys = self.vault.keys;
+        let (instruction, signer, expected) = match *op {
+            Op::Advance { seconds } => {
+                self.vault.svm.advance(seconds);
+                return self.check(op);
+            }
+            Op::WarpToExpiry { request, offset } => {
+                if let Some((_, request)) = self.pick(request) {
+                    let target = request.expires_at + offset;
+                    if target > now {
+                        self.vault.svm.warp_to(target);
+                    }
+                }
+                return self.check(op);
+            }
+            Op::Deposit { amount, signer } => {
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let ok = index == AUTHORITY && !self.model.paused && amount > 0;
+                (self.keys_as(key, keys.guardian).deposit(amount), key, ok)
+            }
+            Op::Payout { amount, signer } => {
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let ok = index == AUTHORITY
+                    && !self.model.paused
+                    && amount > 0
+                    && amount <= self.model.available();
+                (
+                    self.keys_as(key, keys.guardian).payout(amount, None),
+                    key,
+                    ok,
+                )
+            }
+            Op::Create {
+                user,
+                amount,
+                expires_in,
+                destination,
+                signer,
+            } => {
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let ok = index == AUTHORITY
+                    && !self.model.paused
+                    && amount > 0
+                    && expires_in > 0
+                    && amount <= self.model.available();
+                let instruction = self.keys_as(key, keys.guardian).create_withdrawal(
+                    self.actors[2 + user],
+                    self.model.next_request_id,
+                    amount,
+                    expires_in,
+                    destination.map(|index| self.actors[index]),
+                    None,
+                );
+                (instruction, key, ok)
+            }
+            Op::CreateBatch {
+                ref entries,
+                signer,
+            } => {
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let total: u64 = entries.iter().map(|&(_, amount, _)| amount).sum();
+                let ok = index == AUTHORITY
+                    && !self.model.paused
+                    && entries
+                        .iter()
+                        .all(|&(_, amount, expires_in)| amount > 0 && expires_in > 0)
+                    && total <= self.model.available();
+                let withdrawals: Vec<_> = entries
+                    .iter()
+                    .zip(self.model.next_request_id..)
+                    .map(
+                        |(&(user, amount, expires_in), request_id)| BatchWithdrawal {
+                            user: self.actors[2 + user],
+                            request_id,
+                            amount,
+                            expires_in,
+                            destination: None,
+                        },
+                    )
+                    .collect();
+                let instruction = self
+                    .keys_as(key, keys.guardian)
+                    .create_withdrawals_batch(&withdrawals);
+                (instruction, key, ok)
+            }
+            Op::Claim { request, signer } => {
+                let Some((slot, request)) = self.pick(request) else {
+                    return self.check(op);
+                };
+                let (index, key) = self.signer(signer, 2 + request.user);
+                let ok = index == 2 + request.user
+                    && self.model.pending.contains(&slot)
+                    && now <= request.expires_at;
+                let mut instruction = keys.claim_withdrawal(
+                    self.actors[2 + request.user],
+                    request.id,
+                    Some(self.actors[request.destination]),
+                );
+                instruction.accounts[0].pubkey = key;
+                (instruction, key, ok)
+            }
+            Op::ClaimRelayed {
+                request,
+                max_fee,
+                signer,
+            } => {
+                let Some((slot, request)) = self.pick(request) else {
+                    return self.check(op);
+                };
+                let (index, key) = self.signer(signer, 2 + request.user);
+                let ok = index == 2 + request.user
+                    && self.model.pending.contains(&slot)
+                    && now <= request.expires_at
+                    && request.relayer_fee <= max_fee;
+                let fee_payer = self.actors[OUTSIDER];
+                let mut instruction = keys.claim_withdrawal_relayed(
+                    fee_payer,
+                    self.actors[2 + request.user],
+                    request.id,
+                    Some(self.actors[request.destination]),
+                    Some(keys.token_account(&fee_payer)),
+                    max_fee,
+                );
+                instruction.accounts[1].pubkey = key;
+                let result = self.vault.svm.process(&[instruction], &[fee_payer, key]);
+                return self.finish(op, now, result.map(drop), ok);
+            }
+            Op::SetRelayer {
+                request,
+                fee,
+                signer,
+            } => {
+                let Some((slot, request)) = self.pick(request) else {
+                    return self.check(op);
+                };
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let ok = index == AUTHORITY
+                    && self.model.pending.contains(&slot)
+                    && fee < request.amount;
+                let relayer = (fee > 0).then(|| keys.token_account(&self.actors[OUTSIDER]));
+                let instruction = self.keys_as(key, keys.guardian).set_withdrawal_relayer(
+                    self.actors[2 + request.user],
+                    request.id,
+                    relayer,
+                    fee,
+                );
+                (instruction, key, ok)
+            }
+            Op::ClaimVoucher {
+                user,
+                amount,
+                nonce,
+                expires_in,
+                signer,
+            } => {
+                let (index, key) = self.signer(signer, 2 + user);
+                let ok = index == 2 + user
+                    && !self.model.paused
+                    && amount > 0
+                    && expires_in >= 0
+                    && amount <= self.model.available()
+                    && !self.model.used_nonces.contains(&(user, nonce));
+                let voucher = keys.voucher(self.actors[2 + user], amount, nonce, now + expires_in);
+                let signature = self.voucher_signer.sign_message(&voucher.message());
+                let mut claim = keys.claim_with_voucher(&voucher);
+                claim.accounts[0].pubkey = key;
+                let instructions = [
+                    voucher_signature(&self.voucher_signer.pubkey(), &signature.into(), &voucher),
+                    claim,
+                ];
+                let result = self.vault.svm.process(&instructions, &[key]);
+                return self.finish(op, now, result.map(drop), ok);
+            }
+            Op::Cancel { request, signer } => {
+                let Some((slot, request)) = self.pick(request) else {
+                    return self.check(op);
+                };
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let ok = index == AUTHORITY
+                    && self.model.pending.contains(&slot)
+                    && now > request.expires_at;
+                let instruction = self
+                    .keys_as(key, keys.guardian)
+                    .cancel_withdrawal(self.actors[2 + request.user], request.id);
+                (instruction, key, ok)
+            }
+            Op::CancelBatch {
+                ref requests,
+                signer,
+            } => {
+                if self.model.requests.is_empty() {
+                    return self.check(op);
+                }
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let requests: Vec<_> = requests
+                    .iter()
+                    .filter_map(|&request| self.pick(request))
+                    .map(|(_, request)| (self.actors[2 + request.user], request.id))
+                    .collect();
+                let instruction = self
+                    .keys_as(key, keys.guardian)
+                    .cancel_expired_batch(&requests);
+                (instruction, key, index == AUTHORITY)
+            }
+            Op::Crank { request, signer } => {
+                let Some((slot, request)) = self.pick(request) else {
+                    return self.check(op);
+                };
+                // Any actor may crank; only timing decides
+                let (_, key) = self.signer(signer, OUTSIDER);
+                let ok = self.model.pending.contains(&slot)
+                    && now > request.expires_at + CRANK_GRACE_PERIOD;
+                let instruction =
+                    keys.crank_cancel_expired(key, self.actors[2 + request.user], request.id);
+                (instruction, key, ok)
+            }
+            Op::UserCancel {
+                request,
+                reason,
+                signer,
+            } => {
+                let Some((slot, request)) = self.pick(request) else {
+                    return self.check(op);
+                };
+                let (index, key) = self.signer(signer, 2 + request.user);
+                let ok =
+                    index == 2 + request.user && self.model.pending.contains(&slot) && reason != 0;
+                let mut instruction = keys.user_cancel_withdrawal(
+                    self.actors[2 + request.user],
+                    request.id,
+                    self.actors[AUTHORITY],
+                    reason,
+                );
+                instruction.accounts[0].pubkey = key;
+                (instruction, key, ok)
+            }
+            Op::Extend {
+                request,
+                expires_in,
+                signer,
+            } => {
+                let Some((slot, request)) = self.pick(request) else {
+                    return self.check(op);
+                };
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let ok = index == AUTHORITY
+                    && self.model.pending.contains(&slot)
+                    && expires_in > 0
+                    && now + expires_in > request.expires_at;
+                let instruction = self.keys_as(key, keys.guardian).extend_withdrawal(
+                    self.actors[2 + request.user],
+                    request.id,
+                    expires_in,
+                );
+                (instruction, key, ok)
+            }
+            Op::Amend {
+                request,
+                amount,
+                signer,
+            } => {
+                let Some((slot, request)) = self.pick(request) else {
+                    return self.check(op);
+                };
+                let (index, key) = self.signer(signer, AUTHORITY);
+                let ok = index == AUTHORITY
+                    && self.model.pending.contains(&slot)
+                    && amount > request.relayer_fee
+                    && amount < request.amount;
+                let instruction = self.keys_as(key, keys.guardian).amend_withdrawal(
+                    self.actors[2 + request.user],
+                    request.id,
+                    amount,
+                );
+                (instruction, key, ok)
+            }
+            Op::SetPaused { paused, signer } => {
+                let (index, key) = self.signer(signer, GUARDIAN);
+                let keys = self.keys_as(keys.authority, key);
+                let instruction = if paused { keys.pause() } else { keys.unpause() };
+                (instruction, key, index == GUARDIAN)
+            }
+        };
+
+        let result = self.vault.svm.process(&[instruction], &[signer]);
+        self.finish(op, now, result.map(drop), expected)
+    }
+
+    fn finish(
+        &mut self,
+        op: &Op,
+        now: i64,
+        result: Result<(), TxError>,
+        expected: bool,
+    ) -> Result<(), TestCaseError> {
+        prop_assert_eq!(
+            result.is_ok(),
+            expected,
+            "{:?} -> {:?}",
+            op,
+            result.as_ref().err().map(ToString::to_string)
+        );
+        if result.is_ok() {
+            self.apply(op, now);
+        }
+        self.check(op)
+    }
+
+    fn apply(&mut self, op: &Op, now: i64) {
+        let model = &mut self.model;
+        match *op {
+            Op::Deposit { amount, .. } => {
+                model.vault += amount;
+                model.balances[AUTHORITY] -= amount;
+                model.total_deposited += amount;
+                model.deposit_count += 1;
+            }
+            Op::Payout { amount, .. } => {
+                model.vault -= amount;
+                model.balances[PAYOUT] += amount;
+                model.total_paid_out += amount;
+                model.payout_count += 1;
+            }
+            Op::Create {
+                user,
+                amount,
+                expires_in,
+                destination,
+                ..
+            } => {
+                model.requests.push(Request {
+                    user,
+                    id: model.next_request_id,
+                    destination: destination.unwrap_or(2 + user),
+                    amount,
+                    expires_at: now + expires_in,
+                    relayer_fee: 0,
+                });
+                model.pending.insert(model.requests.len() - 1);
+                model.next_request_id += 1;
+            }
+            Op::CreateBatch { ref entries, .. } => {
+                for &(user, amount, expires_in) in entries {
+                    model.requests.push(Request {
+                        user,
+                        id: model.next_request_id,
+                        destination: 2 + user,
+                        amount,
+                        expires_at: now + expires_in,
+                        relayer_fee: 0,
+                    });
+                    model.pending.insert(model.requests.len() - 1);
+                    model.next_request_id += 1;
+                }
+            }
+            Op::Claim { request, .. } => {
+                let slot = request % model.requests.len();
+                let request = model.requests[slot];
+                model.pending.remove(&slot);
+                model.vault -= request.amount;
+                model.balances[request.destination] += request.amount;
+                model.claimed[request.user] += request.amount;
+                model.total_paid_out += request.amount;
+                model.payout_count += 1;
+            }
+            Op::ClaimRelayed { request, .. } => {
+                let slot = request % model.requests.len();
+                let request = model.requests[slot];
+                model.pending.remove(&slot);
+                model.vault -= request.amount;
+                model.balances[request.destination] += request.amount - request.relayer_fee;
+                model.balances[OUTSIDER] += request.relayer_fee;
+                model.claimed[request.user] += request.amount;
+                model.total_paid_out += request.amount;
+                model.payout_count += 1;
+            }
+            Op::SetRelayer { request, fee, .. } => {
+                let slot = request % model.requests.len();
+                model.requests[slot].relayer_fee = fee;
+            }
+            Op::ClaimVoucher {
+                user,
+                amount,
+                nonce,
+                ..
+            } => {
+                model.used_nonces.insert((user, nonce));
+                model.vault -= amount;
+                model.balances[2 + user] += amount;
+                model.claimed[user] += amount;
+                model.total_paid_out += amount;
+                model.payout_count += 1;
+            }
+            Op::CancelBatch { ref requests, .. } => {
+                for request in requests {
+                    let slot = request % model.requests.len();
+                    if now > model.requests[slot].expires_at {
+                        model.pending.remove(&slot);
+                    }
+                }
+            }
+            Op::Cancel { request, .. }
+            | Op::Crank { request, .. }
+            | Op::UserCancel { request, .. } => {
+                let slot = request % model.requests.len();
+                model.pending.remove(&slot);
+            }
+            Op::Extend {
+                request,
+                expires_in,
+                ..
+            } => {
+                let slot = request % model.requests.len();
+                model.requests[slot].expires_at = now + expires_in;
+            }
+            Op::Amend {
+                request, amount, ..
+            } => {
+                let slot = request % model.requests.len();
+                model.requests[slot].amount = amount;
+            }
+            Op::SetPaused { paused, .. } => model.paused = paused,
+            Op::Advance { .. } | Op::WarpToExpiry { .. } => {}
+        }
+    }
+
+    fn check(&mut self, op: &Op) -> Result<(), TestCaseError> {
+        let state = self.vault.state();
+        let balance = self.vault.vault_balance();
+
+        // No token moved except by an authorized, successful instruction
+        prop_assert_eq!(balance, self.model.vault, "vault balance after {:?}", op);
+        for (index, actor) in self.actors.iter().enumerate() {
+            prop_assert_eq!(
+                self.vault.token_balance(actor),
+                self.model.balances[index],
+                "balance of actor {} after {:?}",
+                index,
+                op
+            );
+        }
+        prop_assert_eq!(
+            self.vault.token_balance(&self.vault.payout_wallet),
+            self.model.balances[PAYOUT]
+        );
+
+        // Pending claims are always covered
+        prop_assert!(
+            balance >= state.total_reserved,
+            "reserve uncovered after {:?}",
+            op
+        );
+        prop_assert_eq!(state.total_reserved, self.model.reserved());
+        prop_assert_eq!(state.total_deposited - state.total_paid_out, balance);
+        prop_assert_eq!(state.total_deposited, self.model.total_deposited);
+        prop_assert_eq!(state.total_paid_out, self.model.total_paid_out);
+        prop_assert_eq!(state.deposit_count, self.model.deposit_count);
+        prop_assert_eq!(state.payout_count, self.model.payout_count);
+        prop_assert_eq!(state.paused, self.model.paused);
+
+        // Single-mint run: the primary registry entry carries every total
+        let primary = &state.mints[0];
+        prop_assert_eq!(
+            (
+                primary.total_deposited,
+                primary.total_paid_out,
+                primary.total_reserved
+            ),
+            (
+                state.total_deposited,
+                state.total_paid_out,
+                state.total_reserved
+            )
+        );
+        prop_assert_eq!(
+            (primary.deposit_count, primary.payout_count),
+            (state.deposit_count, state.payout_count)
+        );
+
+        // Counters only move forward
+        let last = &self.last_state;
+        prop_assert!(state.total_deposited >= last.total_deposited);
+        prop_assert!(state.total_paid_out >= last.total_paid_out);
+        prop_assert!(state.deposit_count >= last.deposit_count);
+        prop_assert!(state.payout_count >= last.payout_count);
+        self.last_state = state;
+
+        // Closed requests stay closed, open ones match the model
+        for (slot, request) in self.model.requests.iter().enumerate() {
+            let user = self.actors[2 + request.user];
+            let on_chain = self.vault.withdrawal(&user, request.id);
+            if self.model.pending.contains(&slot) {
+                let on_chain = on_chain.expect("pending request exists");
+                prop_assert_eq!(on_chain.amount, request.amount);
+                prop_assert_eq!(on_chain.expires_at, request.expires_at);
+                prop_assert_eq!(on_chain.relayer_fee, request.relayer_fee);
+            } else {
+                let address = self.vault.keys.withdrawal_address(&user, request.id);
+                prop_assert!(
+                    self.vault.svm.account(&address).is_none(),
+                    "closed request {} revived after {:?}",
+                    request.id,
+                    op
+                );
+            }
+        }
+
+        for user in 0..USERS {
+            let open: Vec<_> = self
+                .model
+                .pending
+                .iter()
+                .map(|&slot| self.model.requests[slot])
+                .filter(|request| request.user == user)
+                .collect();
+            if let Some(ledger) = self.vault.user_ledger(&self.actors[2 + user]) {
+                prop_assert_eq!(ledger.pending_count as usize, open.len());
+                prop_assert_eq!(
+                    ledger.pending_amount,
+                    open.iter().map(|request| request.amount).sum::<u64>()
+                );
+                prop_assert_eq!(ledger.total_claimed, self.model.claimed[user]);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn run(token_program: Pubkey, ops: &[Op]) -> Result<(), TestCaseError> {
+    let mut fuzz = Fuzz::new(token_program);
+    for op in ops {
+        fuzz.step(op)?;
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn spl_token_vault_invariants(ops in prop::collection::vec(op(), 1..60)) {
+        run(TOKEN_PROGRAMS[0], &ops)?;
+    }
+
+    #[test]
+    fn token_2022_vault_invariants(ops in prop::collection::vec(op(), 1..60)) {
+        run(TOKEN_PROGRAMS[1], &ops)?;
+    }
+}
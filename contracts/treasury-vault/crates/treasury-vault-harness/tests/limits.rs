@@ -0,0 +1,103 @@
+//! Transaction size and compute budget of the heaviest instructions.
+//!
+//! Each worst case is sent as a standalone transaction under the default
+//! per-instruction budget, which LiteSVM meters on the compiled program.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use treasury_vault::state::{MAX_VAULT_MINTS, MAX_WITHDRAWAL_BATCH};
+use treasury_vault_client::BatchWithdrawal;
+use treasury_vault_harness::{transaction_size, TestVault, MAX_TRANSACTION_SIZE, TOKEN_PROGRAMS};
+
+/// Budget of an instruction sent without a compute budget instruction
+const DEFAULT_COMPUTE_UNITS: u64 = 200_000;
+
+/// Distinct new users with destinations: the largest accounts and data
+fn full_batch(first_request_id: u64) -> Vec<BatchWithdrawal> {
+    (0..MAX_WITHDRAWAL_BATCH as u64)
+        .map(|offset| BatchWithdrawal {
+            user: Pubkey::new_unique(),
+            request_id: first_request_id + offset,
+            amount: 1_000_000,
+            expires_in: 60,
+            destination: Some(Pubkey::new_unique()),
+        })
+        .collect()
+}
+
+#[test]
+fn full_batch_fits_in_a_transaction() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        vault.create_withdrawals_batch(&full_batch(1)).unwrap();
+        assert_eq!(
+            vault.state().total_reserved,
+            MAX_WITHDRAWAL_BATCH as u64 * 1_000_000
+        );
+    }
+}
+
+/// Sends `instruction` alone as the authority, checking it against the
+/// cluster limits
+fn measure(vault: &mut TestVault, name: &str, instruction: Instruction) {
+    let size = transaction_size(std::slice::from_ref(&instruction), &vault.authority);
+    assert!(size <= MAX_TRANSACTION_SIZE, "{name}: {size} bytes");
+    let units = vault
+        .svm
+        .process(&[instruction], &[vault.authority])
+        .unwrap_or_else(|err| panic!("{name}: {err}\n{}", err.logs.join("\n")))
+        .compute_units;
+    assert!(units <= DEFAULT_COMPUTE_UNITS, "{name}: {units} CU");
+    eprintln!("{name}: {size} bytes, {units} CU");
+}
+
+#[test]
+fn heaviest_instructions_fit_the_default_compute_budget() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+
+        // Every entry creates a request and a user ledger PDA
+        let mut requests = Vec::new();
+        let mut request_id = 1;
+        // As many expired requests as one cancel_expired_batch can carry
+        let fits = |count: usize| {
+            let requests: Vec<_> = (0..count)
+                .map(|id| (Pubkey::new_unique(), id as u64))
+                .collect();
+            transaction_size(
+                &[vault.keys.cancel_expired_batch(&requests)],
+                &vault.authority,
+            ) <= MAX_TRANSACTION_SIZE
+        };
+        let cancel_count = (1..).take_while(|&count| fits(count)).last().unwrap();
+        while requests.len() < cancel_count {
+            let batch = full_batch(request_id);
+            request_id += batch.len() as u64;
+            requests.extend(batch.iter().map(|entry| (entry.user, entry.request_id)));
+            let instruction = vault.keys.create_withdrawals_batch(&batch);
+            measure(&mut vault, "create_withdrawals_batch", instruction);
+        }
+
+        vault.svm.advance(61);
+        let instruction = vault.keys.cancel_expired_batch(&requests[..cancel_count]);
+        measure(&mut vault, "cancel_expired_batch", instruction);
+
+        // Full registry, so the snapshot reads every token account
+        let mut token_accounts = Vec::new();
+        for _ in 1..MAX_VAULT_MINTS {
+            let mint = vault.add_mint(token_program);
+            let keys = vault.mint_keys(&mint.address);
+            vault
+                .svm
+                .process(&[keys.deposit(1_000_000_000)], &[vault.authority])
+                .unwrap();
+            token_accounts.push(keys.vault_token_account);
+        }
+        let instruction = vault
+            .keys
+            .commit_liabilities(1, [7; 32], 0, 0, &token_accounts);
+        measure(&mut vault, "commit_liabilities", instruction);
+    }
+}
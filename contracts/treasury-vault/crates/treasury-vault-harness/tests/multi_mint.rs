@@ -0,0 +1,346 @@
+use anchor_lang::prelude::Pubkey;
+use treasury_vault_client::decode::decode_liability_snapshot;
+use treasury_vault_client::pda::liability_snapshot_address;
+use treasury_vault_client::{MintBalance, ProposalAction, TreasuryError, VaultMint};
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, TOKEN_PROGRAMS};
+
+fn registry_entry(vault: &TestVault, mint: &Pubkey) -> VaultMint {
+    vault.state().mint(mint).cloned().expect("mint registered")
+}
+
+#[test]
+fn each_mint_has_its_own_token_account_and_totals() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(spl_token::ID);
+        let usdc = vault.add_mint(token_program);
+        let usdc_keys = vault.mint_keys(&usdc.address);
+
+        let state = vault.state();
+        assert_eq!(state.mints.len(), 2);
+        assert_eq!(state.mints[0].mint, vault.keys.mint);
+        assert_eq!(state.mints[1].token_account, usdc_keys.vault_token_account);
+
+        vault.deposit(1_000_000_000).unwrap();
+        let logs = vault
+            .svm
+            .process(&[usdc_keys.deposit(400_000_000)], &[vault.authority])
+            .unwrap();
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::DepositEvent(event)] if event.mint == usdc.address
+        ));
+        assert_eq!(
+            vault.svm.token_balance(&usdc_keys.vault_token_account),
+            400_000_000
+        );
+
+        // A request reserves its own mint only
+        let user = vault.new_user();
+        let request = usdc_keys.create_withdrawal(user, 1, 300_000_000, 3600, None, None);
+        vault.svm.process(&[request], &[vault.authority]).unwrap();
+        assert_eq!(vault.withdrawal(&user, 1).unwrap().mint, usdc.address);
+        let err = vault
+            .svm
+            .process(&[usdc_keys.payout(200_000_000, None)], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InsufficientBalance), "{err}");
+        vault.payout(1_000_000_000).unwrap();
+
+        vault.claim(user, 1).unwrap();
+        assert_eq!(
+            vault.svm.token_balance(&usdc_keys.token_account(&user)),
+            300_000_000
+        );
+        assert_eq!(vault.token_balance(&user), 0);
+
+        let entry = registry_entry(&vault, &usdc.address);
+        assert_eq!(
+            (
+                entry.total_deposited,
+                entry.total_paid_out,
+                entry.total_reserved
+            ),
+            (400_000_000, 300_000_000, 0)
+        );
+        let primary = registry_entry(&vault, &vault.keys.mint);
+        assert_eq!(
+            (primary.total_deposited, primary.total_paid_out),
+            (1_000_000_000, 1_000_000_000)
+        );
+        let state = vault.state();
+        assert_eq!(
+            (
+                state.total_deposited,
+                state.total_paid_out,
+                state.deposit_count,
+                state.payout_count
+            ),
+            (1_400_000_000, 1_300_000_000, 2, 2)
+        );
+    }
+}
+
+#[test]
+fn add_mint_checks_authority_decimals_and_registry() {
+    let mut vault = TestVault::new(spl_token::ID);
+
+    let attacker = vault.new_user();
+    let other = vault.svm.create_mint(&vault.authority, 6, &spl_token::ID);
+    let mut keys = vault.mint_keys(&other);
+    keys.authority = attacker;
+    let err = vault
+        .svm
+        .process(&[keys.add_mint(None)], &[attacker])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::Unauthorized), "{err}");
+
+    let nine_decimals = vault.svm.create_mint(&vault.authority, 9, &spl_token::ID);
+    let instruction = vault.mint_keys(&nine_decimals).add_mint(None);
+    let err = vault
+        .svm
+        .process(&[instruction], &[vault.authority])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::InvalidMint), "{err}");
+
+    let err = vault
+        .svm
+        .process(&[vault.keys.add_mint(None)], &[vault.authority])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::MintAlreadyRegistered), "{err}");
+
+    // A token account someone created for the vault up front is reused
+    vault.svm.create_ata(&vault.keys.vault, &other);
+    let instruction = vault.mint_keys(&other).add_mint(None);
+    vault
+        .svm
+        .process(&[instruction], &[vault.authority])
+        .unwrap();
+
+    for _ in 2..8 {
+        vault.add_mint(spl_token_2022::ID);
+    }
+    let last = vault.svm.create_mint(&vault.authority, 6, &spl_token::ID);
+    let instruction = vault.mint_keys(&last).add_mint(None);
+    let err = vault
+        .svm
+        .process(&[instruction], &[vault.authority])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::MintRegistryFull), "{err}");
+    assert_eq!(vault.state().mints.len(), 8);
+}
+
+#[test]
+fn add_mint_needs_a_proposal_in_multisig_mode() {
+    let mut vault = TestVault::new(spl_token::ID);
+    let signers = vault.enable_multisig();
+    let usdc = vault.svm.create_mint(&vault.authority, 6, &spl_token::ID);
+    let keys = vault.mint_keys(&usdc);
+
+    let err = vault
+        .svm
+        .process(&[keys.add_mint(None)], &[vault.authority])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::MultisigRequired), "{err}");
+
+    // Approval names the mint
+    let other = Pubkey::new_unique();
+    let proposal = vault.approved_proposal(&signers, ProposalAction::AddMint { mint: other });
+    let err = vault
+        .svm
+        .process(&[keys.add_mint(Some(proposal))], &[vault.authority])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::InvalidProposal), "{err}");
+
+    let proposal = vault.approved_proposal(&signers, ProposalAction::AddMint { mint: usdc });
+    vault
+        .svm
+        .process(&[keys.add_mint(Some(proposal))], &[vault.authority])
+        .unwrap();
+    assert!(vault.state().mint(&usdc).is_some());
+}
+
+#[test]
+fn token_instructions_reject_unregistered_or_mismatched_mints() {
+    let mut vault = TestVault::new(spl_token::ID);
+    vault.deposit(1_000_000_000).unwrap();
+    let usdc = vault.add_mint(spl_token::ID);
+    let usdc_keys = vault.mint_keys(&usdc.address);
+
+    let stray = vault.svm.create_mint(&vault.authority, 6, &spl_token::ID);
+    vault.svm.create_ata(&vault.authority, &stray);
+    vault.svm.create_ata(&vault.keys.vault, &stray);
+    let err = vault
+        .svm
+        .process(&[vault.mint_keys(&stray).deposit(1)], &[vault.authority])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::InvalidMint), "{err}");
+
+    // Registered mint, another mint's vault token account
+    let mut keys = usdc_keys;
+    keys.vault_token_account = vault.keys.vault_token_account;
+    let user = vault.new_user();
+    let instruction = keys.create_withdrawal(user, 1, 1, 3600, None, None);
+    let err = vault
+        .svm
+        .process(&[instruction], &[vault.authority])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::InvalidVaultAccount), "{err}");
+
+    // A USDT request can't be claimed in USDC
+    vault.create_withdrawal(user, 1, 100_000_000, 3600).unwrap();
+    vault
+        .svm
+        .process(&[usdc_keys.deposit(500_000_000)], &[vault.authority])
+        .unwrap();
+    let instruction = usdc_keys.claim_withdrawal(user, 1, None);
+    let err = vault.svm.process(&[instruction], &[user]).unwrap_err();
+    assert!(err.is(TreasuryError::InvalidMint), "{err}");
+    vault.claim(user, 1).unwrap();
+    assert_eq!(
+        vault.svm.token_balance(&usdc_keys.vault_token_account),
+        500_000_000
+    );
+}
+
+#[test]
+fn disabled_mint_blocks_inflows_and_new_requests_only() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let usdc = vault.add_mint(token_program);
+        let usdc_keys = vault.mint_keys(&usdc.address);
+        vault
+            .svm
+            .process(&[usdc_keys.deposit(1_000_000_000)], &[vault.authority])
+            .unwrap();
+        let signer = vault.enable_vouchers();
+        let user = vault.new_user();
+        let request = usdc_keys.create_withdrawal(user, 1, 100_000_000, 3600, None, None);
+        vault.svm.process(&[request], &[vault.authority]).unwrap();
+
+        let disable = vault.keys.set_mint_enabled(usdc.address, false);
+        let logs = vault.svm.process(&[disable], &[vault.authority]).unwrap();
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::MintStatusChangedEvent(event)]
+                if event.mint == usdc.address && !event.enabled
+        ));
+        assert!(!registry_entry(&vault, &usdc.address).enabled);
+
+        let err = vault
+            .svm
+            .process(&[usdc_keys.deposit(1)], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::MintDisabled), "{err}");
+        let request = usdc_keys.create_withdrawal(user, 2, 1, 3600, None, None);
+        let err = vault
+            .svm
+            .process(&[request], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::MintDisabled), "{err}");
+        let voucher = usdc_keys.voucher(user, 1, 0, vault.svm.now() + 60);
+        let err = vault.claim_with_voucher(&signer, &voucher).unwrap_err();
+        assert!(err.is(TreasuryError::MintDisabled), "{err}");
+
+        // Pending requests still claim, and the rest can be paid out
+        vault.claim(user, 1).unwrap();
+        vault
+            .svm
+            .process(&[usdc_keys.payout(900_000_000, None)], &[vault.authority])
+            .unwrap();
+
+        let attacker = vault.new_user();
+        let mut keys = vault.keys;
+        keys.authority = attacker;
+        let enable = keys.set_mint_enabled(usdc.address, true);
+        let err = vault.svm.process(&[enable], &[attacker]).unwrap_err();
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+        let enable = vault.keys.set_mint_enabled(Pubkey::new_unique(), true);
+        let err = vault
+            .svm
+            .process(&[enable], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidMint), "{err}");
+
+        let enable = vault.keys.set_mint_enabled(usdc.address, true);
+        vault.svm.process(&[enable], &[vault.authority]).unwrap();
+        vault
+            .svm
+            .process(&[usdc_keys.deposit(1)], &[vault.authority])
+            .unwrap();
+    }
+}
+
+#[test]
+fn liabilities_snapshot_sums_every_mint() {
+    let mut vault = TestVault::new(spl_token::ID);
+    vault.deposit(1_000_000_000).unwrap();
+    let usdc = vault.add_mint(spl_token_2022::ID);
+    let usdc_keys = vault.mint_keys(&usdc.address);
+    vault
+        .svm
+        .process(&[usdc_keys.deposit(250_000_000)], &[vault.authority])
+        .unwrap();
+
+    let missing = vault
+        .keys
+        .commit_liabilities(1, [7; 32], 1_000_000_000, 3, &[]);
+    let err = vault
+        .svm
+        .process(&[missing], &[vault.authority])
+        .unwrap_err();
+    assert!(err.is(TreasuryError::InvalidVaultAccount), "{err}");
+    let wrong = vault.keys.commit_liabilities(
+        1,
+        [7; 32],
+        1_000_000_000,
+        3,
+        &[vault.keys.vault_token_account],
+    );
+    let err = vault.svm.process(&[wrong], &[vault.authority]).unwrap_err();
+    assert!(err.is(TreasuryError::InvalidVaultAccount), "{err}");
+
+    let commit = vault.keys.commit_liabilities(
+        1,
+        [7; 32],
+        1_000_000_000,
+        3,
+        &[usdc_keys.vault_token_account],
+    );
+    vault.svm.process(&[commit], &[vault.authority]).unwrap();
+    let address = liability_snapshot_address(&vault.keys.vault, 1).0;
+    let snapshot = decode_liability_snapshot(&vault.svm.account(&address).unwrap().data).unwrap();
+    assert_eq!(snapshot.vault_balance, 1_250_000_000);
+
+    // Reserved tokens are owed to pending requests, not to in-game balances
+    let user = vault.new_user();
+    vault.create_withdrawal(user, 1, 100_000_000, 3600).unwrap();
+    let request = usdc_keys.create_withdrawal(user, 2, 50_000_000, 3600, None, None);
+    vault.svm.process(&[request], &[vault.authority]).unwrap();
+    let commit = vault.keys.commit_liabilities(
+        2,
+        [7; 32],
+        1_000_000_000,
+        3,
+        &[usdc_keys.vault_token_account],
+    );
+    vault.svm.process(&[commit], &[vault.authority]).unwrap();
+    let address = liability_snapshot_address(&vault.keys.vault, 2).0;
+    let snapshot = decode_liability_snapshot(&vault.svm.account(&address).unwrap().data).unwrap();
+    assert_eq!(snapshot.vault_balance, 1_100_000_000);
+    assert_eq!(
+        snapshot.mint_balances,
+        [
+            MintBalance {
+                mint: vault.mint.address,
+                balance: 1_000_000_000,
+                reserved: 100_000_000,
+            },
+            MintBalance {
+                mint: usdc.address,
+                balance: 250_000_000,
+                reserved: 50_000_000,
+            },
+        ]
+    );
+}
@@ -0,0 +1,98 @@
+use treasury_vault_client::instructions::initialize;
+use treasury_vault_client::TreasuryError;
+use treasury_vault_harness::{TestVault, AUTHORITY_BALANCE, TOKEN_PROGRAMS};
+
+#[test]
+fn vaults_under_one_authority_are_independent() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let treasury = vault.keys;
+        let jackpot = vault.init_vault(1, Vec::new());
+        assert_ne!(treasury.vault, jackpot.vault);
+        assert_ne!(treasury.vault_token_account, jackpot.vault_token_account);
+        assert_eq!((treasury.vault_id, jackpot.vault_id), (0, 1));
+
+        vault.deposit(500_000_000).unwrap();
+        vault.keys = jackpot;
+        vault.deposit(200_000_000).unwrap();
+        vault.payout(50_000_000).unwrap();
+
+        // Same user and request id in both vaults: separate PDAs
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, 100_000_000, 60).unwrap();
+        vault.keys = treasury;
+        vault.create_withdrawal(user, 1, 300_000_000, 60).unwrap();
+        vault.claim(user, 1).unwrap();
+        assert_eq!(vault.token_balance(&user), 300_000_000);
+
+        let state = vault.state();
+        assert_eq!(state.vault_id, 0);
+        assert_eq!(
+            (state.total_deposited, state.total_paid_out),
+            (500_000_000, 300_000_000)
+        );
+        assert_eq!(state.total_reserved, 0);
+        assert_eq!(vault.vault_balance(), 200_000_000);
+
+        vault.keys = jackpot;
+        let state = vault.state();
+        assert_eq!(state.vault_id, 1);
+        assert_eq!(
+            (state.total_deposited, state.total_paid_out),
+            (200_000_000, 50_000_000)
+        );
+        assert_eq!(state.total_reserved, 100_000_000);
+        assert_eq!(vault.vault_balance(), 150_000_000);
+        assert_eq!(vault.withdrawal(&user, 1).unwrap().amount, 100_000_000);
+
+        assert_eq!(
+            vault.token_balance(&vault.authority),
+            AUTHORITY_BALANCE - 700_000_000
+        );
+    }
+}
+
+#[test]
+fn vault_cannot_move_another_vaults_tokens() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let treasury = vault.keys;
+        let jackpot = vault.init_vault(1, Vec::new());
+        vault.deposit(500_000_000).unwrap();
+
+        // Jackpot vault signing for the treasury's token account
+        let mut payout = jackpot.payout(100_000_000, None);
+        payout.accounts[3].pubkey = treasury.vault_token_account;
+        let err = vault
+            .svm
+            .process(&[payout], &[vault.authority])
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidVaultAccount), "{err}");
+        assert_eq!(vault.vault_balance(), 500_000_000);
+    }
+}
+
+#[test]
+fn vault_id_cannot_be_initialized_twice() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.init_vault(1, Vec::new());
+
+        for vault_id in [0, 1] {
+            let instruction = initialize(
+                vault.authority,
+                vault_id,
+                vault.mint.address,
+                vault.payout_wallet,
+                vault.guardian,
+                Vec::new(),
+                token_program,
+            );
+            assert!(vault
+                .svm
+                .process(&[instruction], &[vault.authority])
+                .is_err());
+        }
+        assert_eq!(vault.state().vault_id, 0);
+    }
+}
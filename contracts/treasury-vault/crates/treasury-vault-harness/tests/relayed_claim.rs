@@ -0,0 +1,172 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use treasury_vault::state::MAX_RELAYER_FEE;
+use treasury_vault_client::TreasuryError;
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, TOKEN_PROGRAMS};
+
+const AMOUNT: u64 = 400_000_000;
+const FEE: u64 = 250_000;
+
+/// A relayer wallet with SOL and a token account for the vault mint
+fn new_relayer(vault: &mut TestVault) -> (Pubkey, Pubkey) {
+    let relayer = Pubkey::new_unique();
+    vault.svm.airdrop(&relayer, LAMPORTS_PER_SOL);
+    let tokens = vault.svm.create_ata(&relayer, &vault.mint.address);
+    (relayer, tokens)
+}
+
+#[test]
+fn relayer_sponsors_claim_for_user_without_sol() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let (relayer, relayer_tokens) = new_relayer(&mut vault);
+        // Never funded: no SOL for fees or the token account rent
+        let user = Pubkey::new_unique();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+        vault
+            .set_relayer(user, 1, Some(relayer_tokens), FEE)
+            .unwrap();
+
+        let logs = vault.claim_relayed(relayer, user, 1, FEE).unwrap();
+
+        assert_eq!(vault.svm.lamports(&user), 0);
+        assert_eq!(vault.token_balance(&user), AMOUNT - FEE);
+        assert_eq!(vault.svm.token_balance(&relayer_tokens), FEE);
+        assert_eq!(vault.vault_balance(), 1_000_000_000 - AMOUNT);
+        assert!(vault.withdrawal(&user, 1).is_none());
+        let state = vault.state();
+        assert_eq!((state.total_paid_out, state.total_reserved), (AMOUNT, 0));
+        let ledger = vault.user_ledger(&user).unwrap();
+        assert_eq!((ledger.pending_count, ledger.total_claimed), (0, AMOUNT));
+        assert!(matches!(
+            logs.events().as_slice(),
+            [
+                VaultEvent::RelayerFeePaidEvent(paid),
+                VaultEvent::WithdrawalClaimedEvent(claimed),
+            ] if paid.fee == FEE && paid.fee_payer == relayer && claimed.amount == AMOUNT
+        ));
+    }
+}
+
+#[test]
+fn relayed_claim_without_fee_needs_no_relayer_account() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let (relayer, _) = new_relayer(&mut vault);
+        let user = Pubkey::new_unique();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+
+        let logs = vault.claim_relayed(relayer, user, 1, 0).unwrap();
+
+        assert_eq!(vault.token_balance(&user), AMOUNT);
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalClaimedEvent(_)]
+        ));
+    }
+}
+
+#[test]
+fn fee_is_capped_and_bounded_by_user() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let (relayer, relayer_tokens) = new_relayer(&mut vault);
+        let user = Pubkey::new_unique();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+        vault.create_withdrawal(user, 2, 100, 3600).unwrap();
+
+        let err = vault
+            .set_relayer(user, 1, Some(relayer_tokens), MAX_RELAYER_FEE + 1)
+            .unwrap_err();
+        assert!(err.is(TreasuryError::RelayerFeeTooHigh), "{err}");
+        // The user must receive something
+        let err = vault
+            .set_relayer(user, 2, Some(relayer_tokens), 100)
+            .unwrap_err();
+        assert!(err.is(TreasuryError::RelayerFeeTooHigh), "{err}");
+        let err = vault.set_relayer(user, 1, None, FEE).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidRelayerAccount), "{err}");
+
+        vault
+            .set_relayer(user, 1, Some(relayer_tokens), MAX_RELAYER_FEE)
+            .unwrap();
+        let err = vault
+            .claim_relayed(relayer, user, 1, MAX_RELAYER_FEE - 1)
+            .unwrap_err();
+        assert!(err.is(TreasuryError::RelayerFeeTooHigh), "{err}");
+        let err = vault.amend(user, 1, MAX_RELAYER_FEE).unwrap_err();
+        assert!(err.is(TreasuryError::RelayerFeeTooHigh), "{err}");
+
+        // Only the authority sets the fee
+        let set = vault
+            .keys
+            .set_withdrawal_relayer(user, 1, Some(relayer_tokens), 1);
+        assert!(vault.svm.process(&[set], &[relayer]).is_err());
+
+        vault
+            .claim_relayed(relayer, user, 1, MAX_RELAYER_FEE)
+            .unwrap();
+        assert_eq!(vault.token_balance(&user), AMOUNT - MAX_RELAYER_FEE);
+    }
+}
+
+#[test]
+fn fee_only_goes_to_recorded_relayer_account() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let (_, relayer_tokens) = new_relayer(&mut vault);
+        let (other, other_tokens) = new_relayer(&mut vault);
+        let user = Pubkey::new_unique();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+        vault
+            .set_relayer(user, 1, Some(relayer_tokens), FEE)
+            .unwrap();
+
+        let claim =
+            vault
+                .keys
+                .claim_withdrawal_relayed(other, user, 1, None, Some(other_tokens), FEE);
+        let err = vault.svm.process(&[claim], &[other, user]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidRelayerAccount), "{err}");
+        let claim = vault
+            .keys
+            .claim_withdrawal_relayed(other, user, 1, None, None, FEE);
+        let err = vault.svm.process(&[claim], &[other, user]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidRelayerAccount), "{err}");
+
+        // Any fee payer may sponsor, but the fee still lands on the recorded account
+        let claim =
+            vault
+                .keys
+                .claim_withdrawal_relayed(other, user, 1, None, Some(relayer_tokens), FEE);
+        vault.svm.process(&[claim], &[other, user]).unwrap();
+        assert_eq!(vault.svm.token_balance(&relayer_tokens), FEE);
+        assert_eq!(vault.svm.token_balance(&other_tokens), 0);
+    }
+}
+
+#[test]
+fn relayed_claim_requires_user_signature_and_open_request() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let (relayer, _) = new_relayer(&mut vault);
+        let user = Pubkey::new_unique();
+        vault.create_withdrawal(user, 1, AMOUNT, 60).unwrap();
+
+        let claim = vault
+            .keys
+            .claim_withdrawal_relayed(relayer, user, 1, None, None, 0);
+        assert!(vault.svm.process(&[claim], &[relayer]).is_err());
+
+        vault.svm.advance(61);
+        let err = vault.claim_relayed(relayer, user, 1, 0).unwrap_err();
+        assert!(err.is(TreasuryError::WithdrawalExpired), "{err}");
+        assert_eq!(vault.state().total_reserved, AMOUNT);
+    }
+}
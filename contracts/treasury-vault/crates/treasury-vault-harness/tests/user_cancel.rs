@@ -0,0 +1,141 @@
+use treasury_vault_client::TreasuryError;
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, TOKEN_PROGRAMS};
+
+const AMOUNT: u64 = 400_000_000;
+/// App-defined "re-request to another wallet" code
+const REASON: u8 = 2;
+
+#[test]
+fn user_cancels_active_request() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+        let request = vault.keys.withdrawal_address(&user, 1);
+        let rent = vault.svm.lamports(&request);
+        let authority_lamports = vault.svm.lamports(&vault.authority);
+
+        let logs = vault.user_cancel(user, 1, REASON).unwrap();
+
+        assert!(vault.svm.account(&request).is_none());
+        assert_eq!(
+            vault.svm.lamports(&vault.authority),
+            authority_lamports + rent
+        );
+        assert_eq!(vault.state().total_reserved, 0);
+        assert_eq!(vault.vault_balance(), 1_000_000_000);
+        let ledger = vault.user_ledger(&user).unwrap();
+        assert_eq!((ledger.pending_count, ledger.cancel_count), (0, 1));
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalCancelledEvent(event)]
+                if event.reason == REASON && event.amount == AMOUNT
+        ));
+        assert!(vault.claim(user, 1).is_err());
+    }
+}
+
+#[test]
+fn rent_goes_back_to_the_payer_after_rotation() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+        let request = vault.keys.withdrawal_address(&user, 1);
+        let rent = vault.svm.lamports(&request);
+        assert_eq!(vault.withdrawal(&user, 1).unwrap().payer, vault.authority);
+
+        let previous = vault.authority;
+        let rotated = vault.new_user();
+        let propose = vault.keys.propose_authority(rotated);
+        vault.svm.process(&[propose], &[previous]).unwrap();
+        let accept = vault.keys.accept_authority(rotated);
+        vault.svm.process(&[accept], &[rotated]).unwrap();
+        vault.authority = rotated;
+        vault.keys.authority = rotated;
+        let previous_lamports = vault.svm.lamports(&previous);
+        let rotated_lamports = vault.svm.lamports(&rotated);
+
+        // Only the recorded payer may receive the rent
+        let cancel = vault.keys.user_cancel_withdrawal(user, 1, rotated, REASON);
+        let err = vault.svm.process(&[cancel], &[user]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidPayer), "{err}");
+
+        let cancel = vault.keys.user_cancel_withdrawal(user, 1, previous, REASON);
+        vault.svm.process(&[cancel], &[user]).unwrap();
+        assert!(vault.svm.account(&request).is_none());
+        assert_eq!(vault.svm.lamports(&previous), previous_lamports + rent);
+        assert_eq!(vault.svm.lamports(&rotated), rotated_lamports);
+    }
+}
+
+#[test]
+fn user_cancel_works_while_paused_and_after_expiry() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 60).unwrap();
+        vault.create_withdrawal(user, 2, AMOUNT, 60).unwrap();
+        let pause = vault.keys.pause();
+        vault.svm.process(&[pause], &[vault.guardian]).unwrap();
+
+        vault.user_cancel(user, 1, REASON).unwrap();
+        vault.svm.advance(61);
+        vault.user_cancel(user, 2, REASON).unwrap();
+
+        assert_eq!(vault.state().total_reserved, 0);
+    }
+}
+
+#[test]
+fn only_the_user_can_cancel_with_a_nonzero_reason() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        let other = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 3600).unwrap();
+
+        let err = vault.user_cancel(user, 1, 0).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidCancelReason), "{err}");
+
+        // Another wallet pointing at the user's request fails the seeds check
+        let mut cancel = vault
+            .keys
+            .user_cancel_withdrawal(other, 1, vault.authority, REASON);
+        cancel.accounts[3].pubkey = vault.keys.withdrawal_address(&user, 1);
+        assert!(vault.svm.process(&[cancel], &[other]).is_err());
+
+        // The authority cannot use this path to cut the window short
+        let cancel = vault
+            .keys
+            .user_cancel_withdrawal(user, 1, vault.authority, REASON);
+        assert!(vault.svm.process(&[cancel], &[vault.authority]).is_err());
+
+        assert!(vault.withdrawal(&user, 1).is_some());
+        assert_eq!(vault.state().total_reserved, AMOUNT);
+    }
+}
+
+#[test]
+fn expired_cancel_reports_reserved_reason() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, AMOUNT, 60).unwrap();
+        vault.svm.advance(61);
+
+        let logs = vault.cancel(user, 1).unwrap();
+
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalCancelledEvent(event)]
+                if event.reason == treasury_vault::state::CANCEL_REASON_EXPIRED
+        ));
+    }
+}
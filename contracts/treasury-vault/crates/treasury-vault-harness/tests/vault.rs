@@ -0,0 +1,315 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+use treasury_vault::state::RATE_LIMIT_WINDOW;
+use treasury_vault_client::{ProposalAction, TreasuryError};
+use treasury_vault_harness::{InstructionError, TestVault, AUTHORITY_BALANCE, TOKEN_PROGRAMS};
+
+#[test]
+fn deposit_and_payout_move_tokens() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(500_000_000).unwrap();
+        vault.payout(200_000_000).unwrap();
+
+        assert_eq!(vault.vault_balance(), 300_000_000);
+        assert_eq!(
+            vault.token_balance(&vault.authority),
+            AUTHORITY_BALANCE - 500_000_000
+        );
+        assert_eq!(vault.token_balance(&vault.payout_wallet), 200_000_000);
+        let state = vault.state();
+        assert_eq!(
+            (state.total_deposited, state.total_paid_out),
+            (500_000_000, 200_000_000)
+        );
+        assert_eq!((state.deposit_count, state.payout_count), (1, 1));
+    }
+}
+
+#[test]
+fn paused_vault_blocks_outflows_but_not_claims() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(500_000_000).unwrap();
+        let user = vault.new_user();
+        vault.create_withdrawal(user, 1, 100_000_000, 60).unwrap();
+
+        let pause = vault.keys.pause();
+        vault.svm.process(&[pause], &[vault.guardian]).unwrap();
+
+        assert!(vault.payout(1).unwrap_err().is(TreasuryError::VaultPaused));
+        assert!(vault.deposit(1).unwrap_err().is(TreasuryError::VaultPaused));
+        vault.claim(user, 1).unwrap();
+        assert_eq!(vault.token_balance(&user), 100_000_000);
+    }
+}
+
+#[test]
+fn only_the_user_can_claim() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(500_000_000).unwrap();
+        let user = vault.new_user();
+        let thief = vault.new_user();
+        vault.create_withdrawal(user, 1, 100_000_000, 60).unwrap();
+
+        // Without the user's signature
+        let claim = vault.keys.claim_withdrawal(user, 1, None);
+        let err = vault.svm.process(&[claim], &[thief]).unwrap_err();
+        assert!(matches!(err.error, InstructionError::Runtime(_)));
+
+        // Someone else's request PDA under their own key
+        let mut claim = vault.keys.claim_withdrawal(thief, 1, None);
+        claim.accounts[3].pubkey = vault.keys.withdrawal_address(&user, 1);
+        assert!(vault.svm.process(&[claim], &[thief]).is_err());
+
+        assert_eq!(vault.token_balance(&thief), 0);
+        assert_eq!(vault.vault_balance(), 500_000_000);
+    }
+}
+
+#[test]
+fn failed_transaction_rolls_back_earlier_instructions() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let deposit = vault.keys.deposit(500_000_000);
+        let payout = vault.keys.payout(600_000_000, None);
+
+        let err = vault
+            .svm
+            .process(&[deposit, payout], &[vault.authority])
+            .unwrap_err();
+
+        assert_eq!(err.instruction, 1);
+        assert!(err.is(TreasuryError::InsufficientBalance), "{err}");
+        assert_eq!(vault.vault_balance(), 0);
+        assert_eq!(vault.state().deposit_count, 0);
+    }
+}
+
+#[test]
+fn non_authority_cannot_create_withdrawals() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(500_000_000).unwrap();
+        let impostor = vault.new_user();
+        let user = Pubkey::new_unique();
+
+        let mut create = vault.keys.create_withdrawal(user, 1, 1, 60, None, None);
+        create.accounts[0].pubkey = impostor;
+        let err = vault.svm.process(&[create], &[impostor]).unwrap_err();
+
+        assert!(err.is(TreasuryError::Unauthorized), "{err}");
+        assert!(vault.withdrawal(&user, 1).is_none());
+    }
+}
+
+#[test]
+fn guardian_cannot_go_to_the_pending_authority() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let successor = vault.new_user();
+        let propose = vault.keys.propose_authority(successor);
+        vault.svm.process(&[propose], &[vault.authority]).unwrap();
+
+        let set = vault.keys.set_guardian(successor);
+        let err = vault.svm.process(&[set], &[vault.guardian]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidGuardian), "{err}");
+
+        let accept = vault.keys.accept_authority(successor);
+        vault.svm.process(&[accept], &[successor]).unwrap();
+        assert_eq!(vault.state().authority, successor);
+        assert_ne!(vault.state().guardian, successor);
+    }
+}
+
+#[test]
+fn guardian_cannot_accept_the_authority() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        let successor = vault.new_user();
+        let propose = vault.keys.propose_authority(successor);
+        vault.svm.process(&[propose], &[vault.authority]).unwrap();
+
+        // A vault whose guardian became the proposed key anyway
+        let mut state = vault.state();
+        state.guardian = successor;
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        let mut account = vault.svm.account(&vault.keys.vault).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+        vault.svm.set_account(vault.keys.vault, account);
+
+        let accept = vault.keys.accept_authority(successor);
+        let err = vault.svm.process(&[accept], &[successor]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidNewAuthority), "{err}");
+        assert_eq!(vault.state().authority, vault.authority);
+    }
+}
+
+/// Outflow currently counted against the rate-limit window
+fn window_outflow(vault: &TestVault) -> u64 {
+    vault.state().outflow_buckets.iter().sum()
+}
+
+#[test]
+fn unclaimed_requests_are_refunded_to_the_outflow_window() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        let limits = vault.keys.set_rate_limits(0, 300_000_000, 0, None);
+        vault.svm.process(&[limits], &[vault.authority]).unwrap();
+        let crank_config = vault.keys.set_crank_config(60, 0);
+        vault
+            .svm
+            .process(&[crank_config], &[vault.authority])
+            .unwrap();
+
+        vault.create_withdrawal(user, 1, 200_000_000, 3600).unwrap();
+        let err = vault
+            .create_withdrawal(user, 2, 150_000_000, 60)
+            .unwrap_err();
+        assert!(err.is(TreasuryError::RateLimitExceeded), "{err}");
+        vault.user_cancel(user, 1, 2).unwrap();
+        assert_eq!(window_outflow(&vault), 0);
+
+        // Only the amount taken off is refunded
+        vault.create_withdrawal(user, 2, 250_000_000, 60).unwrap();
+        vault.amend(user, 2, 100_000_000).unwrap();
+        assert_eq!(window_outflow(&vault), 100_000_000);
+        vault.create_withdrawal(user, 3, 200_000_000, 60).unwrap();
+        let err = vault.create_withdrawal(user, 4, 1, 60).unwrap_err();
+        assert!(err.is(TreasuryError::RateLimitExceeded), "{err}");
+
+        // Within the window a request gives back its whole amount
+        vault.svm.advance(12 * 60 * 60);
+        vault.cancel(user, 2).unwrap();
+        assert_eq!(window_outflow(&vault), 200_000_000);
+        vault.cancel_expired_batch(&[(user, 3)]).unwrap();
+        assert_eq!(window_outflow(&vault), 0);
+
+        vault.payout(100_000_000).unwrap();
+        vault.create_withdrawal(user, 4, 200_000_000, 60).unwrap();
+        vault.svm.advance(6 * 60 * 60);
+        let cranker = vault.new_user();
+        vault.crank(cranker, user, 4).unwrap();
+        assert_eq!(window_outflow(&vault), 100_000_000);
+
+        // Outflow older than the window has already dropped out: nothing to refund
+        vault.create_withdrawal(user, 5, 100_000_000, 60).unwrap();
+        vault.svm.advance(25 * 60 * 60);
+        vault.cancel(user, 5).unwrap();
+        assert_eq!(window_outflow(&vault), 0);
+        assert_eq!(vault.state().total_reserved, 0);
+    }
+}
+
+#[test]
+fn no_24h_span_exceeds_the_outflow_cap() {
+    const CAP: u64 = 300_000_000;
+    const STEP: i64 = 30 * 60;
+
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(10 * CAP).unwrap();
+        let limits = vault.keys.set_rate_limits(0, CAP, 0, None);
+        vault.svm.process(&[limits], &[vault.authority]).unwrap();
+
+        // Spend the whole cap just before a window boundary, then keep trying
+        // every half hour for three days
+        let mut spent = vec![(vault.svm.now(), CAP - 50_000_000)];
+        vault.payout(CAP - 50_000_000).unwrap();
+        vault.svm.advance(RATE_LIMIT_WINDOW - STEP);
+        let end = vault.svm.now() + 3 * RATE_LIMIT_WINDOW;
+        while vault.svm.now() < end {
+            match vault.payout(50_000_000) {
+                Ok(_) => spent.push((vault.svm.now(), 50_000_000)),
+                Err(err) => assert!(err.is(TreasuryError::RateLimitExceeded), "{err}"),
+            }
+            vault.svm.advance(STEP);
+        }
+
+        for &(at, _) in &spent {
+            let span: u64 = spent
+                .iter()
+                .filter(|(t, _)| *t <= at && at - *t < RATE_LIMIT_WINDOW)
+                .map(|(_, amount)| amount)
+                .sum();
+            assert!(span <= CAP, "{span} left in the 24h before {at}");
+        }
+        // The budget still comes back once a full window has passed
+        assert!(spent.len() > 6, "{spent:?}");
+    }
+}
+
+#[test]
+fn executed_and_stale_proposals_are_closed_to_the_proposer() {
+    let mut vault = TestVault::new(TOKEN_PROGRAMS[0]);
+    let signers = vault.enable_multisig();
+    let set_limits = ProposalAction::SetRateLimits {
+        max_payout_per_tx: 1,
+        max_outflow_per_window: 0,
+        max_withdrawal_amount: 0,
+    };
+
+    // Only half approved: still live
+    let create = vault
+        .keys
+        .create_proposal(signers[0], 0, set_limits.clone());
+    vault.svm.process(&[create], &[signers[0]]).unwrap();
+    let close = vault.keys.close_proposal(signers[0], 0);
+    let err = vault.svm.process(&[close], &[vault.authority]).unwrap_err();
+    assert!(err.is(TreasuryError::ProposalStillActive), "{err}");
+
+    let approve = vault.keys.approve_proposal(signers[1], 0);
+    vault.svm.process(&[approve], &[signers[1]]).unwrap();
+    let proposal = vault.keys.proposal_address(0);
+    let set_limits = vault.keys.set_rate_limits(1, 0, 0, Some(proposal));
+    vault
+        .svm
+        .process(&[set_limits], &[vault.authority])
+        .unwrap();
+
+    // Rent goes to the proposer, whoever sends the transaction
+    let close = vault.keys.close_proposal(signers[1], 0);
+    let err = vault.svm.process(&[close], &[vault.authority]).unwrap_err();
+    assert!(err.is(TreasuryError::InvalidPayer), "{err}");
+    let rent = vault.svm.account(&proposal).unwrap().lamports;
+    let before = vault.svm.lamports(&signers[0]);
+    let close = vault.keys.close_proposal(signers[0], 0);
+    vault.svm.process(&[close], &[vault.authority]).unwrap();
+    assert!(vault.svm.account(&proposal).is_none());
+    assert_eq!(vault.svm.lamports(&signers[0]), before + rent);
+
+    // A proposal from before a signer set change can never run, so it closes
+    let stale = vault.keys.create_proposal(
+        signers[1],
+        1,
+        ProposalAction::SetRateLimits {
+            max_payout_per_tx: 2,
+            max_outflow_per_window: 0,
+            max_withdrawal_amount: 0,
+        },
+    );
+    vault.svm.process(&[stale], &[signers[1]]).unwrap();
+    let reconfigure = ProposalAction::ConfigureMultisig {
+        signers: signers.to_vec(),
+        threshold: 1,
+        large_withdrawal_threshold: 0,
+    };
+    let proposal = vault.approved_proposal(&signers, reconfigure);
+    let configure = vault
+        .keys
+        .configure_multisig(signers.to_vec(), 1, 0, Some(proposal));
+    vault.svm.process(&[configure], &[vault.authority]).unwrap();
+
+    for (proposer, proposal_id) in [(signers[1], 1), (signers[0], 2)] {
+        let close = vault.keys.close_proposal(proposer, proposal_id);
+        vault.svm.process(&[close], &[vault.authority]).unwrap();
+        assert!(vault
+            .svm
+            .account(&vault.keys.proposal_address(proposal_id))
+            .is_none());
+    }
+}
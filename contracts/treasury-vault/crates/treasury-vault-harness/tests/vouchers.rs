@@ -0,0 +1,234 @@
+use anchor_lang::prelude::Pubkey;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use treasury_vault_client::decode::decode_voucher_nonces;
+use treasury_vault_client::instructions::voucher_signature;
+use treasury_vault_client::{ProposalAction, TreasuryError};
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{InstructionError, TestVault, TOKEN_PROGRAMS};
+
+const AMOUNT: u64 = 250_000_000;
+
+fn funded_vault(token_program: Pubkey) -> (TestVault, Keypair) {
+    let mut vault = TestVault::new(token_program);
+    vault.deposit(1_000_000_000).unwrap();
+    let signer = vault.enable_vouchers();
+    (vault, signer)
+}
+
+#[test]
+fn voucher_claim_pays_user_in_one_transaction() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, signer) = funded_vault(token_program);
+        let user = vault.new_user();
+        let expires_at = vault.svm.now() + 600;
+        let voucher = vault.keys.voucher(user, AMOUNT, 7, expires_at);
+
+        let logs = vault.claim_with_voucher(&signer, &voucher).unwrap();
+
+        assert_eq!(vault.token_balance(&user), AMOUNT);
+        assert_eq!(vault.vault_balance(), 1_000_000_000 - AMOUNT);
+        let state = vault.state();
+        assert_eq!(
+            (
+                state.total_paid_out,
+                state.total_reserved,
+                state.payout_count
+            ),
+            (AMOUNT, 0, 1)
+        );
+        let ledger = vault.user_ledger(&user).unwrap();
+        assert_eq!(
+            (
+                ledger.total_claimed,
+                ledger.claim_count,
+                ledger.pending_count
+            ),
+            (AMOUNT, 1, 0)
+        );
+        let nonces = vault.keys.voucher_nonces_address(&user, 7);
+        let nonces = decode_voucher_nonces(&vault.svm.account(&nonces).unwrap().data).unwrap();
+        assert_eq!((nonces.user, nonces.page), (user, 0));
+        assert_eq!(nonces.bitmap[0], 1 << 7);
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::VoucherClaimedEvent(event)]
+                if event.user == user && event.nonce == 7 && event.amount == AMOUNT
+        ));
+    }
+}
+
+#[test]
+fn voucher_nonce_is_single_use() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, signer) = funded_vault(token_program);
+        let user = vault.new_user();
+        let other = vault.new_user();
+        let expires_at = vault.svm.now() + 600;
+        let voucher = vault.keys.voucher(user, AMOUNT, 3, expires_at);
+        vault.claim_with_voucher(&signer, &voucher).unwrap();
+
+        vault.svm.advance(1);
+        let err = vault.claim_with_voucher(&signer, &voucher).unwrap_err();
+        assert!(err.is(TreasuryError::VoucherAlreadyUsed), "{err}");
+
+        // Nonces are per user and span bitmap pages
+        let voucher = vault.keys.voucher(other, 1, 3, expires_at);
+        vault.claim_with_voucher(&signer, &voucher).unwrap();
+        let voucher = vault.keys.voucher(user, 1, 1024 + 3, expires_at);
+        vault.claim_with_voucher(&signer, &voucher).unwrap();
+
+        assert_eq!(vault.token_balance(&user), AMOUNT + 1);
+    }
+}
+
+#[test]
+fn voucher_must_be_signed_by_voucher_signer_for_these_terms() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, signer) = funded_vault(token_program);
+        let user = vault.new_user();
+        let thief = vault.new_user();
+        let expires_at = vault.svm.now() + 600;
+        let voucher = vault.keys.voucher(user, AMOUNT, 1, expires_at);
+
+        let err = vault
+            .claim_with_voucher(&Keypair::new(), &voucher)
+            .unwrap_err();
+        assert!(err.is(TreasuryError::InvalidVoucher), "{err}");
+
+        // A valid signature over different terms
+        let signature = signer.sign_message(&voucher.message()).into();
+        for forged in [
+            vault.keys.voucher(user, AMOUNT * 2, 1, expires_at),
+            vault.keys.voucher(user, AMOUNT, 1, expires_at + 600),
+            vault.keys.voucher(thief, AMOUNT, 1, expires_at),
+        ] {
+            let instructions = [
+                voucher_signature(&signer.pubkey(), &signature, &voucher),
+                vault.keys.claim_with_voucher(&forged),
+            ];
+            let err = vault
+                .svm
+                .process(&instructions, &[forged.user])
+                .unwrap_err();
+            assert!(err.is(TreasuryError::InvalidVoucher), "{err}");
+        }
+
+        // No verification instruction at all
+        let claim = vault.keys.claim_with_voucher(&voucher);
+        let err = vault.svm.process(&[claim], &[user]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidVoucher), "{err}");
+
+        // The precompile itself rejects a bad signature
+        let instructions = [
+            voucher_signature(&signer.pubkey(), &[7; 64], &voucher),
+            vault.keys.claim_with_voucher(&voucher),
+        ];
+        let err = vault.svm.process(&instructions, &[user]).unwrap_err();
+        assert!(matches!(err.error, InstructionError::Runtime(_)), "{err}");
+
+        assert_eq!(vault.token_balance(&user), 0);
+        assert_eq!(vault.state().total_paid_out, 0);
+    }
+}
+
+#[test]
+fn voucher_claims_respect_vault_limits() {
+    for token_program in TOKEN_PROGRAMS {
+        let mut vault = TestVault::new(token_program);
+        vault.deposit(1_000_000_000).unwrap();
+        let user = vault.new_user();
+        let now = vault.svm.now();
+
+        let signer = Keypair::new();
+        let voucher = vault.keys.voucher(user, AMOUNT, 1, now + 600);
+        let err = vault.claim_with_voucher(&signer, &voucher).unwrap_err();
+        assert!(err.is(TreasuryError::VouchersDisabled), "{err}");
+
+        let signer = vault.enable_vouchers();
+        let expired = vault.keys.voucher(user, AMOUNT, 2, now - 1);
+        let err = vault.claim_with_voucher(&signer, &expired).unwrap_err();
+        assert!(err.is(TreasuryError::WithdrawalExpired), "{err}");
+
+        // Funds reserved for requests are off limits
+        let other = vault.new_user();
+        vault.create_withdrawal(other, 1, 900_000_000, 600).unwrap();
+        let err = vault.claim_with_voucher(&signer, &voucher).unwrap_err();
+        assert!(err.is(TreasuryError::InsufficientBalance), "{err}");
+        vault.user_cancel(other, 1, 1).unwrap();
+
+        let pause = vault.keys.pause();
+        vault.svm.process(&[pause], &[vault.guardian]).unwrap();
+        let err = vault.claim_with_voucher(&signer, &voucher).unwrap_err();
+        assert!(err.is(TreasuryError::VaultPaused), "{err}");
+        let unpause = vault.keys.unpause();
+        vault.svm.process(&[unpause], &[vault.guardian]).unwrap();
+
+        let limits = vault.keys.set_rate_limits(0, 0, AMOUNT - 1, None);
+        vault.svm.process(&[limits], &[vault.authority]).unwrap();
+        let err = vault.claim_with_voucher(&signer, &voucher).unwrap_err();
+        assert!(err.is(TreasuryError::RateLimitExceeded), "{err}");
+        let limits = vault.keys.set_rate_limits(0, 0, 0, None);
+        vault.svm.process(&[limits], &[vault.authority]).unwrap();
+
+        // Large amounts still need a multisig proposal
+        let multisig = vault.keys.configure_multisig(
+            vec![vault.authority, Pubkey::new_unique()],
+            1,
+            AMOUNT - 1,
+            None,
+        );
+        vault.svm.process(&[multisig], &[vault.authority]).unwrap();
+        let err = vault.claim_with_voucher(&signer, &voucher).unwrap_err();
+        assert!(err.is(TreasuryError::MultisigRequired), "{err}");
+        let small = vault.keys.voucher(user, AMOUNT - 1, 3, now + 600);
+        vault.claim_with_voucher(&signer, &small).unwrap();
+
+        // Disabling the signer stops its vouchers
+        let action = ProposalAction::SetVoucherSigner {
+            voucher_signer: Pubkey::default(),
+        };
+        let proposal = vault.approved_proposal(&[vault.authority], action);
+        let disable = vault
+            .keys
+            .set_voucher_signer(Pubkey::default(), Some(proposal));
+        vault.svm.process(&[disable], &[vault.authority]).unwrap();
+        let small = vault.keys.voucher(user, 1, 4, now + 600);
+        let err = vault.claim_with_voucher(&signer, &small).unwrap_err();
+        assert!(err.is(TreasuryError::VouchersDisabled), "{err}");
+    }
+}
+
+#[test]
+fn voucher_signer_needs_a_proposal_in_multisig_mode() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, _) = funded_vault(token_program);
+        let signers = vault.enable_multisig();
+        let rogue = Pubkey::new_unique();
+
+        let set = vault.keys.set_voucher_signer(rogue, None);
+        let err = vault.svm.process(&[set], &[vault.authority]).unwrap_err();
+        assert!(err.is(TreasuryError::MultisigRequired), "{err}");
+
+        // Approved for another key
+        let action = ProposalAction::SetVoucherSigner {
+            voucher_signer: Pubkey::new_unique(),
+        };
+        let proposal = vault.approved_proposal(&signers, action);
+        let set = vault.keys.set_voucher_signer(rogue, Some(proposal));
+        let err = vault.svm.process(&[set], &[vault.authority]).unwrap_err();
+        assert!(err.is(TreasuryError::InvalidProposal), "{err}");
+
+        let action = ProposalAction::SetVoucherSigner {
+            voucher_signer: rogue,
+        };
+        let proposal = vault.approved_proposal(&signers, action);
+        let set = vault.keys.set_voucher_signer(rogue, Some(proposal));
+        vault.svm.process(&[set], &[vault.authority]).unwrap();
+        assert_eq!(vault.state().voucher_signer, rogue);
+
+        let set = vault.keys.set_voucher_signer(rogue, Some(proposal));
+        let err = vault.svm.process(&[set], &[vault.authority]).unwrap_err();
+        assert!(err.is(TreasuryError::ProposalAlreadyExecuted), "{err}");
+    }
+}
@@ -0,0 +1,113 @@
+use treasury_vault_client::TreasuryError;
+use treasury_vault_events::VaultEvent;
+use treasury_vault_harness::{TestVault, SIGNATURE_FEE, TOKEN_PROGRAMS};
+
+const AMOUNT: u64 = 250_000_000;
+const EXPIRES_IN: i64 = 3600;
+
+/// A vault holding 1000 USDT with one pending request for `user`
+fn vault_with_request(
+    token_program: anchor_lang::prelude::Pubkey,
+) -> (TestVault, anchor_lang::prelude::Pubkey, i64) {
+    let mut vault = TestVault::new(token_program);
+    vault.deposit(1_000_000_000).unwrap();
+    let user = vault.new_user();
+    vault
+        .create_withdrawal(user, 1, AMOUNT, EXPIRES_IN)
+        .unwrap();
+    let expires_at = vault.withdrawal(&user, 1).unwrap().expires_at;
+    assert_eq!(expires_at, vault.svm.now() + EXPIRES_IN);
+    (vault, user, expires_at)
+}
+
+#[test]
+fn claim_at_exactly_expires_at_succeeds() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, user, expires_at) = vault_with_request(token_program);
+        let authority_lamports = vault.svm.lamports(&vault.authority);
+        vault.svm.warp_to(expires_at);
+
+        let logs = vault.claim(user, 1).unwrap();
+
+        assert_eq!(vault.token_balance(&user), AMOUNT);
+        assert_eq!(vault.vault_balance(), 1_000_000_000 - AMOUNT);
+        assert!(vault.withdrawal(&user, 1).is_none());
+        // Rent of the closed request goes back to the authority
+        assert!(vault.svm.lamports(&vault.authority) > authority_lamports);
+        let state = vault.state();
+        assert_eq!(state.total_reserved, 0);
+        assert_eq!(state.total_paid_out, AMOUNT);
+        let ledger = vault.user_ledger(&user).unwrap();
+        assert_eq!((ledger.total_claimed, ledger.pending_count), (AMOUNT, 0));
+        assert!(matches!(
+            logs.events().as_slice(),
+            [VaultEvent::WithdrawalClaimedEvent(event)] if event.amount == AMOUNT && event.timestamp == expires_at
+        ));
+    }
+}
+
+#[test]
+fn claim_one_second_after_expiry_fails() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, user, expires_at) = vault_with_request(token_program);
+        vault.svm.warp_to(expires_at + 1);
+
+        let err = vault.claim(user, 1).unwrap_err();
+
+        assert!(err.is(TreasuryError::WithdrawalExpired), "{err}");
+        assert_eq!(vault.token_balance(&user), 0);
+        assert!(vault.withdrawal(&user, 1).is_some());
+    }
+}
+
+#[test]
+fn cancel_at_exactly_expires_at_fails() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, user, expires_at) = vault_with_request(token_program);
+        vault.svm.warp_to(expires_at);
+
+        let err = vault.cancel(user, 1).unwrap_err();
+
+        assert!(err.is(TreasuryError::WithdrawalNotExpired), "{err}");
+        assert_eq!(vault.state().total_reserved, AMOUNT);
+    }
+}
+
+#[test]
+fn cancel_after_expiry_releases_reserve_and_rent() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, user, expires_at) = vault_with_request(token_program);
+        let request = vault.keys.withdrawal_address(&user, 1);
+        let rent = vault.svm.lamports(&request);
+        let authority_lamports = vault.svm.lamports(&vault.authority);
+        vault.svm.warp_to(expires_at + 1);
+
+        vault.cancel(user, 1).unwrap();
+
+        assert!(vault.svm.account(&request).is_none());
+        assert_eq!(
+            vault.svm.lamports(&vault.authority),
+            authority_lamports + rent - SIGNATURE_FEE
+        );
+        assert_eq!(vault.state().total_reserved, 0);
+        assert_eq!(vault.vault_balance(), 1_000_000_000);
+        let ledger = vault.user_ledger(&user).unwrap();
+        assert_eq!((ledger.pending_amount, ledger.cancel_count), (0, 1));
+        // Both paths are closed for good
+        assert!(vault.claim(user, 1).is_err());
+    }
+}
+
+#[test]
+fn reserved_funds_are_not_available_for_payout() {
+    for token_program in TOKEN_PROGRAMS {
+        let (mut vault, user, _) = vault_with_request(token_program);
+
+        let err = vault.payout(1_000_000_000 - AMOUNT + 1).unwrap_err();
+        assert!(err.is(TreasuryError::InsufficientBalance), "{err}");
+
+        vault.payout(1_000_000_000 - AMOUNT).unwrap();
+        vault.claim(user, 1).unwrap();
+        assert_eq!(vault.vault_balance(), 0);
+    }
+}
@@ -0,0 +1,127 @@
+//! Turns vault transactions into a normalized event stream.
+//!
+//! Each row is keyed by `(tx_signature, log_index)` so re-indexing the same
+//! range is idempotent, and carries the columns reconciliation needs against
+//! the API's `deposits` / `withdrawals` tables (`tx_signature`, `user`,
+//! `amount`, `request_id`). The full event is kept as JSON in `data`.
+
+pub mod sink;
+pub mod source;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use treasury_vault_client::rpc::TransactionLogs;
+use treasury_vault_events::parse_logs;
+
+use crate::sink::Sink;
+
+/// In JSON, `amount` and `request_id` are decimal strings like the `u64`
+/// fields of `data`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedEvent {
+    pub tx_signature: String,
+    /// Position of the event within its transaction
+    pub log_index: u32,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub name: String,
+    pub vault: String,
+    pub user: Option<String>,
+    /// Base units
+    pub amount: Option<u64>,
+    pub request_id: Option<u64>,
+    pub data: Value,
+}
+
+impl IndexedEvent {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "tx_signature": self.tx_signature,
+            "log_index": self.log_index,
+            "slot": self.slot,
+            "block_time": self.block_time,
+            "name": self.name,
+            "vault": self.vault,
+            "user": self.user,
+            "amount": self.amount.map(|amount| amount.to_string()),
+            "request_id": self.request_id.map(|id| id.to_string()),
+            "data": self.data,
+        })
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self> {
+        let str_field = |key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+        Ok(Self {
+            tx_signature: str_field("tx_signature").context("tx_signature")?,
+            log_index: value
+                .get("log_index")
+                .and_then(Value::as_u64)
+                .and_then(|index| u32::try_from(index).ok())
+                .context("log_index")?,
+            slot: value.get("slot").and_then(Value::as_u64).context("slot")?,
+            block_time: value.get("block_time").and_then(Value::as_i64),
+            name: str_field("name").context("name")?,
+            vault: str_field("vault").context("vault")?,
+            user: str_field("user"),
+            amount: u64_field(value, "amount"),
+            request_id: u64_field(value, "request_id"),
+            data: value.get("data").cloned().unwrap_or(Value::Null),
+        })
+    }
+}
+
+/// `u64` written as a decimal string, which JSON numbers can't hold exactly
+fn u64_field(value: &Value, key: &str) -> Option<u64> {
+    value.get(key)?.as_str()?.parse().ok()
+}
+
+/// Decodes the vault events of one transaction. Failed transactions are
+/// rolled back on-chain, so they yield nothing.
+pub fn index_transaction(program_id: &Pubkey, tx: &TransactionLogs) -> Result<Vec<IndexedEvent>> {
+    if tx.failed {
+        return Ok(Vec::new());
+    }
+    let events = parse_logs(program_id, &tx.logs)
+        .with_context(|| format!("decoding logs of {}", tx.signature))?;
+
+    events
+        .iter()
+        .enumerate()
+        .map(|(index, event)| {
+            let data = event.to_json();
+            let str_field = |key: &str| data.get(key).and_then(Value::as_str).map(str::to_string);
+            // Withdrawal events carry `user`; user deposits are attributed to the depositor
+            let user = str_field("user").or_else(|| str_field("depositor"));
+            Ok(IndexedEvent {
+                tx_signature: tx.signature.clone(),
+                log_index: u32::try_from(index)?,
+                slot: tx.slot,
+                block_time: tx.block_time,
+                name: event.name().to_string(),
+                vault: str_field("vault").unwrap_or_default(),
+                user,
+                amount: u64_field(&data, "amount"),
+                request_id: u64_field(&data, "request_id"),
+                data,
+            })
+        })
+        .collect()
+}
+
+/// Writes the events of a page of transactions, oldest first. The cursor is
+/// left alone: it moves with [`Sink::checkpoint`] once every transaction up
+/// to it is written. Returns the number of events written.
+pub fn write_page(
+    sink: &mut dyn Sink,
+    program_id: &Pubkey,
+    transactions: &[TransactionLogs],
+) -> Result<usize> {
+    let mut count = 0;
+    for tx in transactions {
+        let events = index_transaction(program_id, tx)?;
+        sink.write_events(&events)?;
+        count += events.len();
+    }
+    Ok(count)
+}
@@ -0,0 +1,100 @@
+//! `treasury-vault-indexer` — writes vault events from RPC or recorded
+//! fixtures to JSONL or SQLite. Resumes from the sink's last transaction.
+
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{bail, Result};
+use clap::Parser;
+use treasury_vault_client::rpc::RpcClient;
+use treasury_vault_indexer::sink::{JsonlSink, Sink, SqliteSink};
+use treasury_vault_indexer::{source, write_page};
+
+#[derive(Parser)]
+#[command(
+    name = "treasury-vault-indexer",
+    version,
+    about = "Index Treasury Vault events"
+)]
+struct Args {
+    /// RPC URL
+    #[arg(
+        long,
+        short = 'u',
+        env = "TREASURY_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Vault program id
+    #[arg(long, default_value_t = treasury_vault_client::PROGRAM_ID)]
+    program_id: Pubkey,
+
+    /// Address whose history is walked (a vault, or the program id for all vaults)
+    #[arg(long)]
+    address: Option<Pubkey>,
+
+    /// Read recorded transactions from this JSON file instead of RPC
+    #[arg(long)]
+    fixture: Option<PathBuf>,
+
+    /// Append events as JSON lines ("-" for stdout)
+    #[arg(long, conflicts_with = "sqlite")]
+    jsonl: Option<PathBuf>,
+
+    /// Write events into this SQLite database
+    #[arg(long)]
+    sqlite: Option<PathBuf>,
+
+    /// Keep polling for new transactions
+    #[arg(long, conflicts_with = "fixture")]
+    follow: bool,
+
+    #[arg(long, default_value_t = 10)]
+    poll_secs: u64,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let mut sink: Box<dyn Sink> = match (&args.jsonl, &args.sqlite) {
+        (Some(path), None) => Box::new(JsonlSink::open(path)?),
+        (None, Some(path)) => Box::new(SqliteSink::open(path)?),
+        _ => bail!("pass exactly one of --jsonl or --sqlite"),
+    };
+
+    if let Some(fixture) = &args.fixture {
+        let transactions = source::load_fixture(fixture)?;
+        let count = write_page(sink.as_mut(), &args.program_id, &transactions)?;
+        if let Some(last) = transactions.last() {
+            sink.checkpoint(&last.signature, last.slot)?;
+        }
+        eprintln!("indexed {count} event(s) from {}", fixture.display());
+        return Ok(());
+    }
+
+    let rpc = RpcClient::new(&args.url);
+    let address = args.address.unwrap_or(args.program_id);
+    loop {
+        let until = sink.last_signature()?;
+        let mut count = 0;
+        let fetched = source::fetch_rpc(&rpc, &address, until.as_deref(), |page| {
+            count += write_page(sink.as_mut(), &args.program_id, page)?;
+            Ok(())
+        })?;
+        if let Some(newest) = &fetched.newest {
+            sink.checkpoint(&newest.signature, newest.slot)?;
+        }
+        if count > 0 || !args.follow {
+            eprintln!(
+                "indexed {count} event(s) from {} transaction(s)",
+                fetched.count
+            );
+        }
+        if !args.follow {
+            return Ok(());
+        }
+        sleep(Duration::from_secs(args.poll_secs));
+    }
+}
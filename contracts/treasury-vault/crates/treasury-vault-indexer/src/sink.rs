@@ -0,0 +1,202 @@
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::Value;
+
+use crate::IndexedEvent;
+
+/// Destination of the event stream. `last_signature` is the resume cursor:
+/// the newest transaction whose events, and every older one's, are written.
+pub trait Sink {
+    fn write_events(&mut self, events: &[IndexedEvent]) -> Result<()>;
+    fn last_signature(&self) -> Result<Option<String>>;
+    /// Makes everything written so far durable and moves the cursor to
+    /// `signature`. Called once every transaction up to it is written.
+    fn checkpoint(&mut self, signature: &str, slot: u64) -> Result<()>;
+}
+
+/// One JSON object per line; `-` writes to stdout (no resume cursor).
+///
+/// The cursor lives next to the file in `<path>.cursor` and is replaced on
+/// every checkpoint, after the events are flushed. A crash before that
+/// re-appends the same range on restart, so readers dedupe on
+/// `(tx_signature, log_index)` as the SQLite sink does.
+pub struct JsonlSink {
+    path: Option<PathBuf>,
+    out: Box<dyn Write>,
+}
+
+impl JsonlSink {
+    pub fn open(path: &Path) -> Result<Self> {
+        if path == Path::new("-") {
+            return Ok(Self {
+                path: None,
+                out: Box::new(io::stdout()),
+            });
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("opening {}", path.display()))?;
+        // A new file gets an empty cursor right away: pages may arrive newest
+        // first, so its last line is no cursor
+        let cursor = Self::cursor_path(path);
+        if file.metadata()?.len() == 0 && !cursor.exists() {
+            fs::write(&cursor, "").with_context(|| format!("writing {}", cursor.display()))?;
+        }
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            out: Box::new(file),
+        })
+    }
+
+    /// `<path>.cursor`
+    pub fn cursor_path(path: &Path) -> PathBuf {
+        let mut cursor = OsString::from(path.as_os_str());
+        cursor.push(".cursor");
+        PathBuf::from(cursor)
+    }
+}
+
+impl Sink for JsonlSink {
+    fn write_events(&mut self, events: &[IndexedEvent]) -> Result<()> {
+        for event in events {
+            writeln!(self.out, "{}", event.to_json())?;
+        }
+        Ok(())
+    }
+
+    fn last_signature(&self) -> Result<Option<String>> {
+        let Some(path) = &self.path else {
+            return Ok(None);
+        };
+        match fs::read_to_string(Self::cursor_path(path)) {
+            Ok(cursor) => {
+                let cursor = cursor.trim();
+                return Ok((!cursor.is_empty()).then(|| cursor.to_string()));
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        // Files written before the cursor file existed, which were always
+        // appended oldest first: newest event line
+        let mut last = None;
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                last = Some(line);
+            }
+        }
+        last.map(|line| {
+            let value: Value = serde_json::from_str(&line)?;
+            Ok(IndexedEvent::from_json(&value)?.tx_signature)
+        })
+        .transpose()
+    }
+
+    fn checkpoint(&mut self, signature: &str, _slot: u64) -> Result<()> {
+        self.out.flush()?;
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        // Rename so a crash never leaves a half-written cursor
+        let cursor_path = Self::cursor_path(path);
+        let mut tmp = cursor_path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, signature)?;
+        fs::rename(&tmp, &cursor_path)
+            .with_context(|| format!("writing {}", cursor_path.display()))?;
+        Ok(())
+    }
+}
+
+pub struct SqliteSink {
+    conn: Connection,
+}
+
+impl SqliteSink {
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    pub fn from_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS vault_events (
+                tx_signature TEXT NOT NULL,
+                log_index INTEGER NOT NULL,
+                slot INTEGER NOT NULL,
+                block_time INTEGER,
+                name TEXT NOT NULL,
+                vault TEXT NOT NULL,
+                user TEXT,
+                amount INTEGER,
+                request_id INTEGER,
+                data TEXT NOT NULL,
+                PRIMARY KEY (tx_signature, log_index)
+            );
+            CREATE INDEX IF NOT EXISTS vault_events_user ON vault_events (user, name);
+            CREATE TABLE IF NOT EXISTS indexer_cursor (
+                id INTEGER PRIMARY KEY CHECK (id = 0),
+                tx_signature TEXT NOT NULL,
+                slot INTEGER NOT NULL
+            );",
+        )?;
+        Ok(Self { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+}
+
+impl Sink for SqliteSink {
+    fn write_events(&mut self, events: &[IndexedEvent]) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        for event in events {
+            tx.execute(
+                "INSERT OR REPLACE INTO vault_events
+                    (tx_signature, log_index, slot, block_time, name, vault, user, amount, request_id, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    event.tx_signature,
+                    event.log_index,
+                    i64::try_from(event.slot)?,
+                    event.block_time,
+                    event.name,
+                    event.vault,
+                    event.user,
+                    event.amount.map(i64::try_from).transpose()?,
+                    event.request_id.map(i64::try_from).transpose()?,
+                    event.data.to_string(),
+                ],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn last_signature(&self) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT tx_signature FROM indexer_cursor WHERE id = 0",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    fn checkpoint(&mut self, signature: &str, slot: u64) -> Result<()> {
+        // Rows are committed as they are written, and re-writing them is a no-op
+        self.conn.execute(
+            "INSERT OR REPLACE INTO indexer_cursor (id, tx_signature, slot) VALUES (0, ?1, ?2)",
+            params![signature, i64::try_from(slot)?],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::path::Path;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use treasury_vault_client::rpc::{RpcClient, SignatureInfo, TransactionLogs};
+
+/// Most signatures `getSignaturesForAddress` returns at once, and the number
+/// of transactions handed to the sink per page
+pub const PAGE_SIZE: usize = 1000;
+
+/// The two RPC calls [`fetch_rpc`] makes, so paging can run against a fake
+pub trait History {
+    /// Same contract as [`RpcClient::get_signatures_for_address`]: newest
+    /// first, `before` / `until` exclusive
+    fn signatures(
+        &self,
+        address: &Pubkey,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SignatureInfo>>;
+    fn transaction(&self, signature: &str) -> Result<Option<TransactionLogs>>;
+}
+
+impl History for RpcClient {
+    fn signatures(
+        &self,
+        address: &Pubkey,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SignatureInfo>> {
+        Ok(self.get_signatures_for_address(address, before, until, limit)?)
+    }
+
+    fn transaction(&self, signature: &str) -> Result<Option<TransactionLogs>> {
+        Ok(self.get_transaction(signature)?)
+    }
+}
+
+/// What [`fetch_rpc`] walked
+#[derive(Debug, Default)]
+pub struct Fetched {
+    /// Transactions handed to `on_page`
+    pub count: usize,
+    /// Newest transaction seen: the sink's next cursor, once every page
+    /// is written
+    pub newest: Option<SignatureInfo>,
+}
+
+/// Transactions touching `address` after `until` (exclusive), handed to
+/// `on_page` [`PAGE_SIZE`] at a time. With no `until` the whole history is
+/// walked.
+///
+/// RPC only lists signatures newest first, so the walk goes backwards from
+/// the tip and stops at `until`. Each page is fetched and handed over as it
+/// arrives, oldest first within the page, so memory stays at one page however
+/// far behind `until` is. Pages therefore arrive newest first: the caller
+/// moves its cursor to [`Fetched::newest`] only after the walk returns, and a
+/// failure midway re-walks the same range next time.
+pub fn fetch_rpc(
+    history: &impl History,
+    address: &Pubkey,
+    until: Option<&str>,
+    mut on_page: impl FnMut(&[TransactionLogs]) -> Result<()>,
+) -> Result<Fetched> {
+    let mut fetched = Fetched::default();
+    let mut before: Option<String> = None;
+    loop {
+        let signatures = history.signatures(address, before.as_deref(), until, PAGE_SIZE)?;
+        let Some(oldest) = signatures.last() else {
+            break;
+        };
+        before = Some(oldest.signature.clone());
+        let done = signatures.len() < PAGE_SIZE;
+
+        let page = signatures
+            .iter()
+            .rev()
+            .map(|info| {
+                history
+                    .transaction(&info.signature)?
+                    .with_context(|| format!("transaction {} not found", info.signature))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        on_page(&page)?;
+        fetched.count += page.len();
+        if fetched.newest.is_none() {
+            fetched.newest = signatures.into_iter().next();
+        }
+        if done {
+            break;
+        }
+    }
+    Ok(fetched)
+}
+
+/// Recorded transactions: a JSON array of
+/// `{ "signature", "slot", "blockTime", "err", "logs" }`, oldest first.
+pub fn load_fixture(path: &Path) -> Result<Vec<TransactionLogs>> {
+    let raw = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let value: Value = serde_json::from_str(&raw)?;
+    let Some(entries) = value.as_array() else {
+        bail!("{}: expected a JSON array", path.display());
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            Ok(TransactionLogs {
+                signature: entry
+                    .get("signature")
+                    .and_then(Value::as_str)
+                    .context("fixture entry without signature")?
+                    .to_string(),
+                slot: entry
+                    .get("slot")
+                    .and_then(Value::as_u64)
+                    .unwrap_or_default(),
+                block_time: entry.get("blockTime").and_then(Value::as_i64),
+                failed: entry.get("err").is_some_and(|err| !err.is_null()),
+                logs: entry
+                    .get("logs")
+                    .and_then(Value::as_array)
+                    .context("fixture entry without logs")?
+                    .iter()
+                    .filter_map(|log| log.as_str().map(str::to_string))
+                    .collect(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,235 @@
+use std::path::Path;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use rusqlite::Connection;
+use treasury_vault_client::rpc::{SignatureInfo, TransactionLogs};
+use treasury_vault_client::PROGRAM_ID;
+use treasury_vault_indexer::sink::{JsonlSink, Sink, SqliteSink};
+use treasury_vault_indexer::source::{History, PAGE_SIZE};
+use treasury_vault_indexer::{index_transaction, source, write_page, IndexedEvent};
+
+const USER: &str = "LQVcTQajEfHFgC7dJeWJ6R3uBsqZrSdp9rTzv344p4A";
+
+fn index_fixture(sink: &mut dyn Sink) -> Vec<IndexedEvent> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/transactions.json");
+    let transactions = source::load_fixture(&path).unwrap();
+    let mut all = Vec::new();
+    for tx in &transactions {
+        let events = index_transaction(&PROGRAM_ID, tx).unwrap();
+        sink.write_events(&events).unwrap();
+        all.extend(events);
+    }
+    let last = transactions.last().unwrap();
+    sink.checkpoint(&last.signature, last.slot).unwrap();
+    all
+}
+
+#[test]
+fn indexes_fixture_into_sqlite_idempotently() {
+    let mut sink = SqliteSink::from_connection(Connection::open_in_memory().unwrap()).unwrap();
+    let events = index_fixture(&mut sink);
+
+    // The failed payout transaction is skipped
+    let names: Vec<_> = events.iter().map(|event| event.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "VaultInitialized",
+            "DepositEvent",
+            "WithdrawalCreatedEvent",
+            "WithdrawalClaimedEvent"
+        ]
+    );
+    let claimed = &events[3];
+    assert_eq!(claimed.user.as_deref(), Some(USER));
+    assert_eq!(claimed.amount, Some(2_000_000));
+    assert_eq!(claimed.request_id, Some(1));
+
+    // Re-indexing the same range doesn't duplicate rows
+    index_fixture(&mut sink);
+    let count: i64 = sink
+        .connection()
+        .query_row("SELECT COUNT(*) FROM vault_events", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(count, 4);
+
+    let claimed_total: i64 = sink
+        .connection()
+        .query_row(
+            "SELECT SUM(amount) FROM vault_events WHERE user = ?1 AND name = 'WithdrawalClaimedEvent'",
+            [USER],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(claimed_total, 2_000_000);
+    assert_eq!(
+        sink.last_signature().unwrap(),
+        Some(claimed.tx_signature.clone())
+    );
+}
+
+#[test]
+fn jsonl_sink_resumes_from_its_cursor() {
+    let path = std::env::temp_dir().join(format!("vault-events-{}.jsonl", std::process::id()));
+    let cursor = JsonlSink::cursor_path(&path);
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(&cursor);
+
+    let mut sink = JsonlSink::open(&path).unwrap();
+    assert_eq!(sink.last_signature().unwrap(), None);
+    let events = index_fixture(&mut sink);
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    let first: serde_json::Value = serde_json::from_str(written.lines().next().unwrap()).unwrap();
+    assert_eq!(IndexedEvent::from_json(&first).unwrap(), events[0]);
+    // u64 amounts and ids are strings, at the top level and in `data`
+    let claimed: serde_json::Value = serde_json::from_str(written.lines().nth(3).unwrap()).unwrap();
+    assert_eq!(claimed["amount"], "2000000");
+    assert_eq!(claimed["data"]["request_id"], "1");
+    assert_eq!(IndexedEvent::from_json(&claimed).unwrap(), events[3]);
+    assert_eq!(
+        sink.last_signature().unwrap(),
+        Some(events[3].tx_signature.clone())
+    );
+
+    // Files from before the cursor file resume from their newest line
+    std::fs::remove_file(&cursor).unwrap();
+    let sink = JsonlSink::open(&path).unwrap();
+    assert_eq!(
+        sink.last_signature().unwrap(),
+        Some(events[3].tx_signature.clone())
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+/// In-memory address history, oldest first
+struct FakeHistory(Vec<TransactionLogs>);
+
+impl History for FakeHistory {
+    fn signatures(
+        &self,
+        _address: &Pubkey,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SignatureInfo>> {
+        Ok(self
+            .0
+            .iter()
+            .rev()
+            .skip_while(|tx| before.is_some_and(|before| tx.signature != before))
+            .skip(usize::from(before.is_some()))
+            .take_while(|tx| until != Some(tx.signature.as_str()))
+            .take(limit)
+            .map(|tx| SignatureInfo {
+                signature: tx.signature.clone(),
+                slot: tx.slot,
+                block_time: tx.block_time,
+                failed: tx.failed,
+            })
+            .collect())
+    }
+
+    fn transaction(&self, signature: &str) -> Result<Option<TransactionLogs>> {
+        Ok(self.0.iter().find(|tx| tx.signature == signature).cloned())
+    }
+}
+
+#[test]
+fn rpc_pages_are_written_as_they_arrive_and_the_cursor_moves_at_the_end() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/transactions.json");
+    let fixture = source::load_fixture(&path).unwrap();
+    // Event-free transactions (e.g. failed or unrelated) fill more than two pages
+    let quiet = |range: std::ops::Range<usize>| {
+        range.map(|index| TransactionLogs {
+            signature: format!("quiet-{index}"),
+            slot: index as u64,
+            block_time: None,
+            failed: false,
+            logs: Vec::new(),
+        })
+    };
+    let mut history = FakeHistory(fixture.clone());
+    history.0.extend(quiet(0..2 * PAGE_SIZE + 1));
+
+    let out = std::env::temp_dir().join(format!("vault-rpc-{}.jsonl", std::process::id()));
+    let cursor = JsonlSink::cursor_path(&out);
+    let _ = std::fs::remove_file(&out);
+    let _ = std::fs::remove_file(&cursor);
+    let mut sink = JsonlSink::open(&out).unwrap();
+
+    // A failure midway leaves the cursor where it was
+    let mut pages = 0;
+    let err = source::fetch_rpc(&history, &PROGRAM_ID, None, |page| {
+        write_page(&mut sink, &PROGRAM_ID, page)?;
+        pages += 1;
+        anyhow::ensure!(pages < 2, "connection reset");
+        Ok(())
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "connection reset");
+    assert_eq!(sink.last_signature().unwrap(), None);
+
+    // Pages come newest first, each oldest first, and the cursor stays put
+    // until the walk is done
+    let mut pages = Vec::new();
+    let fetched = source::fetch_rpc(&history, &PROGRAM_ID, None, |page| {
+        write_page(&mut sink, &PROGRAM_ID, page)?;
+        assert_eq!(sink.last_signature()?, None);
+        assert!(
+            page.is_sorted_by_key(|tx| history.0.iter().position(|h| h.signature == tx.signature))
+        );
+        pages.push((page.len(), page.last().unwrap().signature.clone()));
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(fetched.count, history.0.len());
+    assert_eq!(
+        pages,
+        [
+            (PAGE_SIZE, "quiet-2000".to_string()),
+            (PAGE_SIZE, "quiet-1000".to_string()),
+            (fixture.len() + 1, "quiet-0".to_string()),
+        ]
+    );
+    let newest = fetched.newest.unwrap();
+    assert_eq!(newest.signature, "quiet-2000");
+    sink.checkpoint(&newest.signature, newest.slot).unwrap();
+
+    // Events land in chain order
+    let written = std::fs::read_to_string(&out).unwrap();
+    let signatures: Vec<_> = written
+        .lines()
+        .map(|line| IndexedEvent::from_json(&serde_json::from_str(line).unwrap()).unwrap())
+        .map(|event| event.tx_signature)
+        .collect();
+    assert_eq!(signatures.len(), 4);
+    assert!(signatures
+        .is_sorted_by_key(|signature| fixture.iter().position(|tx| tx.signature == *signature)));
+
+    // Resuming walks back only to the cursor
+    let until = JsonlSink::open(&out).unwrap().last_signature().unwrap();
+    assert_eq!(until.as_deref(), Some("quiet-2000"));
+    let fetched = source::fetch_rpc(&history, &PROGRAM_ID, until.as_deref(), |_| {
+        panic!("nothing new")
+    })
+    .unwrap();
+    assert_eq!(fetched.count, 0);
+    assert!(fetched.newest.is_none());
+
+    history
+        .0
+        .extend(quiet(2 * PAGE_SIZE + 1..2 * PAGE_SIZE + 4));
+    let mut seen = Vec::new();
+    let fetched = source::fetch_rpc(&history, &PROGRAM_ID, until.as_deref(), |page| {
+        seen.extend(page.iter().map(|tx| tx.signature.clone()));
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(seen, ["quiet-2001", "quiet-2002", "quiet-2003"]);
+    assert_eq!(fetched.newest.unwrap().signature, "quiet-2003");
+
+    std::fs::remove_file(&out).unwrap();
+    std::fs::remove_file(&cursor).unwrap();
+}
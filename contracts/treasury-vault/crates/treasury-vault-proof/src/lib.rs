@@ -0,0 +1,232 @@
+//! Proof-of-liabilities helpers for the Treasury Vault.
+//!
+//! The backend builds a Merkle-sum tree over every user's in-game balance and
+//! commits the root with `commit_liabilities`. A user (or auditor) holding
+//! their leaf and proof can check it against the on-chain `LiabilitySnapshot`
+//! and compare the committed total with the vault's unreserved token balance.
+//!
+//! Every node carries the sum of the balances below it, and that sum is
+//! hashed into its parent, so the root commits to the total as well as to
+//! the leaves. Hashing is SHA-256 with domain separation:
+//! - leaf = H(0x00 || user || balance_le), sum = balance
+//! - node = H(0x01 || lo.hash || lo.sum_le || hi.hash || hi.sum_le), sum = lo.sum + hi.sum
+//! - merkle_root = H(0x02 || top.hash || top.sum_le || leaf_count_le)
+//!
+//! Children are ordered by hash before hashing, so proofs carry no left/right
+//! flags. An odd node at the end of a level is promoted unchanged. A proof
+//! that verifies shows the user's balance is counted in `total_liabilities`,
+//! and that the root was built over `leaf_count` leaves.
+
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::AccountDeserialize;
+pub use treasury_vault::state::LiabilitySnapshot;
+
+const LEAF_PREFIX: &[u8] = &[0x00];
+const NODE_PREFIX: &[u8] = &[0x01];
+const ROOT_PREFIX: &[u8] = &[0x02];
+
+pub type Hash = [u8; 32];
+
+/// A tree node: its hash and the sum of the balances below it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SumNode {
+    pub hash: Hash,
+    pub sum: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofError {
+    /// Account data is not a LiabilitySnapshot
+    InvalidAccount(String),
+    /// Proof does not lead to the committed Merkle root
+    RootMismatch,
+    /// Leaf index is outside the tree
+    IndexOutOfRange,
+    /// Balances add up past u64::MAX
+    Overflow,
+    /// Proof is valid but its sum differs from the snapshot's `total_liabilities`
+    TotalMismatch,
+    /// Proof length cannot come from a tree of the snapshot's `leaf_count`
+    LeafCountMismatch,
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofError::InvalidAccount(err) => write!(f, "invalid liability snapshot: {err}"),
+            ProofError::RootMismatch => write!(f, "inclusion proof does not match merkle root"),
+            ProofError::IndexOutOfRange => write!(f, "leaf index out of range"),
+            ProofError::Overflow => write!(f, "liability sum overflows u64"),
+            ProofError::TotalMismatch => {
+                write!(f, "proven total does not match total_liabilities")
+            }
+            ProofError::LeafCountMismatch => {
+                write!(f, "proof length does not match leaf_count")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProofError {}
+
+/// Result of comparing committed liabilities with the vault balance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Solvency {
+    pub total_liabilities: u64,
+    pub vault_balance: u64,
+}
+
+impl Solvency {
+    pub fn is_solvent(&self) -> bool {
+        self.vault_balance >= self.total_liabilities
+    }
+
+    /// Balance minus liabilities; negative when under-collateralized
+    pub fn surplus(&self) -> i128 {
+        self.vault_balance as i128 - self.total_liabilities as i128
+    }
+}
+
+pub fn leaf(user: &Pubkey, balance: u64) -> SumNode {
+    SumNode {
+        hash: hashv(&[LEAF_PREFIX, user.as_ref(), &balance.to_le_bytes()]).to_bytes(),
+        sum: balance,
+    }
+}
+
+pub fn node(a: &SumNode, b: &SumNode) -> Result<SumNode, ProofError> {
+    let (lo, hi) = if a.hash <= b.hash { (a, b) } else { (b, a) };
+    Ok(SumNode {
+        hash: hashv(&[
+            NODE_PREFIX,
+            &lo.hash,
+            &lo.sum.to_le_bytes(),
+            &hi.hash,
+            &hi.sum.to_le_bytes(),
+        ])
+        .to_bytes(),
+        sum: lo.sum.checked_add(hi.sum).ok_or(ProofError::Overflow)?,
+    })
+}
+
+/// Value committed on-chain as `merkle_root`, binding the tree's top node to
+/// its total and leaf count
+pub fn commitment(top: &SumNode, leaf_count: u64) -> Hash {
+    hashv(&[
+        ROOT_PREFIX,
+        &top.hash,
+        &top.sum.to_le_bytes(),
+        &leaf_count.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Top node over `leaves`; its `sum` is the total to commit. An empty tree
+/// has an all-zero hash and a zero sum.
+pub fn tree_top(leaves: &[SumNode]) -> Result<SumNode, ProofError> {
+    if leaves.is_empty() {
+        return Ok(SumNode {
+            hash: [0u8; 32],
+            sum: 0,
+        });
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level)?;
+    }
+    Ok(level[0])
+}
+
+/// `merkle_root` to commit for `leaves`
+pub fn merkle_root(leaves: &[SumNode]) -> Result<Hash, ProofError> {
+    Ok(commitment(&tree_top(leaves)?, leaves.len() as u64))
+}
+
+/// Sibling nodes from leaf `index` up to the top
+pub fn merkle_proof(leaves: &[SumNode], index: usize) -> Result<Vec<SumNode>, ProofError> {
+    if index >= leaves.len() {
+        return Err(ProofError::IndexOutOfRange);
+    }
+
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    let mut index = index;
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+        if sibling < level.len() {
+            proof.push(level[sibling]);
+        }
+        level = next_level(&level)?;
+        index /= 2;
+    }
+    Ok(proof)
+}
+
+/// Recomputes the top node from a leaf and its proof
+pub fn top_from_proof(leaf: SumNode, proof: &[SumNode]) -> Result<SumNode, ProofError> {
+    proof
+        .iter()
+        .try_fold(leaf, |acc, sibling| node(&acc, sibling))
+}
+
+/// Decodes a `LiabilitySnapshot` from raw account data (discriminator included)
+pub fn decode_snapshot(data: &[u8]) -> Result<LiabilitySnapshot, ProofError> {
+    let mut data = data;
+    LiabilitySnapshot::try_deserialize(&mut data)
+        .map_err(|err| ProofError::InvalidAccount(err.to_string()))
+}
+
+/// Checks that (user, balance) is included in the committed snapshot and
+/// counted in its `total_liabilities`
+pub fn verify_inclusion(
+    snapshot: &LiabilitySnapshot,
+    user: &Pubkey,
+    balance: u64,
+    proof: &[SumNode],
+) -> Result<(), ProofError> {
+    // One leaf has an empty proof; otherwise a path is at most ceil(log2 n) long
+    let max_depth = snapshot
+        .leaf_count
+        .checked_next_power_of_two()
+        .map_or(64, u64::trailing_zeros) as usize;
+    if snapshot.leaf_count == 0
+        || proof.len() > max_depth
+        || proof.is_empty() != (snapshot.leaf_count == 1)
+    {
+        return Err(ProofError::LeafCountMismatch);
+    }
+
+    let top = top_from_proof(leaf(user, balance), proof)?;
+    if commitment(&top, snapshot.leaf_count) != snapshot.merkle_root {
+        return Err(ProofError::RootMismatch);
+    }
+    if top.sum != snapshot.total_liabilities {
+        return Err(ProofError::TotalMismatch);
+    }
+    Ok(())
+}
+
+/// Compares committed liabilities with a vault balance: the snapshot's own
+/// `vault_balance`, or the current token balances less reservations, summed
+/// over the mints the caller accepts as backing (see `mint_balances`)
+pub fn check_solvency(snapshot: &LiabilitySnapshot, vault_balance: u64) -> Solvency {
+    Solvency {
+        total_liabilities: snapshot.total_liabilities,
+        vault_balance,
+    }
+}
+
+fn next_level(level: &[SumNode]) -> Result<Vec<SumNode>, ProofError> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => node(a, b),
+            [a] => Ok(*a),
+            _ => unreachable!(),
+        })
+        .collect()
+}
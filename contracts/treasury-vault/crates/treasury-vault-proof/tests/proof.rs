@@ -0,0 +1,143 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountSerialize, Discriminator};
+use treasury_vault_proof::{
+    check_solvency, decode_snapshot, leaf, merkle_proof, merkle_root, tree_top, verify_inclusion,
+    LiabilitySnapshot, ProofError, SumNode,
+};
+
+fn balances() -> Vec<(Pubkey, u64)> {
+    (0..5)
+        .map(|i| (Pubkey::new_unique(), 1_000_000 * (i + 1)))
+        .collect()
+}
+
+fn snapshot(root: [u8; 32], total: u64, leaf_count: u64) -> LiabilitySnapshot {
+    LiabilitySnapshot {
+        vault: Pubkey::new_unique(),
+        epoch: 1,
+        merkle_root: root,
+        total_liabilities: total,
+        leaf_count,
+        vault_balance: total,
+        mint_balances: Vec::new(),
+        committed_at: 0,
+        bump: 255,
+    }
+}
+
+fn leaves(balances: &[(Pubkey, u64)]) -> Vec<SumNode> {
+    balances.iter().map(|(u, b)| leaf(u, *b)).collect()
+}
+
+/// Snapshot as the backend commits it for `leaves`
+fn committed(leaves: &[SumNode]) -> LiabilitySnapshot {
+    let total = tree_top(leaves).unwrap().sum;
+    snapshot(merkle_root(leaves).unwrap(), total, leaves.len() as u64)
+}
+
+#[test]
+fn every_leaf_verifies_against_root() {
+    let balances = balances();
+    let leaves = leaves(&balances);
+    let snapshot = committed(&leaves);
+    assert_eq!(snapshot.total_liabilities, 15_000_000);
+
+    for (i, (user, balance)) in balances.iter().enumerate() {
+        let proof = merkle_proof(&leaves, i).unwrap();
+        assert_eq!(verify_inclusion(&snapshot, user, *balance, &proof), Ok(()));
+    }
+}
+
+#[test]
+fn rejects_wrong_balance() {
+    let balances = balances();
+    let leaves = leaves(&balances);
+    let snapshot = committed(&leaves);
+
+    let (user, balance) = balances[2];
+    let proof = merkle_proof(&leaves, 2).unwrap();
+    assert_eq!(
+        verify_inclusion(&snapshot, &user, balance + 1, &proof),
+        Err(ProofError::RootMismatch)
+    );
+}
+
+#[test]
+fn total_is_bound_to_the_root() {
+    let balances = balances();
+    let leaves = leaves(&balances);
+    let (user, balance) = balances[0];
+    let proof = merkle_proof(&leaves, 0).unwrap();
+
+    // Snapshot understating the total the tree adds up to
+    let mut understated = committed(&leaves);
+    understated.total_liabilities -= 1;
+    assert_eq!(
+        verify_inclusion(&understated, &user, balance, &proof),
+        Err(ProofError::TotalMismatch)
+    );
+
+    // Sibling sums cannot be shaved without changing the root
+    let mut shaved = proof.clone();
+    shaved[0].sum -= 1;
+    assert_eq!(
+        verify_inclusion(&committed(&leaves), &user, balance, &shaved),
+        Err(ProofError::RootMismatch)
+    );
+
+    // Sums past u64::MAX are rejected, not wrapped
+    let huge = [leaf(&user, u64::MAX), leaf(&Pubkey::new_unique(), 1)];
+    assert_eq!(tree_top(&huge), Err(ProofError::Overflow));
+}
+
+#[test]
+fn leaf_count_is_checked() {
+    let balances = balances();
+    let leaves = leaves(&balances);
+    let (user, balance) = balances[0];
+    let proof = merkle_proof(&leaves, 0).unwrap();
+
+    // Committed in the root, so a different count fails
+    let mut inflated = committed(&leaves);
+    inflated.leaf_count = 6;
+    assert_eq!(
+        verify_inclusion(&inflated, &user, balance, &proof),
+        Err(ProofError::RootMismatch)
+    );
+
+    // Paths longer than ceil(log2 5) = 3, or empty in a multi-leaf tree
+    let snapshot = committed(&leaves);
+    let mut long = proof.clone();
+    long.push(proof[0]);
+    assert_eq!(
+        verify_inclusion(&snapshot, &user, balance, &long),
+        Err(ProofError::LeafCountMismatch)
+    );
+    assert_eq!(
+        verify_inclusion(&snapshot, &user, balance, &[]),
+        Err(ProofError::LeafCountMismatch)
+    );
+
+    let single = committed(&leaves[..1]);
+    assert_eq!(verify_inclusion(&single, &user, balance, &[]), Ok(()));
+    let empty = committed(&[]);
+    assert_eq!(
+        verify_inclusion(&empty, &user, balance, &[]),
+        Err(ProofError::LeafCountMismatch)
+    );
+}
+
+#[test]
+fn decodes_account_data_and_compares_balance() {
+    let snapshot = snapshot([7u8; 32], 10_000_000, 3);
+    let mut data = Vec::new();
+    snapshot.try_serialize(&mut data).unwrap();
+    assert_eq!(&data[..8], LiabilitySnapshot::DISCRIMINATOR);
+
+    let decoded = decode_snapshot(&data).unwrap();
+    assert_eq!(decoded.merkle_root, [7u8; 32]);
+
+    let solvency = check_solvency(&decoded, 9_000_000);
+    assert!(!solvency.is_solvent());
+    assert_eq!(solvency.surplus(), -1_000_000);
+}
@@ -31,4 +31,111 @@ pub enum TreasuryError {
 
     #[msg("Expiration duration must be positive")]
     InvalidExpiration,
+
+    #[msg("New authority must be a different, non-default key and match the pending proposal")]
+    InvalidNewAuthority,
+
+    #[msg("Multisig mode is on: this action needs an approved proposal")]
+    MultisigRequired,
+
+    #[msg("Signer is not in the vault's multisig signer set")]
+    NotMultisigSigner,
+
+    #[msg("Invalid multisig signer set or threshold")]
+    InvalidMultisigConfig,
+
+    #[msg("Proposal does not match this vault or action")]
+    InvalidProposal,
+
+    #[msg("Proposal was created for a previous multisig signer set")]
+    StaleProposal,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalNotApproved,
+
+    #[msg("Signer has already approved this proposal")]
+    AlreadyApproved,
+
+    #[msg("Payout or withdrawal exceeds the vault's rate limits")]
+    RateLimitExceeded,
+
+    #[msg("Vault is paused by the guardian")]
+    VaultPaused,
+
+    #[msg("Unauthorized: only the vault guardian can perform this action")]
+    NotGuardian,
+
+    #[msg(
+        "Guardian must be a non-default key different from the authority and any pending authority"
+    )]
+    InvalidGuardian,
+
+    #[msg("Liability snapshot epoch must be greater than the last committed epoch")]
+    InvalidEpoch,
+
+    #[msg("Amended amount must be lower than the current request amount")]
+    AmountNotReduced,
+
+    #[msg("Cancel reason code 0 is reserved for expired requests")]
+    InvalidCancelReason,
+
+    #[msg("Relayer fee exceeds the cap, the request amount or the user's max_fee")]
+    RelayerFeeTooHigh,
+
+    #[msg("Relayer token account does not match the withdrawal request")]
+    InvalidRelayerAccount,
+
+    #[msg("Destination does not match the withdrawal request")]
+    InvalidDestination,
+
+    #[msg("Voucher claims are disabled for this vault")]
+    VouchersDisabled,
+
+    #[msg("Voucher is not signed by the vault's voucher signer")]
+    InvalidVoucher,
+
+    #[msg("Voucher nonce was already used")]
+    VoucherAlreadyUsed,
+
+    #[msg("Batch must hold between 1 and MAX_WITHDRAWAL_BATCH entries")]
+    InvalidBatchSize,
+
+    #[msg("Remaining accounts do not match the batch entries")]
+    InvalidBatchAccounts,
+
+    #[msg("Expired request is still within the crank grace period")]
+    CrankTooEarly,
+
+    #[msg("Crank grace period must be non-negative and bounty at most MAX_CRANK_BOUNTY")]
+    InvalidCrankConfig,
+
+    #[msg("Mint is disabled for deposits and new withdrawals")]
+    MintDisabled,
+
+    #[msg("Mint registry is full")]
+    MintRegistryFull,
+
+    #[msg("Mint is already registered with this vault")]
+    MintAlreadyRegistered,
+
+    #[msg("Transfer allowlist is too long or holds a duplicate, default or the vault itself")]
+    InvalidTransferAllowlist,
+
+    #[msg("Destination vault is not on the transfer allowlist")]
+    TransferNotAllowed,
+
+    #[msg("No transfer allowlist change is pending")]
+    NoPendingTransferAllowlist,
+
+    #[msg("Transfer allowlist timelock has not elapsed")]
+    TransferAllowlistTimelocked,
+
+    #[msg("Rent must be returned to the account that paid it")]
+    InvalidPayer,
+
+    #[msg("Proposal can still be approved or executed")]
+    ProposalStillActive,
 }
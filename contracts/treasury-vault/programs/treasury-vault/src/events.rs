@@ -1,17 +1,36 @@
 use anchor_lang::prelude::*;
 
+use crate::state::ProposalAction;
+
 #[event]
 pub struct VaultInitialized {
     pub vault: Pubkey,
+    pub vault_id: u16,
     pub authority: Pubkey,
     pub payout_wallet: Pubkey,
+    pub guardian: Pubkey,
     pub usdt_mint: Pubkey,
+    pub transfer_allowlist: Vec<Pubkey>,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct DepositEvent {
     pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub total_deposited: u64,
+    pub deposit_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserDepositEvent {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    /// Opaque app-side user id (e.g. UUID bytes) the deposit is credited to
+    pub user_ref: [u8; 32],
     pub amount: u64,
     pub total_deposited: u64,
     pub deposit_count: u32,
@@ -21,6 +40,7 @@ pub struct DepositEvent {
 #[event]
 pub struct PayoutEvent {
     pub vault: Pubkey,
+    pub mint: Pubkey,
     pub payout_wallet: Pubkey,
     pub amount: u64,
     pub total_paid_out: u64,
@@ -31,18 +51,27 @@ pub struct PayoutEvent {
 #[event]
 pub struct WithdrawalCreatedEvent {
     pub vault: Pubkey,
+    pub mint: Pubkey,
     pub user: Pubkey,
+    pub destination: Option<Pubkey>,
+    pub request_id: u64,
     pub amount: u64,
     pub expires_at: i64,
+    pub total_reserved: u64,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct WithdrawalClaimedEvent {
     pub vault: Pubkey,
+    pub mint: Pubkey,
     pub user: Pubkey,
+    /// Owner of the token account that received the funds
+    pub destination: Pubkey,
+    pub request_id: u64,
     pub amount: u64,
     pub total_paid_out: u64,
+    pub total_reserved: u64,
     pub payout_count: u32,
     pub timestamp: i64,
 }
@@ -51,6 +80,241 @@ pub struct WithdrawalClaimedEvent {
 pub struct WithdrawalCancelledEvent {
     pub vault: Pubkey,
     pub user: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub total_reserved: u64,
+    /// `CANCEL_REASON_EXPIRED` for authority cleanups, otherwise the
+    /// app-defined code the user passed to `user_cancel_withdrawal`
+    pub reason: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalExtendedEvent {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub previous_expires_at: i64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalAmendedEvent {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub previous_amount: u64,
     pub amount: u64,
+    pub total_reserved: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalRelayerSetEvent {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub relayer_token_account: Option<Pubkey>,
+    pub relayer_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RelayerFeePaidEvent {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub fee_payer: Pubkey,
+    pub relayer_token_account: Pubkey,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CrankBountyPaidEvent {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub cranker: Pubkey,
+    pub bounty: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherClaimedEvent {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub nonce: u64,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub total_paid_out: u64,
+    pub payout_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityProposedEvent {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub vault: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigConfiguredEvent {
+    pub vault: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub large_withdrawal_threshold: u64,
+    pub multisig_version: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreatedEvent {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalApprovedEvent {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub signer: Pubkey,
+    pub approval_count: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub approval_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CrankConfigUpdatedEvent {
+    pub vault: Pubkey,
+    pub grace_period: i64,
+    pub bounty: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RateLimitsUpdatedEvent {
+    pub vault: Pubkey,
+    pub max_payout_per_tx: u64,
+    pub max_outflow_per_window: u64,
+    pub max_withdrawal_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultPausedEvent {
+    pub vault: Pubkey,
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUnpausedEvent {
+    pub vault: Pubkey,
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianChangedEvent {
+    pub vault: Pubkey,
+    pub previous_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoucherSignerChangedEvent {
+    pub vault: Pubkey,
+    pub previous_voucher_signer: Pubkey,
+    pub new_voucher_signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintAddedEvent {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintStatusChangedEvent {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferAllowlistProposedEvent {
+    pub vault: Pubkey,
+    pub pending_transfer_allowlist: Vec<Pubkey>,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferAllowlistChangedEvent {
+    pub vault: Pubkey,
+    pub previous_transfer_allowlist: Vec<Pubkey>,
+    pub transfer_allowlist: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferAllowlistCancelledEvent {
+    pub vault: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub cancelled_transfer_allowlist: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InternalTransferEvent {
+    pub vault: Pubkey,
+    pub destination_vault: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub total_paid_out: u64,
+    pub destination_total_deposited: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiabilitiesCommittedEvent {
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub total_liabilities: u64,
+    pub leaf_count: u64,
+    pub vault_balance: u64,
     pub timestamp: i64,
 }
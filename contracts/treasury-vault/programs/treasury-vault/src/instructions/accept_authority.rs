@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::AuthorityTransferredEvent;
+use crate::state::TreasuryVault;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Proposed authority signs to prove it controls the new key
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        constraint = vault.pending_authority == Some(new_authority.key())
+            @ TreasuryError::InvalidNewAuthority,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+}
+
+pub fn handle_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let previous_authority = vault.authority;
+
+    // The guardian may have changed since the proposal; the two roles must
+    // never end up on one key
+    require!(
+        ctx.accounts.new_authority.key() != vault.guardian,
+        TreasuryError::InvalidNewAuthority
+    );
+
+    // Vault PDA stays seeded by seed_key, so the address and token account are unchanged
+    vault.authority = ctx.accounts.new_authority.key();
+    vault.pending_authority = None;
+
+    emit!(AuthorityTransferredEvent {
+        vault: vault.key(),
+        previous_authority,
+        new_authority: vault.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
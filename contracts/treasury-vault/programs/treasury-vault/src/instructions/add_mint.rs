@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::errors::TreasuryError;
+use crate::events::MintAddedEvent;
+use crate::multisig::execute_proposal;
+use crate::state::{Proposal, ProposalAction, TreasuryVault, VaultMint, MAX_VAULT_MINTS};
+
+#[derive(Accounts)]
+pub struct AddMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Mint to register. Must share the primary mint's decimals so amounts,
+    /// totals and rate limits keep a single unit.
+    #[account(
+        constraint = mint.decimals == vault.mint_decimals @ TreasuryError::InvalidMint,
+        constraint = vault.mint(&mint.key()).is_none() @ TreasuryError::MintAlreadyRegistered,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault's token account for the mint (ATA owned by vault PDA).
+    /// Anyone can create an ATA, so an existing one is reused.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Approved AddMint proposal, required in multisig mode
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_add_mint(ctx: Context<AddMint>) -> Result<()> {
+    // A registered mint counts as backing in liability snapshots, so the
+    // registry gets the same M-of-N gate as payouts
+    if ctx.accounts.vault.multisig_enabled() {
+        execute_proposal(
+            &ctx.accounts.vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::AddMint {
+                mint: ctx.accounts.mint.key(),
+            },
+        )?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    require!(
+        vault.mints.len() < MAX_VAULT_MINTS,
+        TreasuryError::MintRegistryFull
+    );
+
+    let mint = ctx.accounts.mint.key();
+    let token_account = ctx.accounts.vault_token_account.key();
+    vault.mints.push(VaultMint::new(mint, token_account));
+
+    emit!(MintAddedEvent {
+        vault: vault.key(),
+        mint,
+        token_account,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::WithdrawalAmendedEvent;
+use crate::state::{TreasuryVault, UserLedger, WithdrawalRequest};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct AmendWithdrawal<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// CHECK: Needed for PDA seed derivation. Validated through withdrawal_request.has_one.
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = withdrawal_request.bump,
+        has_one = vault @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+
+    /// User's on-chain ledger
+    #[account(
+        mut,
+        seeds = [b"user_ledger", vault.key().as_ref(), user.key().as_ref()],
+        bump = user_ledger.bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
+}
+
+pub fn handle_amend_withdrawal(
+    ctx: Context<AmendWithdrawal>,
+    _request_id: u64,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, TreasuryError::ZeroAmount);
+
+    // Raising the amount would bypass create_withdrawal's balance, rate-limit
+    // and multisig checks, so that always needs a new request
+    let previous_amount = ctx.accounts.withdrawal_request.amount;
+    require!(amount < previous_amount, TreasuryError::AmountNotReduced);
+    let released = previous_amount - amount;
+    // A sponsored claim must still leave the user something
+    require!(
+        amount > ctx.accounts.withdrawal_request.relayer_fee,
+        TreasuryError::RelayerFeeTooHigh
+    );
+
+    let clock = Clock::get()?;
+
+    // The reduction will never be claimed, so it leaves the rate-limit window too
+    let request = &ctx.accounts.withdrawal_request;
+    let vault = &mut ctx.accounts.vault;
+    vault.release_unclaimed(
+        &request.mint,
+        released,
+        request.created_at,
+        clock.unix_timestamp,
+    )?;
+
+    ctx.accounts.withdrawal_request.amount = amount;
+    ctx.accounts
+        .user_ledger
+        .record_amend(released, clock.unix_timestamp)?;
+
+    emit!(WithdrawalAmendedEvent {
+        vault: vault.key(),
+        user: ctx.accounts.user.key(),
+        request_id: ctx.accounts.withdrawal_request.request_id,
+        previous_amount,
+        amount,
+        total_reserved: vault.total_reserved,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
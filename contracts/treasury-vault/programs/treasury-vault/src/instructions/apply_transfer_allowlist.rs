@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::TransferAllowlistChangedEvent;
+use crate::multisig::execute_proposal;
+use crate::state::{Proposal, ProposalAction, TreasuryVault};
+
+#[derive(Accounts)]
+pub struct ApplyTransferAllowlist<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Approved ApplyTransferAllowlist proposal, required in multisig mode
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+pub fn handle_apply_transfer_allowlist(ctx: Context<ApplyTransferAllowlist>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.vault.transfer_allowlist_effective_at != 0,
+        TreasuryError::NoPendingTransferAllowlist
+    );
+    require!(
+        clock.unix_timestamp >= ctx.accounts.vault.transfer_allowlist_effective_at,
+        TreasuryError::TransferAllowlistTimelocked
+    );
+
+    if ctx.accounts.vault.multisig_enabled() {
+        execute_proposal(
+            &ctx.accounts.vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::ApplyTransferAllowlist {
+                transfer_allowlist: ctx.accounts.vault.pending_transfer_allowlist.clone(),
+            },
+        )?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+
+    let pending = std::mem::take(&mut vault.pending_transfer_allowlist);
+    let previous_transfer_allowlist = std::mem::replace(&mut vault.transfer_allowlist, pending);
+    vault.transfer_allowlist_effective_at = 0;
+
+    emit!(TransferAllowlistChangedEvent {
+        vault: vault.key(),
+        previous_transfer_allowlist,
+        transfer_allowlist: vault.transfer_allowlist.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::ProposalApprovedEvent;
+use crate::state::{Proposal, TreasuryVault};
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    /// Member of the vault's multisig signer set
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            vault.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+        has_one = vault @ TreasuryError::InvalidProposal,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handle_approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let signer_index = vault
+        .multisig_signer_index(&ctx.accounts.signer.key())
+        .ok_or(TreasuryError::NotMultisigSigner)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.executed, TreasuryError::ProposalAlreadyExecuted);
+    require!(
+        proposal.multisig_version == vault.multisig_version,
+        TreasuryError::StaleProposal
+    );
+
+    let bit = 1u16 << signer_index;
+    require!(
+        proposal.approvals & bit == 0,
+        TreasuryError::AlreadyApproved
+    );
+
+    proposal.approvals |= bit;
+    proposal.approval_count = proposal
+        .approval_count
+        .checked_add(1)
+        .ok_or(TreasuryError::Overflow)?;
+
+    emit!(ProposalApprovedEvent {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        signer: ctx.accounts.signer.key(),
+        approval_count: proposal.approval_count,
+        threshold: vault.multisig_threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::WithdrawalCancelledEvent;
+use crate::state::{TreasuryVault, UserLedger, WithdrawalRequest, CANCEL_REASON_EXPIRED};
+
+/// Remaining accounts, per request in any order:
+/// `[withdrawal_request (writable), user_ledger (writable)]`
+#[derive(Accounts)]
+pub struct CancelExpiredBatch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+}
+
+pub fn handle_cancel_expired_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelExpiredBatch<'info>>,
+) -> Result<()> {
+    let accounts = ctx.remaining_accounts;
+    let pairs = accounts.chunks_exact(2);
+    require!(
+        !accounts.is_empty() && pairs.remainder().is_empty(),
+        TreasuryError::InvalidBatchAccounts
+    );
+
+    let clock = Clock::get()?;
+    let vault_key = ctx.accounts.vault.key();
+    let mut cancelled = 0;
+    for pair in pairs {
+        let (request_info, ledger_info) = (&pair[0], &pair[1]);
+
+        // Claimed or cancelled since the caller listed it (or listed twice)
+        if request_info.owner != &crate::ID {
+            continue;
+        }
+        let request = Account::<WithdrawalRequest>::try_from(request_info)?;
+        require_keys_eq!(request.vault, vault_key, TreasuryError::InvalidVaultAccount);
+        // Still claimable: leave it for the user rather than failing the batch
+        if clock.unix_timestamp <= request.expires_at {
+            continue;
+        }
+
+        let (ledger_address, _) = Pubkey::find_program_address(
+            &[b"user_ledger", vault_key.as_ref(), request.user.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            ledger_info.key(),
+            ledger_address,
+            TreasuryError::InvalidBatchAccounts
+        );
+        let mut ledger = Account::<UserLedger>::try_from(ledger_info)?;
+        ledger.record_cancel(request.amount, clock.unix_timestamp)?;
+        ledger.exit(&crate::ID)?;
+
+        // Release the reservation made at creation and refund the rate-limit window
+        let vault = &mut ctx.accounts.vault;
+        vault.release_unclaimed(
+            &request.mint,
+            request.amount,
+            request.created_at,
+            clock.unix_timestamp,
+        )?;
+
+        emit!(WithdrawalCancelledEvent {
+            vault: vault_key,
+            user: request.user,
+            request_id: request.request_id,
+            amount: request.amount,
+            total_reserved: vault.total_reserved,
+            reason: CANCEL_REASON_EXPIRED,
+            timestamp: clock.unix_timestamp,
+        });
+
+        request.close(ctx.accounts.authority.to_account_info())?;
+        cancelled += 1;
+    }
+
+    msg!("cancelled {} of {} requests", cancelled, accounts.len() / 2);
+    Ok(())
+}
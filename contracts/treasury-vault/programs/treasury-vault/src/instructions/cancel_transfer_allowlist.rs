@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::TransferAllowlistCancelledEvent;
+use crate::state::TreasuryVault;
+
+#[derive(Accounts)]
+pub struct CancelTransferAllowlist<'info> {
+    /// Guardian or authority
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        constraint = signer.key() == vault.guardian || signer.key() == vault.authority
+            @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+}
+
+pub fn handle_cancel_transfer_allowlist(ctx: Context<CancelTransferAllowlist>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(
+        vault.transfer_allowlist_effective_at != 0,
+        TreasuryError::NoPendingTransferAllowlist
+    );
+
+    // Only ever narrows what the vault can do, so no proposal is needed
+    let cancelled_transfer_allowlist = std::mem::take(&mut vault.pending_transfer_allowlist);
+    vault.transfer_allowlist_effective_at = 0;
+
+    emit!(TransferAllowlistCancelledEvent {
+        vault: vault.key(),
+        cancelled_by: ctx.accounts.signer.key(),
+        cancelled_transfer_allowlist,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
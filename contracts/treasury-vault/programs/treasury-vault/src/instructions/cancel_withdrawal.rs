@@ -2,15 +2,17 @@ use anchor_lang::prelude::*;
 
 use crate::errors::TreasuryError;
 use crate::events::WithdrawalCancelledEvent;
-use crate::state::{TreasuryVault, WithdrawalRequest};
+use crate::state::{TreasuryVault, UserLedger, WithdrawalRequest, CANCEL_REASON_EXPIRED};
 
 #[derive(Accounts)]
+#[instruction(request_id: u64)]
 pub struct CancelWithdrawal<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
-        seeds = [b"treasury_vault", authority.key().as_ref()],
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
         has_one = authority @ TreasuryError::Unauthorized,
     )]
@@ -21,15 +23,28 @@ pub struct CancelWithdrawal<'info> {
 
     #[account(
         mut,
-        seeds = [b"withdrawal", vault.key().as_ref(), user.key().as_ref()],
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
         bump = withdrawal_request.bump,
         has_one = vault @ TreasuryError::InvalidVaultAccount,
         close = authority,
     )]
     pub withdrawal_request: Account<'info, WithdrawalRequest>,
+
+    /// User's on-chain ledger
+    #[account(
+        mut,
+        seeds = [b"user_ledger", vault.key().as_ref(), user.key().as_ref()],
+        bump = user_ledger.bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
 }
 
-pub fn handle_cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+pub fn handle_cancel_withdrawal(ctx: Context<CancelWithdrawal>, _request_id: u64) -> Result<()> {
     let clock = Clock::get()?;
 
     // Can only cancel expired withdrawal requests
@@ -38,10 +53,28 @@ pub fn handle_cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
         TreasuryError::WithdrawalNotExpired
     );
 
+    // Release the reservation made at creation and refund the rate-limit window
+    let request = &ctx.accounts.withdrawal_request;
+    let amount = request.amount;
+    let vault = &mut ctx.accounts.vault;
+    vault.release_unclaimed(
+        &request.mint,
+        amount,
+        request.created_at,
+        clock.unix_timestamp,
+    )?;
+
+    ctx.accounts
+        .user_ledger
+        .record_cancel(amount, clock.unix_timestamp)?;
+
     emit!(WithdrawalCancelledEvent {
-        vault: ctx.accounts.vault.key(),
+        vault: vault.key(),
         user: ctx.accounts.user.key(),
-        amount: ctx.accounts.withdrawal_request.amount,
+        request_id: ctx.accounts.withdrawal_request.request_id,
+        amount,
+        total_reserved: vault.total_reserved,
+        reason: CANCEL_REASON_EXPIRED,
         timestamp: clock.unix_timestamp,
     });
 
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::TreasuryError;
+use crate::events::VoucherClaimedEvent;
+use crate::state::{TreasuryVault, UserLedger, VoucherNonces, VOUCHER_NONCES_PER_PAGE};
+use crate::voucher::{verify_ed25519_signature, Voucher};
+
+#[derive(Accounts)]
+#[instruction(amount: u64, nonce: u64)]
+pub struct ClaimWithVoucher<'info> {
+    /// User signs and pays for the transaction and any new accounts
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        constraint = !vault.paused @ TreasuryError::VaultPaused,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Used-nonce bitmap page holding `nonce`
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + VoucherNonces::INIT_SPACE,
+        seeds = [
+            b"voucher_nonces",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            (nonce / VOUCHER_NONCES_PER_PAGE).to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub voucher_nonces: Account<'info, VoucherNonces>,
+
+    /// User's on-chain ledger, created on first claim
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserLedger::INIT_SPACE,
+        seeds = [b"user_ledger", vault.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
+
+    /// Registered mint the voucher pays in, enabled for new withdrawals
+    #[account(
+        constraint = vault.mint(&mint.key()).is_some() @ TreasuryError::InvalidMint,
+        constraint = vault.mint_enabled(&mint.key()) @ TreasuryError::MintDisabled,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User's token account (destination)
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Instructions sysvar, read to find the Ed25519 verification
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_with_voucher(
+    ctx: Context<ClaimWithVoucher>,
+    amount: u64,
+    nonce: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let vault = &ctx.accounts.vault;
+
+    require!(vault.vouchers_enabled(), TreasuryError::VouchersDisabled);
+    require!(amount > 0, TreasuryError::ZeroAmount);
+    require!(
+        clock.unix_timestamp <= expires_at,
+        TreasuryError::WithdrawalExpired
+    );
+
+    let voucher = Voucher {
+        vault: vault.key(),
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        nonce,
+        expires_at,
+    };
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &vault.voucher_signer,
+        &voucher.message(),
+    )?;
+
+    // A voucher is a create_withdrawal + claim in one, so it gets the same
+    // limits. Large amounts can't skip the multisig proposal this way.
+    require!(
+        !vault.multisig_enabled() || amount <= vault.large_withdrawal_threshold,
+        TreasuryError::MultisigRequired
+    );
+    require!(
+        vault.available_balance(&voucher.mint, ctx.accounts.vault_token_account.amount) >= amount,
+        TreasuryError::InsufficientBalance
+    );
+
+    let nonces = &mut ctx.accounts.voucher_nonces;
+    nonces.init_if_new(
+        voucher.vault,
+        voucher.user,
+        nonce / VOUCHER_NONCES_PER_PAGE,
+        ctx.bumps.voucher_nonces,
+    );
+    nonces.consume(nonce)?;
+
+    let vault = &mut ctx.accounts.vault;
+    if vault.max_withdrawal_amount > 0 {
+        require!(
+            amount <= vault.max_withdrawal_amount,
+            TreasuryError::RateLimitExceeded
+        );
+    }
+    vault.record_outflow(amount, clock.unix_timestamp)?;
+
+    let seed_key = vault.seed_key;
+    let vault_id = vault.vault_id.to_le_bytes();
+    let seeds: &[&[u8]] = &[
+        b"treasury_vault",
+        seed_key.as_ref(),
+        vault_id.as_ref(),
+        &[vault.bump],
+    ];
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.record_paid_out(&voucher.mint, amount, clock.unix_timestamp)?;
+
+    let ledger = &mut ctx.accounts.user_ledger;
+    ledger.init_if_new(voucher.vault, voucher.user, ctx.bumps.user_ledger);
+    ledger.record_claimed(amount, clock.unix_timestamp)?;
+
+    emit!(VoucherClaimedEvent {
+        vault: voucher.vault,
+        mint: voucher.mint,
+        user: voucher.user,
+        nonce,
+        amount,
+        expires_at,
+        total_paid_out: vault.total_paid_out,
+        payout_count: vault.payout_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
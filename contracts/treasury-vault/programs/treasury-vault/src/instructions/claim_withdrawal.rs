@@ -6,31 +6,36 @@ use anchor_spl::{
 
 use crate::errors::TreasuryError;
 use crate::events::WithdrawalClaimedEvent;
-use crate::state::{TreasuryVault, WithdrawalRequest};
+use crate::state::{TreasuryVault, UserLedger, WithdrawalRequest};
 
 #[derive(Accounts)]
+#[instruction(request_id: u64)]
 pub struct ClaimWithdrawal<'info> {
     /// User signs the transaction with their wallet
     #[account(mut)]
     pub user: Signer<'info>,
 
-    /// CHECK: Authority pubkey needed for vault PDA seeds derivation.
+    /// CHECK: Current vault authority, receives the closed request's rent.
     /// Validated through vault.has_one = authority.
     #[account(mut)]
     pub authority: UncheckedAccount<'info>,
 
     #[account(
         mut,
-        seeds = [b"treasury_vault", authority.key().as_ref()],
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
         has_one = authority @ TreasuryError::Unauthorized,
-        has_one = usdt_mint @ TreasuryError::InvalidMint,
     )]
     pub vault: Account<'info, TreasuryVault>,
 
     #[account(
         mut,
-        seeds = [b"withdrawal", vault.key().as_ref(), user.key().as_ref()],
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
         bump = withdrawal_request.bump,
         has_one = user @ TreasuryError::Unauthorized,
         has_one = vault @ TreasuryError::InvalidVaultAccount,
@@ -38,31 +43,50 @@ pub struct ClaimWithdrawal<'info> {
     )]
     pub withdrawal_request: Account<'info, WithdrawalRequest>,
 
-    pub usdt_mint: InterfaceAccount<'info, Mint>,
+    /// User's on-chain ledger
+    #[account(
+        mut,
+        seeds = [b"user_ledger", vault.key().as_ref(), user.key().as_ref()],
+        bump = user_ledger.bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
+
+    /// Mint the request is paid in
+    #[account(address = withdrawal_request.mint @ TreasuryError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// Vault's USDT token account (source)
+    /// Vault's token account (source)
     #[account(
         mut,
-        address = vault.vault_token_account @ TreasuryError::InvalidVaultAccount,
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// User's USDT token account (destination)
+    /// CHECK: Owner of the receiving token account, the request's
+    /// `destination` or the user. Validated against withdrawal_request.
+    #[account(
+        address = withdrawal_request.destination.unwrap_or(user.key())
+            @ TreasuryError::InvalidDestination,
+    )]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's token account
     #[account(
         init_if_needed,
         payer = user,
-        associated_token::mint = usdt_mint,
-        associated_token::authority = user,
+        associated_token::mint = mint,
+        associated_token::authority = destination,
         associated_token::token_program = token_program,
     )]
-    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_claim_withdrawal(ctx: Context<ClaimWithdrawal>) -> Result<()> {
+pub fn handle_claim_withdrawal(ctx: Context<ClaimWithdrawal>, _request_id: u64) -> Result<()> {
     let clock = Clock::get()?;
     let request = &ctx.accounts.withdrawal_request;
 
@@ -78,11 +102,13 @@ pub fn handle_claim_withdrawal(ctx: Context<ClaimWithdrawal>) -> Result<()> {
         TreasuryError::InsufficientBalance
     );
 
-    // Transfer USDT from vault to user using PDA signer seeds
-    let authority_key = ctx.accounts.authority.key();
+    // Transfer from vault to user using PDA signer seeds
+    let seed_key = ctx.accounts.vault.seed_key;
+    let vault_id = ctx.accounts.vault.vault_id.to_le_bytes();
     let seeds: &[&[u8]] = &[
         b"treasury_vault",
-        authority_key.as_ref(),
+        seed_key.as_ref(),
+        vault_id.as_ref(),
         &[ctx.accounts.vault.bump],
     ];
 
@@ -91,33 +117,33 @@ pub fn handle_claim_withdrawal(ctx: Context<ClaimWithdrawal>) -> Result<()> {
             ctx.accounts.token_program.to_account_info(),
             TransferChecked {
                 from: ctx.accounts.vault_token_account.to_account_info(),
-                to: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.destination_token_account.to_account_info(),
                 authority: ctx.accounts.vault.to_account_info(),
-                mint: ctx.accounts.usdt_mint.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
             },
             &[seeds],
         ),
         request.amount,
-        ctx.accounts.usdt_mint.decimals,
+        ctx.accounts.mint.decimals,
     )?;
 
     // Update vault stats
     let vault = &mut ctx.accounts.vault;
-    vault.total_paid_out = vault
-        .total_paid_out
-        .checked_add(request.amount)
-        .ok_or(TreasuryError::Overflow)?;
-    vault.payout_count = vault
-        .payout_count
-        .checked_add(1)
-        .ok_or(TreasuryError::Overflow)?;
-    vault.last_payout_at = clock.unix_timestamp;
+    vault.record_claim(&request.mint, request.amount, clock.unix_timestamp)?;
+
+    ctx.accounts
+        .user_ledger
+        .record_claim(request.amount, clock.unix_timestamp)?;
 
     emit!(WithdrawalClaimedEvent {
         vault: vault.key(),
+        mint: request.mint,
         user: ctx.accounts.user.key(),
+        destination: ctx.accounts.destination.key(),
+        request_id: request.request_id,
         amount: request.amount,
         total_paid_out: vault.total_paid_out,
+        total_reserved: vault.total_reserved,
         payout_count: vault.payout_count,
         timestamp: clock.unix_timestamp,
     });
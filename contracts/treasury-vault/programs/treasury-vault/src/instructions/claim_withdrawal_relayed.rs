@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::TreasuryError;
+use crate::events::{RelayerFeePaidEvent, WithdrawalClaimedEvent};
+use crate::state::{TreasuryVault, UserLedger, WithdrawalRequest};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct ClaimWithdrawalRelayed<'info> {
+    /// Relayer that pays the transaction fee and the user's ATA rent
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    /// User still signs to authorize the claim, but needs no SOL
+    pub user: Signer<'info>,
+
+    /// CHECK: Current vault authority, receives the closed request's rent.
+    /// Validated through vault.has_one = authority.
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = withdrawal_request.bump,
+        has_one = user @ TreasuryError::Unauthorized,
+        has_one = vault @ TreasuryError::InvalidVaultAccount,
+        close = authority,
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+
+    /// User's on-chain ledger
+    #[account(
+        mut,
+        seeds = [b"user_ledger", vault.key().as_ref(), user.key().as_ref()],
+        bump = user_ledger.bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
+
+    /// Mint the request is paid in
+    #[account(address = withdrawal_request.mint @ TreasuryError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault's token account (source)
+    #[account(
+        mut,
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Owner of the receiving token account, the request's
+    /// `destination` or the user. Validated against withdrawal_request.
+    #[account(
+        address = withdrawal_request.destination.unwrap_or(user.key())
+            @ TreasuryError::InvalidDestination,
+    )]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Destination's token account, created at the relayer's expense
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        associated_token::mint = mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_program,
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fee destination, must match `withdrawal_request.relayer_token_account`.
+    /// Required only when the request carries a relayer fee.
+    #[account(mut)]
+    pub relayer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_withdrawal_relayed(
+    ctx: Context<ClaimWithdrawalRelayed>,
+    _request_id: u64,
+    max_fee: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let request = &ctx.accounts.withdrawal_request;
+
+    require!(
+        clock.unix_timestamp <= request.expires_at,
+        TreasuryError::WithdrawalExpired
+    );
+
+    // Signed by the user, so the fee can't be raised behind their back
+    let fee = request.relayer_fee;
+    require!(fee <= max_fee, TreasuryError::RelayerFeeTooHigh);
+
+    require!(
+        ctx.accounts.vault_token_account.amount >= request.amount,
+        TreasuryError::InsufficientBalance
+    );
+
+    let seed_key = ctx.accounts.vault.seed_key;
+    let vault_id = ctx.accounts.vault.vault_id.to_le_bytes();
+    let seeds: &[&[u8]] = &[
+        b"treasury_vault",
+        seed_key.as_ref(),
+        vault_id.as_ref(),
+        &[ctx.accounts.vault.bump],
+    ];
+    let decimals = ctx.accounts.mint.decimals;
+
+    let user_amount = request
+        .amount
+        .checked_sub(fee)
+        .ok_or(TreasuryError::Overflow)?;
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.destination_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[seeds],
+        ),
+        user_amount,
+        decimals,
+    )?;
+
+    if fee > 0 {
+        let relayer_token_account = ctx
+            .accounts
+            .relayer_token_account
+            .as_ref()
+            .filter(|account| Some(account.key()) == request.relayer_token_account)
+            .ok_or(TreasuryError::InvalidRelayerAccount)?;
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: relayer_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+                &[seeds],
+            ),
+            fee,
+            decimals,
+        )?;
+
+        emit!(RelayerFeePaidEvent {
+            vault: ctx.accounts.vault.key(),
+            user: ctx.accounts.user.key(),
+            request_id: request.request_id,
+            fee_payer: ctx.accounts.fee_payer.key(),
+            relayer_token_account: relayer_token_account.key(),
+            fee,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // The whole request is settled, fee included
+    let vault = &mut ctx.accounts.vault;
+    vault.record_claim(&request.mint, request.amount, clock.unix_timestamp)?;
+
+    ctx.accounts
+        .user_ledger
+        .record_claim(request.amount, clock.unix_timestamp)?;
+
+    emit!(WithdrawalClaimedEvent {
+        vault: vault.key(),
+        mint: request.mint,
+        user: ctx.accounts.user.key(),
+        destination: ctx.accounts.destination.key(),
+        request_id: request.request_id,
+        amount: request.amount,
+        total_paid_out: vault.total_paid_out,
+        total_reserved: vault.total_reserved,
+        payout_count: vault.payout_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // PDA is closed automatically via `close = authority` constraint
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::state::{Proposal, TreasuryVault};
+
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    #[account(
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// CHECK: Signer that created the proposal, receives its rent back.
+    /// Validated through proposal.has_one = proposer.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            vault.key().as_ref(),
+            proposal.proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+        has_one = vault @ TreasuryError::InvalidProposal,
+        has_one = proposer @ TreasuryError::InvalidPayer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn handle_close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+    // Ids only grow, so a closed proposal can't be recreated and replayed
+    let proposal = &ctx.accounts.proposal;
+    require!(
+        proposal.executed || proposal.multisig_version != ctx.accounts.vault.multisig_version,
+        TreasuryError::ProposalStillActive
+    );
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::TreasuryError;
+use crate::events::LiabilitiesCommittedEvent;
+use crate::state::{LiabilitySnapshot, MintBalance, TreasuryVault};
+
+/// Remaining accounts: the vault token accounts of `vault.mints[1..]`, in
+/// registry order, so the snapshot balance covers every mint
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct CommitLiabilities<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    #[account(address = vault.vault_token_account @ TreasuryError::InvalidVaultAccount)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LiabilitySnapshot::INIT_SPACE,
+        seeds = [b"liabilities", vault.key().as_ref(), epoch.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub liability_snapshot: Account<'info, LiabilitySnapshot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_commit_liabilities<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CommitLiabilities<'info>>,
+    epoch: u64,
+    merkle_root: [u8; 32],
+    total_liabilities: u64,
+    leaf_count: u64,
+) -> Result<()> {
+    // Epochs only move forward, so an old snapshot can't be re-committed
+    require!(
+        epoch > ctx.accounts.vault.liability_epoch,
+        TreasuryError::InvalidEpoch
+    );
+
+    // Registered mints share decimals, so their balances add up directly.
+    // Tokens reserved for pending requests are already owed, so only the
+    // rest backs the in-game balances.
+    let vault = &ctx.accounts.vault;
+    require!(
+        ctx.remaining_accounts.len() + 1 == vault.mints.len(),
+        TreasuryError::InvalidVaultAccount
+    );
+    let mut balances = vec![ctx.accounts.vault_token_account.amount];
+    for (entry, info) in vault.mints[1..].iter().zip(ctx.remaining_accounts) {
+        require_keys_eq!(
+            info.key(),
+            entry.token_account,
+            TreasuryError::InvalidVaultAccount
+        );
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(info)?;
+        balances.push(token_account.amount);
+    }
+
+    let mut vault_balance: u64 = 0;
+    let mut mint_balances = Vec::with_capacity(balances.len());
+    for (entry, balance) in vault.mints.iter().zip(balances) {
+        let unreserved = balance.saturating_sub(entry.total_reserved);
+        vault_balance = vault_balance
+            .checked_add(unreserved)
+            .ok_or(TreasuryError::Overflow)?;
+        mint_balances.push(MintBalance {
+            mint: entry.mint,
+            balance,
+            reserved: entry.total_reserved,
+        });
+    }
+
+    let clock = Clock::get()?;
+
+    let snapshot = &mut ctx.accounts.liability_snapshot;
+    snapshot.vault = ctx.accounts.vault.key();
+    snapshot.epoch = epoch;
+    snapshot.merkle_root = merkle_root;
+    snapshot.total_liabilities = total_liabilities;
+    snapshot.leaf_count = leaf_count;
+    snapshot.vault_balance = vault_balance;
+    snapshot.mint_balances = mint_balances;
+    snapshot.committed_at = clock.unix_timestamp;
+    snapshot.bump = ctx.bumps.liability_snapshot;
+
+    ctx.accounts.vault.liability_epoch = epoch;
+
+    emit!(LiabilitiesCommittedEvent {
+        vault: snapshot.vault,
+        epoch,
+        merkle_root,
+        total_liabilities,
+        leaf_count,
+        vault_balance: snapshot.vault_balance,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::MultisigConfiguredEvent;
+use crate::multisig::{execute_proposal, validate_multisig_config};
+use crate::state::{Proposal, ProposalAction, TreasuryVault};
+
+#[derive(Accounts)]
+pub struct ConfigureMultisig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Approved ConfigureMultisig proposal, required once multisig mode is on
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+pub fn handle_configure_multisig(
+    ctx: Context<ConfigureMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    large_withdrawal_threshold: u64,
+) -> Result<()> {
+    validate_multisig_config(&signers, threshold)?;
+
+    // First enable is authority-only; after that the current signer set must approve changes
+    if ctx.accounts.vault.multisig_enabled() {
+        execute_proposal(
+            &ctx.accounts.vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::ConfigureMultisig {
+                signers: signers.clone(),
+                threshold,
+                large_withdrawal_threshold,
+            },
+        )?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.multisig_signers = signers;
+    vault.multisig_threshold = threshold;
+    vault.large_withdrawal_threshold = large_withdrawal_threshold;
+    vault.multisig_version = vault
+        .multisig_version
+        .checked_add(1)
+        .ok_or(TreasuryError::Overflow)?;
+
+    emit!(MultisigConfiguredEvent {
+        vault: vault.key(),
+        signers: vault.multisig_signers.clone(),
+        threshold,
+        large_withdrawal_threshold,
+        multisig_version: vault.multisig_version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
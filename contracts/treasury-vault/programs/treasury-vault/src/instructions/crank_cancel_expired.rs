@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::{CrankBountyPaidEvent, WithdrawalCancelledEvent};
+use crate::state::{TreasuryVault, UserLedger, WithdrawalRequest, CANCEL_REASON_EXPIRED};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct CrankCancelExpired<'info> {
+    /// Anyone; receives `vault.crank_bounty` from the request's rent
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// CHECK: Receives the rest of the request's rent, as with cancel_withdrawal.
+    #[account(mut, address = vault.authority @ TreasuryError::Unauthorized)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: Needed for PDA seed derivation. Validated through withdrawal_request seeds.
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = withdrawal_request.bump,
+        has_one = vault @ TreasuryError::InvalidVaultAccount,
+        close = authority,
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+
+    /// User's on-chain ledger
+    #[account(
+        mut,
+        seeds = [b"user_ledger", vault.key().as_ref(), user.key().as_ref()],
+        bump = user_ledger.bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
+}
+
+pub fn handle_crank_cancel_expired(
+    ctx: Context<CrankCancelExpired>,
+    _request_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // The authority gets the grace period to clean up (and reclaim all rent) first
+    let crankable_after = ctx
+        .accounts
+        .withdrawal_request
+        .expires_at
+        .checked_add(ctx.accounts.vault.crank_grace_period)
+        .ok_or(TreasuryError::Overflow)?;
+    require!(
+        clock.unix_timestamp > crankable_after,
+        TreasuryError::CrankTooEarly
+    );
+
+    // Release the reservation made at creation and refund the rate-limit window
+    let request = &ctx.accounts.withdrawal_request;
+    let amount = request.amount;
+    let vault = &mut ctx.accounts.vault;
+    vault.release_unclaimed(
+        &request.mint,
+        amount,
+        request.created_at,
+        clock.unix_timestamp,
+    )?;
+    let bounty = vault.crank_bounty;
+
+    ctx.accounts
+        .user_ledger
+        .record_cancel(amount, clock.unix_timestamp)?;
+
+    emit!(WithdrawalCancelledEvent {
+        vault: vault.key(),
+        user: ctx.accounts.user.key(),
+        request_id: ctx.accounts.withdrawal_request.request_id,
+        amount,
+        total_reserved: vault.total_reserved,
+        reason: CANCEL_REASON_EXPIRED,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if bounty > 0 {
+        ctx.accounts.withdrawal_request.sub_lamports(bounty)?;
+        ctx.accounts.cranker.add_lamports(bounty)?;
+
+        emit!(CrankBountyPaidEvent {
+            vault: ctx.accounts.vault.key(),
+            user: ctx.accounts.user.key(),
+            request_id: ctx.accounts.withdrawal_request.request_id,
+            cranker: ctx.accounts.cranker.key(),
+            bounty,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Remaining rent goes to the authority via `close = authority`
+
+    Ok(())
+}
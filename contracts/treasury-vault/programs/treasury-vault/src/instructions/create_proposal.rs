@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::ProposalCreatedEvent;
+use crate::multisig::validate_multisig_config;
+use crate::state::{Proposal, ProposalAction, TreasuryVault};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// Member of the vault's multisig signer set
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            b"proposal",
+            vault.key().as_ref(),
+            vault.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    let signer_index = ctx
+        .accounts
+        .vault
+        .multisig_signer_index(&ctx.accounts.proposer.key())
+        .ok_or(TreasuryError::NotMultisigSigner)?;
+
+    match &action {
+        ProposalAction::Payout { mint, amount } => {
+            require!(*amount > 0, TreasuryError::ZeroAmount);
+            require!(
+                ctx.accounts.vault.mint(mint).is_some(),
+                TreasuryError::InvalidMint
+            );
+        }
+        ProposalAction::CreateWithdrawal {
+            mint,
+            amount,
+            expires_in,
+            ..
+        } => {
+            require!(*amount > 0, TreasuryError::ZeroAmount);
+            require!(
+                ctx.accounts.vault.mint(mint).is_some(),
+                TreasuryError::InvalidMint
+            );
+            require!(*expires_in > 0, TreasuryError::InvalidExpiration);
+        }
+        ProposalAction::ConfigureMultisig {
+            signers, threshold, ..
+        } => {
+            validate_multisig_config(signers, *threshold)?;
+        }
+        ProposalAction::SetRateLimits { .. }
+        | ProposalAction::SetVoucherSigner { .. }
+        | ProposalAction::ApplyTransferAllowlist { .. } => {}
+        ProposalAction::ProposeTransferAllowlist { transfer_allowlist } => {
+            TreasuryVault::validate_transfer_allowlist(
+                &ctx.accounts.vault.key(),
+                transfer_allowlist,
+            )?;
+        }
+        ProposalAction::InternalTransfer {
+            mint,
+            destination_vault,
+            amount,
+        } => {
+            require!(*amount > 0, TreasuryError::ZeroAmount);
+            require!(
+                ctx.accounts.vault.mint(mint).is_some(),
+                TreasuryError::InvalidMint
+            );
+            require!(
+                ctx.accounts.vault.transfer_allowed(destination_vault),
+                TreasuryError::TransferNotAllowed
+            );
+        }
+        ProposalAction::AddMint { mint } => {
+            require!(
+                ctx.accounts.vault.mint(mint).is_none(),
+                TreasuryError::MintAlreadyRegistered
+            );
+        }
+    }
+
+    let clock = Clock::get()?;
+    let vault = &mut ctx.accounts.vault;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.vault = vault.key();
+    proposal.proposal_id = vault.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.multisig_version = vault.multisig_version;
+    // Creating a proposal counts as the proposer's approval
+    proposal.approvals = 1 << signer_index;
+    proposal.approval_count = 1;
+    proposal.executed = false;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+
+    vault.proposal_count = vault
+        .proposal_count
+        .checked_add(1)
+        .ok_or(TreasuryError::Overflow)?;
+
+    emit!(ProposalCreatedEvent {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        proposer: proposal.proposer,
+        action: proposal.action.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -3,25 +3,35 @@ use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::errors::TreasuryError;
 use crate::events::WithdrawalCreatedEvent;
-use crate::state::{TreasuryVault, WithdrawalRequest};
+use crate::multisig::execute_proposal;
+use crate::state::{Proposal, ProposalAction, TreasuryVault, UserLedger, WithdrawalRequest};
 
 #[derive(Accounts)]
+#[instruction(request_id: u64)]
 pub struct CreateWithdrawal<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"treasury_vault", authority.key().as_ref()],
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
         has_one = authority @ TreasuryError::Unauthorized,
-        has_one = usdt_mint @ TreasuryError::InvalidMint,
+        constraint = !vault.paused @ TreasuryError::VaultPaused,
     )]
     pub vault: Account<'info, TreasuryVault>,
 
-    pub usdt_mint: InterfaceAccount<'info, Mint>,
+    /// Registered mint the request is paid in, enabled for new requests
+    #[account(
+        constraint = vault.mint(&mint.key()).is_some() @ TreasuryError::InvalidMint,
+        constraint = vault.mint_enabled(&mint.key()) @ TreasuryError::MintDisabled,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    #[account(address = vault.vault_token_account @ TreasuryError::InvalidVaultAccount)]
+    #[account(
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
+    )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: User wallet that will be allowed to claim. Not a signer — authority creates on behalf.
@@ -31,46 +41,115 @@ pub struct CreateWithdrawal<'info> {
         init,
         payer = authority,
         space = 8 + WithdrawalRequest::INIT_SPACE,
-        seeds = [b"withdrawal", vault.key().as_ref(), user.key().as_ref()],
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
         bump,
     )]
     pub withdrawal_request: Account<'info, WithdrawalRequest>,
 
+    /// User's on-chain ledger, created on first request
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + UserLedger::INIT_SPACE,
+        seeds = [b"user_ledger", vault.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
+
+    /// Approved CreateWithdrawal proposal, required in multisig mode
+    /// for amounts above `vault.large_withdrawal_threshold`
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handle_create_withdrawal(
     ctx: Context<CreateWithdrawal>,
+    request_id: u64,
     amount: u64,
     expires_in: i64,
+    destination: Option<Pubkey>,
 ) -> Result<()> {
     require!(amount > 0, TreasuryError::ZeroAmount);
     require!(expires_in > 0, TreasuryError::InvalidExpiration);
 
-    // Verify vault has enough balance to cover this withdrawal
+    let mint = ctx.accounts.mint.key();
+    let vault = &ctx.accounts.vault;
+    if vault.multisig_enabled() && amount > vault.large_withdrawal_threshold {
+        execute_proposal(
+            vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::CreateWithdrawal {
+                mint,
+                user: ctx.accounts.user.key(),
+                request_id,
+                amount,
+                expires_in,
+                destination,
+            },
+        )?;
+    }
+
+    // Verify vault has enough unreserved balance to cover this withdrawal
     require!(
-        ctx.accounts.vault_token_account.amount >= amount,
+        vault.available_balance(&mint, ctx.accounts.vault_token_account.amount) >= amount,
         TreasuryError::InsufficientBalance
     );
 
     let clock = Clock::get()?;
 
+    let vault = &mut ctx.accounts.vault;
+    if vault.max_withdrawal_amount > 0 {
+        require!(
+            amount <= vault.max_withdrawal_amount,
+            TreasuryError::RateLimitExceeded
+        );
+    }
+    vault.record_outflow(amount, clock.unix_timestamp)?;
+
+    // Reserve the amount so it stays claimable until the request is closed
+    vault.reserve(&mint, amount)?;
+
     let request = &mut ctx.accounts.withdrawal_request;
     request.vault = ctx.accounts.vault.key();
     request.user = ctx.accounts.user.key();
+    request.request_id = request_id;
     request.amount = amount;
     request.created_at = clock.unix_timestamp;
     request.expires_at = clock
         .unix_timestamp
         .checked_add(expires_in)
         .ok_or(TreasuryError::Overflow)?;
+    request.mint = mint;
+    request.destination = destination;
+    request.relayer_token_account = None;
+    request.relayer_fee = 0;
+    request.payer = ctx.accounts.authority.key();
     request.bump = ctx.bumps.withdrawal_request;
 
+    let ledger = &mut ctx.accounts.user_ledger;
+    ledger.init_if_new(
+        ctx.accounts.vault.key(),
+        ctx.accounts.user.key(),
+        ctx.bumps.user_ledger,
+    );
+    ledger.record_request(amount, clock.unix_timestamp)?;
+
     emit!(WithdrawalCreatedEvent {
         vault: ctx.accounts.vault.key(),
+        mint,
         user: ctx.accounts.user.key(),
+        destination,
+        request_id,
         amount,
         expires_at: request.expires_at,
+        total_reserved: ctx.accounts.vault.total_reserved,
         timestamp: clock.unix_timestamp,
     });
 
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::errors::TreasuryError;
+use crate::events::WithdrawalCreatedEvent;
+use crate::state::{
+    BatchWithdrawal, TreasuryVault, UserLedger, WithdrawalRequest, MAX_WITHDRAWAL_BATCH,
+};
+
+/// Remaining accounts, per batch entry in order:
+/// `[withdrawal_request (writable), user_ledger (writable)]`
+#[derive(Accounts)]
+pub struct CreateWithdrawalsBatch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+        constraint = !vault.paused @ TreasuryError::VaultPaused,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Registered mint every request in the batch is paid in
+    #[account(
+        constraint = vault.mint(&mint.key()).is_some() @ TreasuryError::InvalidMint,
+        constraint = vault.mint_enabled(&mint.key()) @ TreasuryError::MintDisabled,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_withdrawals_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateWithdrawalsBatch<'info>>,
+    withdrawals: Vec<BatchWithdrawal>,
+) -> Result<()> {
+    require!(
+        !withdrawals.is_empty() && withdrawals.len() <= MAX_WITHDRAWAL_BATCH,
+        TreasuryError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == withdrawals.len() * 2,
+        TreasuryError::InvalidBatchAccounts
+    );
+
+    let mint = ctx.accounts.mint.key();
+    let vault = &ctx.accounts.vault;
+    let mut total: u64 = 0;
+    for withdrawal in &withdrawals {
+        require!(withdrawal.amount > 0, TreasuryError::ZeroAmount);
+        require!(withdrawal.expires_in > 0, TreasuryError::InvalidExpiration);
+        if vault.max_withdrawal_amount > 0 {
+            require!(
+                withdrawal.amount <= vault.max_withdrawal_amount,
+                TreasuryError::RateLimitExceeded
+            );
+        }
+        // Large withdrawals need a proposal, which only create_withdrawal takes
+        if vault.multisig_enabled() {
+            require!(
+                withdrawal.amount <= vault.large_withdrawal_threshold,
+                TreasuryError::MultisigRequired
+            );
+        }
+        total = total
+            .checked_add(withdrawal.amount)
+            .ok_or(TreasuryError::Overflow)?;
+    }
+
+    // One check for the whole batch: all requests are covered or none is created
+    require!(
+        vault.available_balance(&mint, ctx.accounts.vault_token_account.amount) >= total,
+        TreasuryError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+    let vault_key = ctx.accounts.vault.key();
+    ctx.accounts
+        .vault
+        .record_outflow(total, clock.unix_timestamp)?;
+
+    for (withdrawal, accounts) in withdrawals.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (request_info, ledger_info) = (&accounts[0], &accounts[1]);
+
+        let request_id = withdrawal.request_id.to_le_bytes();
+        let request_seeds: &[&[u8]] = &[
+            b"withdrawal",
+            vault_key.as_ref(),
+            withdrawal.user.as_ref(),
+            &request_id,
+        ];
+        let request_bump = pda_bump(request_info, request_seeds)?;
+        create_pda(
+            &ctx.accounts.authority,
+            request_info,
+            &ctx.accounts.system_program,
+            8 + WithdrawalRequest::INIT_SPACE,
+            WithdrawalRequest::DISCRIMINATOR,
+            &[request_seeds, &[&[request_bump]]].concat(),
+        )?;
+
+        let expires_at = clock
+            .unix_timestamp
+            .checked_add(withdrawal.expires_in)
+            .ok_or(TreasuryError::Overflow)?;
+        let mut request = Account::<WithdrawalRequest>::try_from(request_info)?;
+        request.vault = vault_key;
+        request.user = withdrawal.user;
+        request.request_id = withdrawal.request_id;
+        request.amount = withdrawal.amount;
+        request.created_at = clock.unix_timestamp;
+        request.expires_at = expires_at;
+        request.mint = mint;
+        request.destination = withdrawal.destination;
+        request.relayer_token_account = None;
+        request.relayer_fee = 0;
+        request.payer = ctx.accounts.authority.key();
+        request.bump = request_bump;
+        request.exit(&crate::ID)?;
+
+        // The same user may appear several times; the ledger is created once
+        let ledger_seeds: &[&[u8]] =
+            &[b"user_ledger", vault_key.as_ref(), withdrawal.user.as_ref()];
+        let ledger_bump = pda_bump(ledger_info, ledger_seeds)?;
+        if ledger_info.owner != &crate::ID {
+            create_pda(
+                &ctx.accounts.authority,
+                ledger_info,
+                &ctx.accounts.system_program,
+                8 + UserLedger::INIT_SPACE,
+                UserLedger::DISCRIMINATOR,
+                &[ledger_seeds, &[&[ledger_bump]]].concat(),
+            )?;
+        }
+        let mut ledger = Account::<UserLedger>::try_from(ledger_info)?;
+        ledger.init_if_new(vault_key, withdrawal.user, ledger_bump);
+        ledger.record_request(withdrawal.amount, clock.unix_timestamp)?;
+        ledger.exit(&crate::ID)?;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.reserve(&mint, withdrawal.amount)?;
+
+        emit!(WithdrawalCreatedEvent {
+            vault: vault_key,
+            mint,
+            user: withdrawal.user,
+            destination: withdrawal.destination,
+            request_id: withdrawal.request_id,
+            amount: withdrawal.amount,
+            expires_at,
+            total_reserved: vault.total_reserved,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+/// Bump of the PDA at `seeds`, which `account` must be
+fn pda_bump(account: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(account.key(), address, TreasuryError::InvalidBatchAccounts);
+    require!(account.is_writable, TreasuryError::InvalidBatchAccounts);
+    Ok(bump)
+}
+
+/// Allocates a zeroed, program-owned PDA stamped with `discriminator`, the
+/// same steps Anchor's `init` takes (including a pre-funded address)
+fn create_pda<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    discriminator: &[u8],
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let program = system_program.to_account_info();
+    let signer = &[signer_seeds];
+    if account.lamports() == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                program,
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        let missing = rent.saturating_sub(account.lamports());
+        if missing > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    program.clone(),
+                    Transfer {
+                        from: payer.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                missing,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                program.clone(),
+                Allocate {
+                    account_to_allocate: account.clone(),
+                },
+                signer,
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                program,
+                Assign {
+                    account_to_assign: account.clone(),
+                },
+                signer,
+            ),
+            &crate::ID,
+        )?;
+    }
+    account.try_borrow_mut_data()?[..8].copy_from_slice(discriminator);
+    Ok(())
+}
@@ -14,28 +14,34 @@ pub struct Deposit<'info> {
 
     #[account(
         mut,
-        seeds = [b"treasury_vault", authority.key().as_ref()],
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
         has_one = authority @ TreasuryError::Unauthorized,
-        has_one = usdt_mint @ TreasuryError::InvalidMint,
+        constraint = !vault.paused @ TreasuryError::VaultPaused,
     )]
     pub vault: Account<'info, TreasuryVault>,
 
-    pub usdt_mint: InterfaceAccount<'info, Mint>,
+    /// Registered mint, enabled for deposits
+    #[account(
+        constraint = vault.mint(&mint.key()).is_some() @ TreasuryError::InvalidMint,
+        constraint = vault.mint_enabled(&mint.key()) @ TreasuryError::MintDisabled,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// Authority's USDT token account (source of deposit)
+    /// Authority's token account (source of deposit)
     #[account(
         mut,
-        associated_token::mint = usdt_mint,
+        associated_token::mint = mint,
         associated_token::authority = authority,
         associated_token::token_program = token_program,
     )]
     pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// Vault's USDT token account (destination)
+    /// Vault's token account (destination)
     #[account(
         mut,
-        address = vault.vault_token_account @ TreasuryError::InvalidVaultAccount,
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -53,27 +59,21 @@ pub fn handle_deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
                 from: ctx.accounts.authority_token_account.to_account_info(),
                 to: ctx.accounts.vault_token_account.to_account_info(),
                 authority: ctx.accounts.authority.to_account_info(),
-                mint: ctx.accounts.usdt_mint.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
             },
         ),
         amount,
-        ctx.accounts.usdt_mint.decimals,
+        ctx.accounts.mint.decimals,
     )?;
 
     // Update vault stats
+    let mint = ctx.accounts.mint.key();
     let vault = &mut ctx.accounts.vault;
-    vault.total_deposited = vault
-        .total_deposited
-        .checked_add(amount)
-        .ok_or(TreasuryError::Overflow)?;
-    vault.deposit_count = vault
-        .deposit_count
-        .checked_add(1)
-        .ok_or(TreasuryError::Overflow)?;
-    vault.last_deposit_at = Clock::get()?.unix_timestamp;
+    vault.record_deposit(&mint, amount, Clock::get()?.unix_timestamp)?;
 
     emit!(DepositEvent {
         vault: vault.key(),
+        mint,
         amount,
         total_deposited: vault.total_deposited,
         deposit_count: vault.deposit_count,
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::WithdrawalExtendedEvent;
+use crate::state::{TreasuryVault, WithdrawalRequest};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct ExtendWithdrawal<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// CHECK: Needed for PDA seed derivation. Validated through withdrawal_request.has_one.
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = withdrawal_request.bump,
+        has_one = vault @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+}
+
+pub fn handle_extend_withdrawal(
+    ctx: Context<ExtendWithdrawal>,
+    _request_id: u64,
+    expires_in: i64,
+) -> Result<()> {
+    require!(expires_in > 0, TreasuryError::InvalidExpiration);
+
+    let clock = Clock::get()?;
+    let request = &mut ctx.accounts.withdrawal_request;
+    let expires_at = clock
+        .unix_timestamp
+        .checked_add(expires_in)
+        .ok_or(TreasuryError::Overflow)?;
+
+    // Only ever moves the deadline forward, so the user's window never shrinks.
+    // Works on expired requests too, as long as they were not cancelled yet.
+    require!(
+        expires_at > request.expires_at,
+        TreasuryError::InvalidExpiration
+    );
+
+    let previous_expires_at = request.expires_at;
+    request.expires_at = expires_at;
+
+    emit!(WithdrawalExtendedEvent {
+        vault: ctx.accounts.vault.key(),
+        user: ctx.accounts.user.key(),
+        request_id: request.request_id,
+        previous_expires_at,
+        expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
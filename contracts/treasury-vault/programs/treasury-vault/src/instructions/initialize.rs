@@ -4,10 +4,15 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
+use crate::errors::TreasuryError;
 use crate::events::VaultInitialized;
-use crate::state::TreasuryVault;
+use crate::state::{
+    TreasuryVault, VaultMint, DEFAULT_CRANK_BOUNTY, DEFAULT_CRANK_GRACE_PERIOD, OUTFLOW_BUCKETS,
+    OUTFLOW_BUCKET_SECONDS,
+};
 
 #[derive(Accounts)]
+#[instruction(vault_id: u16)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -16,12 +21,12 @@ pub struct Initialize<'info> {
         init,
         payer = authority,
         space = 8 + TreasuryVault::INIT_SPACE,
-        seeds = [b"treasury_vault", authority.key().as_ref()],
+        seeds = [b"treasury_vault", authority.key().as_ref(), vault_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub vault: Account<'info, TreasuryVault>,
 
-    /// USDT SPL mint
+    /// USDT SPL mint, the vault's primary mint. Others are added with add_mint.
     pub usdt_mint: InterfaceAccount<'info, Mint>,
 
     /// Vault's token account (ATA owned by vault PDA)
@@ -37,32 +42,66 @@ pub struct Initialize<'info> {
     /// CHECK: Payout destination wallet, validated by being stored in vault state
     pub payout_wallet: UncheckedAccount<'info>,
 
+    /// CHECK: Emergency pause key, stored in vault state. Must differ from authority.
+    #[account(constraint = guardian.key() != authority.key() @ TreasuryError::InvalidGuardian)]
+    pub guardian: UncheckedAccount<'info>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_initialize(ctx: Context<Initialize>) -> Result<()> {
+pub fn handle_initialize(
+    ctx: Context<Initialize>,
+    vault_id: u16,
+    transfer_allowlist: Vec<Pubkey>,
+) -> Result<()> {
+    TreasuryVault::validate_transfer_allowlist(&ctx.accounts.vault.key(), &transfer_allowlist)?;
+
     let vault = &mut ctx.accounts.vault;
     let clock = Clock::get()?;
 
     vault.authority = ctx.accounts.authority.key();
+    vault.seed_key = ctx.accounts.authority.key();
+    vault.vault_id = vault_id;
+    vault.pending_authority = None;
+    vault.guardian = ctx.accounts.guardian.key();
+    vault.paused = false;
     vault.payout_wallet = ctx.accounts.payout_wallet.key();
     vault.usdt_mint = ctx.accounts.usdt_mint.key();
     vault.vault_token_account = ctx.accounts.vault_token_account.key();
+    vault.mint_decimals = ctx.accounts.usdt_mint.decimals;
+    vault.mints = vec![VaultMint::new(vault.usdt_mint, vault.vault_token_account)];
     vault.total_deposited = 0;
     vault.total_paid_out = 0;
+    vault.total_reserved = 0;
     vault.deposit_count = 0;
     vault.payout_count = 0;
     vault.last_deposit_at = 0;
     vault.last_payout_at = 0;
+    // Rate limits start disabled; configure with set_rate_limits
+    vault.max_payout_per_tx = 0;
+    vault.max_outflow_per_window = 0;
+    vault.max_withdrawal_amount = 0;
+    vault.outflow_buckets = [0; OUTFLOW_BUCKETS];
+    vault.outflow_hour = clock.unix_timestamp.div_euclid(OUTFLOW_BUCKET_SECONDS);
+    // Voucher claims start disabled; enable with set_voucher_signer
+    vault.voucher_signer = Pubkey::default();
+    vault.crank_grace_period = DEFAULT_CRANK_GRACE_PERIOD;
+    vault.crank_bounty = DEFAULT_CRANK_BOUNTY;
+    vault.transfer_allowlist = transfer_allowlist;
+    vault.pending_transfer_allowlist = Vec::new();
+    vault.transfer_allowlist_effective_at = 0;
     vault.bump = ctx.bumps.vault;
 
     emit!(VaultInitialized {
         vault: vault.key(),
+        vault_id,
         authority: vault.authority,
         payout_wallet: vault.payout_wallet,
+        guardian: vault.guardian,
         usdt_mint: vault.usdt_mint,
+        transfer_allowlist: vault.transfer_allowlist.clone(),
         timestamp: clock.unix_timestamp,
     });
 
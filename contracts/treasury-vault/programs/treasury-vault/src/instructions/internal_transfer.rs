@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::TreasuryError;
+use crate::events::InternalTransferEvent;
+use crate::multisig::execute_proposal;
+use crate::state::{Proposal, ProposalAction, TreasuryVault};
+
+#[derive(Accounts)]
+pub struct InternalTransfer<'info> {
+    pub authority: Signer<'info>,
+
+    /// Source vault
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+        constraint = !vault.paused @ TreasuryError::VaultPaused,
+        constraint = vault.transfer_allowed(&destination_vault.key())
+            @ TreasuryError::TransferNotAllowed,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Vault of this program on the source vault's transfer allowlist
+    #[account(
+        mut,
+        seeds = [
+            b"treasury_vault",
+            destination_vault.seed_key.as_ref(),
+            destination_vault.vault_id.to_le_bytes().as_ref(),
+        ],
+        bump = destination_vault.bump,
+        constraint = !destination_vault.paused @ TreasuryError::VaultPaused,
+    )]
+    pub destination_vault: Account<'info, TreasuryVault>,
+
+    /// Mint registered with both vaults, enabled for deposits in the destination
+    #[account(
+        constraint = vault.mint(&mint.key()).is_some() @ TreasuryError::InvalidMint,
+        constraint = destination_vault.mint(&mint.key()).is_some() @ TreasuryError::InvalidMint,
+        constraint = destination_vault.mint_enabled(&mint.key()) @ TreasuryError::MintDisabled,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Source vault's token account (PDA is authority)
+    #[account(
+        mut,
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Destination vault's token account for the mint
+    #[account(
+        mut,
+        constraint = destination_vault.is_token_account(&mint.key(), &destination_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub destination_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Approved InternalTransfer proposal, required in multisig mode
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_internal_transfer(ctx: Context<InternalTransfer>, amount: u64) -> Result<()> {
+    require!(amount > 0, TreasuryError::ZeroAmount);
+
+    let mint = ctx.accounts.mint.key();
+    let destination_vault = ctx.accounts.destination_vault.key();
+    if ctx.accounts.vault.multisig_enabled() {
+        execute_proposal(
+            &ctx.accounts.vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::InternalTransfer {
+                mint,
+                destination_vault,
+                amount,
+            },
+        )?;
+    }
+
+    let clock = Clock::get()?;
+    let vault = &mut ctx.accounts.vault;
+    vault.record_outflow(amount, clock.unix_timestamp)?;
+
+    // Funds reserved for pending withdrawals stay in the source vault
+    require!(
+        vault.available_balance(&mint, ctx.accounts.vault_token_account.amount) >= amount,
+        TreasuryError::InsufficientBalance
+    );
+
+    let seed_key = vault.seed_key;
+    let vault_id = vault.vault_id.to_le_bytes();
+    let seeds: &[&[u8]] = &[
+        b"treasury_vault",
+        seed_key.as_ref(),
+        vault_id.as_ref(),
+        &[vault.bump],
+    ];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: ctx.accounts.destination_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Counted as a payout of the source and a deposit of the destination,
+    // so each vault's totals keep matching its token balances
+    let vault = &mut ctx.accounts.vault;
+    vault.record_paid_out(&mint, amount, clock.unix_timestamp)?;
+    let destination = &mut ctx.accounts.destination_vault;
+    destination.record_deposit(&mint, amount, clock.unix_timestamp)?;
+
+    emit!(InternalTransferEvent {
+        vault: ctx.accounts.vault.key(),
+        destination_vault,
+        mint,
+        amount,
+        total_paid_out: ctx.accounts.vault.total_paid_out,
+        destination_total_deposited: ctx.accounts.destination_vault.total_deposited,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
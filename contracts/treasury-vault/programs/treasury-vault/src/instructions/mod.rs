@@ -4,6 +4,34 @@ pub mod payout;
 pub mod create_withdrawal;
 pub mod claim_withdrawal;
 pub mod cancel_withdrawal;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod configure_multisig;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod set_rate_limits;
+pub mod set_paused;
+pub mod set_guardian;
+pub mod user_deposit;
+pub mod commit_liabilities;
+pub mod extend_withdrawal;
+pub mod amend_withdrawal;
+pub mod user_cancel_withdrawal;
+pub mod claim_withdrawal_relayed;
+pub mod set_withdrawal_relayer;
+pub mod claim_with_voucher;
+pub mod set_voucher_signer;
+pub mod create_withdrawals_batch;
+pub mod cancel_expired_batch;
+pub mod crank_cancel_expired;
+pub mod set_crank_config;
+pub mod add_mint;
+pub mod set_mint_enabled;
+pub mod internal_transfer;
+pub mod propose_transfer_allowlist;
+pub mod apply_transfer_allowlist;
+pub mod cancel_transfer_allowlist;
+pub mod close_proposal;
 
 pub use initialize::*;
 pub use deposit::*;
@@ -11,3 +39,31 @@ pub use payout::*;
 pub use create_withdrawal::*;
 pub use claim_withdrawal::*;
 pub use cancel_withdrawal::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use configure_multisig::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use set_rate_limits::*;
+pub use set_paused::*;
+pub use set_guardian::*;
+pub use user_deposit::*;
+pub use commit_liabilities::*;
+pub use extend_withdrawal::*;
+pub use amend_withdrawal::*;
+pub use user_cancel_withdrawal::*;
+pub use claim_withdrawal_relayed::*;
+pub use set_withdrawal_relayer::*;
+pub use claim_with_voucher::*;
+pub use set_voucher_signer::*;
+pub use create_withdrawals_batch::*;
+pub use cancel_expired_batch::*;
+pub use crank_cancel_expired::*;
+pub use set_crank_config::*;
+pub use add_mint::*;
+pub use set_mint_enabled::*;
+pub use internal_transfer::*;
+pub use propose_transfer_allowlist::*;
+pub use apply_transfer_allowlist::*;
+pub use cancel_transfer_allowlist::*;
+pub use close_proposal::*;
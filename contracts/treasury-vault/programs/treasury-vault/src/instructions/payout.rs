@@ -8,7 +8,8 @@ use anchor_spl::{
 
 use crate::errors::TreasuryError;
 use crate::events::PayoutEvent;
-use crate::state::TreasuryVault;
+use crate::multisig::execute_proposal;
+use crate::state::{Proposal, ProposalAction, TreasuryVault};
 
 #[derive(Accounts)]
 pub struct Payout<'info> {
@@ -17,28 +18,31 @@ pub struct Payout<'info> {
 
     #[account(
         mut,
-        seeds = [b"treasury_vault", authority.key().as_ref()],
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
         bump = vault.bump,
         has_one = authority @ TreasuryError::Unauthorized,
         has_one = payout_wallet @ TreasuryError::InvalidPayoutWallet,
-        has_one = usdt_mint @ TreasuryError::InvalidMint,
+        constraint = !vault.paused @ TreasuryError::VaultPaused,
     )]
     pub vault: Account<'info, TreasuryVault>,
 
-    pub usdt_mint: InterfaceAccount<'info, Mint>,
+    /// Registered mint to pay out (disabled mints can still be drained)
+    #[account(constraint = vault.mint(&mint.key()).is_some() @ TreasuryError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    /// Vault's USDT token account (source — PDA is authority)
+    /// Vault's token account (source — PDA is authority)
     #[account(
         mut,
-        address = vault.vault_token_account @ TreasuryError::InvalidVaultAccount,
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// Payout wallet's USDT token account (destination)
+    /// Payout wallet's token account (destination)
     #[account(
         init_if_needed,
         payer = authority,
-        associated_token::mint = usdt_mint,
+        associated_token::mint = mint,
         associated_token::authority = payout_wallet,
         associated_token::token_program = token_program,
     )]
@@ -47,6 +51,10 @@ pub struct Payout<'info> {
     /// CHECK: Validated via has_one constraint on vault
     pub payout_wallet: UncheckedAccount<'info>,
 
+    /// Approved Payout proposal, required in multisig mode
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -55,17 +63,41 @@ pub struct Payout<'info> {
 pub fn handle_payout(ctx: Context<Payout>, amount: u64) -> Result<()> {
     require!(amount > 0, TreasuryError::ZeroAmount);
 
-    // Check vault has enough balance
+    let mint = ctx.accounts.mint.key();
+    if ctx.accounts.vault.multisig_enabled() {
+        execute_proposal(
+            &ctx.accounts.vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::Payout { mint, amount },
+        )?;
+    }
+
+    let clock = Clock::get()?;
+    let vault = &mut ctx.accounts.vault;
+    if vault.max_payout_per_tx > 0 {
+        require!(
+            amount <= vault.max_payout_per_tx,
+            TreasuryError::RateLimitExceeded
+        );
+    }
+    vault.record_outflow(amount, clock.unix_timestamp)?;
+
+    // Check vault has enough balance not reserved for pending withdrawals
     require!(
-        ctx.accounts.vault_token_account.amount >= amount,
+        ctx.accounts
+            .vault
+            .available_balance(&mint, ctx.accounts.vault_token_account.amount)
+            >= amount,
         TreasuryError::InsufficientBalance
     );
 
-    // Transfer USDT from vault to payout wallet using PDA signer seeds
-    let authority_key = ctx.accounts.authority.key();
+    // Transfer from vault to payout wallet using PDA signer seeds
+    let seed_key = ctx.accounts.vault.seed_key;
+    let vault_id = ctx.accounts.vault.vault_id.to_le_bytes();
     let seeds: &[&[u8]] = &[
         b"treasury_vault",
-        authority_key.as_ref(),
+        seed_key.as_ref(),
+        vault_id.as_ref(),
         &[ctx.accounts.vault.bump],
     ];
 
@@ -76,28 +108,21 @@ pub fn handle_payout(ctx: Context<Payout>, amount: u64) -> Result<()> {
                 from: ctx.accounts.vault_token_account.to_account_info(),
                 to: ctx.accounts.payout_token_account.to_account_info(),
                 authority: ctx.accounts.vault.to_account_info(),
-                mint: ctx.accounts.usdt_mint.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
             },
             &[seeds],
         ),
         amount,
-        ctx.accounts.usdt_mint.decimals,
+        ctx.accounts.mint.decimals,
     )?;
 
     // Update vault stats
     let vault = &mut ctx.accounts.vault;
-    vault.total_paid_out = vault
-        .total_paid_out
-        .checked_add(amount)
-        .ok_or(TreasuryError::Overflow)?;
-    vault.payout_count = vault
-        .payout_count
-        .checked_add(1)
-        .ok_or(TreasuryError::Overflow)?;
-    vault.last_payout_at = Clock::get()?.unix_timestamp;
+    vault.record_paid_out(&mint, amount, clock.unix_timestamp)?;
 
     emit!(PayoutEvent {
         vault: vault.key(),
+        mint,
         payout_wallet: ctx.accounts.payout_wallet.key(),
         amount,
         total_paid_out: vault.total_paid_out,
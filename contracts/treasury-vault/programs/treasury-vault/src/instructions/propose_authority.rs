@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::AuthorityProposedEvent;
+use crate::state::TreasuryVault;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+}
+
+pub fn handle_propose_authority(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    // Authority and guardian must stay separate keys
+    require!(
+        new_authority != Pubkey::default()
+            && new_authority != ctx.accounts.authority.key()
+            && new_authority != ctx.accounts.vault.guardian,
+        TreasuryError::InvalidNewAuthority
+    );
+
+    // Overwrites any previous proposal that was never accepted
+    let vault = &mut ctx.accounts.vault;
+    vault.pending_authority = Some(new_authority);
+
+    emit!(AuthorityProposedEvent {
+        vault: vault.key(),
+        authority: vault.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
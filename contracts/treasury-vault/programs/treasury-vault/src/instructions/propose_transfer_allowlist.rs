@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::TransferAllowlistProposedEvent;
+use crate::multisig::execute_proposal;
+use crate::state::{Proposal, ProposalAction, TreasuryVault, TRANSFER_ALLOWLIST_TIMELOCK};
+
+#[derive(Accounts)]
+pub struct ProposeTransferAllowlist<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Approved ProposeTransferAllowlist proposal, required in multisig mode
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+pub fn handle_propose_transfer_allowlist(
+    ctx: Context<ProposeTransferAllowlist>,
+    transfer_allowlist: Vec<Pubkey>,
+) -> Result<()> {
+    TreasuryVault::validate_transfer_allowlist(&ctx.accounts.vault.key(), &transfer_allowlist)?;
+
+    if ctx.accounts.vault.multisig_enabled() {
+        execute_proposal(
+            &ctx.accounts.vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::ProposeTransferAllowlist {
+                transfer_allowlist: transfer_allowlist.clone(),
+            },
+        )?;
+    }
+
+    let clock = Clock::get()?;
+    let effective_at = clock
+        .unix_timestamp
+        .checked_add(TRANSFER_ALLOWLIST_TIMELOCK)
+        .ok_or(TreasuryError::Overflow)?;
+
+    // Overwrites any pending change and restarts the timelock
+    let vault = &mut ctx.accounts.vault;
+    vault.pending_transfer_allowlist = transfer_allowlist;
+    vault.transfer_allowlist_effective_at = effective_at;
+
+    emit!(TransferAllowlistProposedEvent {
+        vault: vault.key(),
+        pending_transfer_allowlist: vault.pending_transfer_allowlist.clone(),
+        effective_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
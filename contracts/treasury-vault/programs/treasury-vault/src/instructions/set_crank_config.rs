@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::CrankConfigUpdatedEvent;
+use crate::state::{TreasuryVault, MAX_CRANK_BOUNTY};
+
+#[derive(Accounts)]
+pub struct SetCrankConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+}
+
+pub fn handle_set_crank_config(
+    ctx: Context<SetCrankConfig>,
+    grace_period: i64,
+    bounty: u64,
+) -> Result<()> {
+    require!(
+        grace_period >= 0 && bounty <= MAX_CRANK_BOUNTY,
+        TreasuryError::InvalidCrankConfig
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    vault.crank_grace_period = grace_period;
+    vault.crank_bounty = bounty;
+
+    emit!(CrankConfigUpdatedEvent {
+        vault: vault.key(),
+        grace_period,
+        bounty,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
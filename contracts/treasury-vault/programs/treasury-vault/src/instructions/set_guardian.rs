@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::GuardianChangedEvent;
+use crate::state::TreasuryVault;
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = guardian @ TreasuryError::NotGuardian,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+}
+
+pub fn handle_set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    // Authority cannot hold the kill switch for itself, nor can the key it
+    // is being handed to
+    require!(
+        new_guardian != Pubkey::default()
+            && new_guardian != vault.authority
+            && Some(new_guardian) != vault.pending_authority,
+        TreasuryError::InvalidGuardian
+    );
+
+    let previous_guardian = vault.guardian;
+    vault.guardian = new_guardian;
+
+    emit!(GuardianChangedEvent {
+        vault: vault.key(),
+        previous_guardian,
+        new_guardian,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
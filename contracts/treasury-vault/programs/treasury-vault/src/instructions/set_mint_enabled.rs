@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::MintStatusChangedEvent;
+use crate::state::TreasuryVault;
+
+#[derive(Accounts)]
+pub struct SetMintEnabled<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+}
+
+pub fn handle_set_mint_enabled(
+    ctx: Context<SetMintEnabled>,
+    mint: Pubkey,
+    enabled: bool,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.mint_mut(&mint)?.enabled = enabled;
+
+    emit!(MintStatusChangedEvent {
+        vault: vault.key(),
+        mint,
+        enabled,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
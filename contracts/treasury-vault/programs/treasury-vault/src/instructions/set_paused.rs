@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::{VaultPausedEvent, VaultUnpausedEvent};
+use crate::state::TreasuryVault;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = guardian @ TreasuryError::NotGuardian,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+}
+
+pub fn handle_pause(ctx: Context<SetPaused>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.paused = true;
+
+    emit!(VaultPausedEvent {
+        vault: vault.key(),
+        guardian: vault.guardian,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_unpause(ctx: Context<SetPaused>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.paused = false;
+
+    emit!(VaultUnpausedEvent {
+        vault: vault.key(),
+        guardian: vault.guardian,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
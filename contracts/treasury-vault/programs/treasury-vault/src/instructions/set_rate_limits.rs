@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::RateLimitsUpdatedEvent;
+use crate::multisig::execute_proposal;
+use crate::state::{Proposal, ProposalAction, TreasuryVault};
+
+#[derive(Accounts)]
+pub struct SetRateLimits<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Approved SetRateLimits proposal, required in multisig mode
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+pub fn handle_set_rate_limits(
+    ctx: Context<SetRateLimits>,
+    max_payout_per_tx: u64,
+    max_outflow_per_window: u64,
+    max_withdrawal_amount: u64,
+) -> Result<()> {
+    if ctx.accounts.vault.multisig_enabled() {
+        execute_proposal(
+            &ctx.accounts.vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::SetRateLimits {
+                max_payout_per_tx,
+                max_outflow_per_window,
+                max_withdrawal_amount,
+            },
+        )?;
+    }
+
+    // Outflow already counted in the window is kept; new limits apply from the next call
+    let vault = &mut ctx.accounts.vault;
+    vault.max_payout_per_tx = max_payout_per_tx;
+    vault.max_outflow_per_window = max_outflow_per_window;
+    vault.max_withdrawal_amount = max_withdrawal_amount;
+
+    emit!(RateLimitsUpdatedEvent {
+        vault: vault.key(),
+        max_payout_per_tx,
+        max_outflow_per_window,
+        max_withdrawal_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::VoucherSignerChangedEvent;
+use crate::multisig::execute_proposal;
+use crate::state::{Proposal, ProposalAction, TreasuryVault};
+
+#[derive(Accounts)]
+pub struct SetVoucherSigner<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Approved SetVoucherSigner proposal, required in multisig mode
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+}
+
+pub fn handle_set_voucher_signer(
+    ctx: Context<SetVoucherSigner>,
+    new_voucher_signer: Pubkey,
+) -> Result<()> {
+    // The signer key authorizes payouts on its own, so it gets the same
+    // M-of-N gate as the payouts themselves
+    if ctx.accounts.vault.multisig_enabled() {
+        execute_proposal(
+            &ctx.accounts.vault,
+            ctx.accounts.proposal.as_mut(),
+            &ProposalAction::SetVoucherSigner {
+                voucher_signer: new_voucher_signer,
+            },
+        )?;
+    }
+
+    let vault = &mut ctx.accounts.vault;
+
+    let previous_voucher_signer = vault.voucher_signer;
+    vault.voucher_signer = new_voucher_signer;
+
+    emit!(VoucherSignerChangedEvent {
+        vault: vault.key(),
+        previous_voucher_signer,
+        new_voucher_signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
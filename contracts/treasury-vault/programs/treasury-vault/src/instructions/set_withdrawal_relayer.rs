@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::WithdrawalRelayerSetEvent;
+use crate::state::{TreasuryVault, WithdrawalRequest, MAX_RELAYER_FEE};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct SetWithdrawalRelayer<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = authority @ TreasuryError::Unauthorized,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// CHECK: Needed for PDA seed derivation. Validated through withdrawal_request.has_one.
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = withdrawal_request.bump,
+        has_one = vault @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+}
+
+pub fn handle_set_withdrawal_relayer(
+    ctx: Context<SetWithdrawalRelayer>,
+    _request_id: u64,
+    relayer_token_account: Option<Pubkey>,
+    relayer_fee: u64,
+) -> Result<()> {
+    let request = &mut ctx.accounts.withdrawal_request;
+
+    // The user must always receive something, and a fee needs a recipient
+    require!(
+        relayer_fee <= MAX_RELAYER_FEE && relayer_fee < request.amount,
+        TreasuryError::RelayerFeeTooHigh
+    );
+    require!(
+        relayer_fee == 0 || relayer_token_account.is_some(),
+        TreasuryError::InvalidRelayerAccount
+    );
+
+    request.relayer_token_account = relayer_token_account;
+    request.relayer_fee = relayer_fee;
+
+    emit!(WithdrawalRelayerSetEvent {
+        vault: ctx.accounts.vault.key(),
+        user: ctx.accounts.user.key(),
+        request_id: request.request_id,
+        relayer_token_account,
+        relayer_fee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::WithdrawalCancelledEvent;
+use crate::state::{TreasuryVault, UserLedger, WithdrawalRequest, CANCEL_REASON_EXPIRED};
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct UserCancelWithdrawal<'info> {
+    /// User who the request was created for
+    pub user: Signer<'info>,
+
+    /// CHECK: Account that paid the request's rent at creation, receives it
+    /// back. Validated through withdrawal_request.has_one = payer.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal",
+            vault.key().as_ref(),
+            user.key().as_ref(),
+            request_id.to_le_bytes().as_ref(),
+        ],
+        bump = withdrawal_request.bump,
+        has_one = user @ TreasuryError::Unauthorized,
+        has_one = vault @ TreasuryError::InvalidVaultAccount,
+        has_one = payer @ TreasuryError::InvalidPayer,
+        close = payer,
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+
+    /// User's on-chain ledger
+    #[account(
+        mut,
+        seeds = [b"user_ledger", vault.key().as_ref(), user.key().as_ref()],
+        bump = user_ledger.bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
+}
+
+pub fn handle_user_cancel_withdrawal(
+    ctx: Context<UserCancelWithdrawal>,
+    _request_id: u64,
+    reason: u8,
+) -> Result<()> {
+    // 0 marks authority cleanups of expired requests in WithdrawalCancelledEvent
+    require!(
+        reason != CANCEL_REASON_EXPIRED,
+        TreasuryError::InvalidCancelReason
+    );
+
+    let clock = Clock::get()?;
+
+    // Release the reservation made at creation and refund the rate-limit window
+    let request = &ctx.accounts.withdrawal_request;
+    let amount = request.amount;
+    let vault = &mut ctx.accounts.vault;
+    vault.release_unclaimed(
+        &request.mint,
+        amount,
+        request.created_at,
+        clock.unix_timestamp,
+    )?;
+
+    ctx.accounts
+        .user_ledger
+        .record_cancel(amount, clock.unix_timestamp)?;
+
+    emit!(WithdrawalCancelledEvent {
+        vault: vault.key(),
+        user: ctx.accounts.user.key(),
+        request_id: ctx.accounts.withdrawal_request.request_id,
+        amount,
+        total_reserved: vault.total_reserved,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // PDA is closed automatically via `close = payer` constraint
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::TreasuryError;
+use crate::events::UserDepositEvent;
+use crate::state::{TreasuryVault, UserLedger};
+
+#[derive(Accounts)]
+pub struct UserDeposit<'info> {
+    /// Any wallet depositing its own USDT
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault", vault.seed_key.as_ref(), vault.vault_id.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        constraint = !vault.paused @ TreasuryError::VaultPaused,
+    )]
+    pub vault: Account<'info, TreasuryVault>,
+
+    /// Registered mint, enabled for deposits
+    #[account(
+        constraint = vault.mint(&mint.key()).is_some() @ TreasuryError::InvalidMint,
+        constraint = vault.mint_enabled(&mint.key()) @ TreasuryError::MintDisabled,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Depositor's token account (source of deposit)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Vault's token account (destination)
+    #[account(
+        mut,
+        constraint = vault.is_token_account(&mint.key(), &vault_token_account.key())
+            @ TreasuryError::InvalidVaultAccount,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Depositor's on-chain ledger, created on first deposit
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = 8 + UserLedger::INIT_SPACE,
+        seeds = [b"user_ledger", vault.key().as_ref(), depositor.key().as_ref()],
+        bump,
+    )]
+    pub user_ledger: Account<'info, UserLedger>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_user_deposit(
+    ctx: Context<UserDeposit>,
+    amount: u64,
+    user_ref: [u8; 32],
+) -> Result<()> {
+    require!(amount > 0, TreasuryError::ZeroAmount);
+
+    // Transfer USDT from depositor to vault (depositor signs as owner)
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Update vault stats
+    let mint = ctx.accounts.mint.key();
+    let vault = &mut ctx.accounts.vault;
+    vault.record_deposit(&mint, amount, Clock::get()?.unix_timestamp)?;
+
+    let ledger = &mut ctx.accounts.user_ledger;
+    ledger.init_if_new(
+        vault.key(),
+        ctx.accounts.depositor.key(),
+        ctx.bumps.user_ledger,
+    );
+    ledger.record_deposit(amount, vault.last_deposit_at)?;
+
+    // API credits the app user identified by user_ref from this event
+    emit!(UserDepositEvent {
+        vault: vault.key(),
+        mint,
+        depositor: ctx.accounts.depositor.key(),
+        user_ref,
+        amount,
+        total_deposited: vault.total_deposited,
+        deposit_count: vault.deposit_count,
+        timestamp: vault.last_deposit_at,
+    });
+
+    Ok(())
+}
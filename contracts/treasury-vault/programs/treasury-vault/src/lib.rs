@@ -3,9 +3,12 @@ use anchor_lang::prelude::*;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod multisig;
 pub mod state;
+pub mod voucher;
 
 use instructions::*;
+use state::{BatchWithdrawal, ProposalAction};
 
 declare_id!("9brgETdzzaoxH9DcctMx7KprqpQkdDtcdQmM1y6pgDgD");
 
@@ -13,41 +16,350 @@ declare_id!("9brgETdzzaoxH9DcctMx7KprqpQkdDtcdQmM1y6pgDgD");
 pub mod treasury_vault {
     use super::*;
 
-    /// Initialize the treasury vault. Called once after deploy.
-    /// Sets authority (backend wallet) and payout_wallet (payout destination).
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        instructions::initialize::handle_initialize(ctx)
+    /// Initialize a treasury vault. Called once per vault after deploy.
+    /// Sets authority (backend wallet), payout_wallet (payout destination)
+    /// and guardian (emergency pause key). `vault_id` is part of the vault
+    /// PDA, so one authority can run separate vaults (treasury, jackpot
+    /// pool, referral reserve, insurance fund) under different ids.
+    /// `transfer_allowlist` lists the vaults `internal_transfer` may send to.
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        vault_id: u16,
+        transfer_allowlist: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::initialize::handle_initialize(ctx, vault_id, transfer_allowlist)
+    }
+
+    /// Register another mint (e.g. USDC, PYUSD) with its own vault token
+    /// account and totals. Only authority can call. In multisig mode also
+    /// consumes an approved AddMint proposal. The mint must have the same
+    /// decimals as the primary USDT mint.
+    pub fn add_mint(ctx: Context<AddMint>) -> Result<()> {
+        instructions::add_mint::handle_add_mint(ctx)
+    }
+
+    /// Enable or disable a registered mint. Only authority can call.
+    /// A disabled mint takes no deposits, withdrawal requests or vouchers;
+    /// pending requests stay claimable and payout can drain the balance.
+    pub fn set_mint_enabled(
+        ctx: Context<SetMintEnabled>,
+        mint: Pubkey,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_mint_enabled::handle_set_mint_enabled(ctx, mint, enabled)
     }
 
-    /// Deposit USDT into the vault. Only authority can call.
+    /// Deposit a registered mint into the vault. Only authority can call.
     pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         instructions::deposit::handle_deposit(ctx, amount)
     }
 
-    /// Payout USDT from vault to payout_wallet. Only authority can call.
+    /// Deposit a registered mint from any wallet's own token account into the vault.
+    /// `user_ref` identifies the app user to credit (see UserDepositEvent).
+    pub fn user_deposit(ctx: Context<UserDeposit>, amount: u64, user_ref: [u8; 32]) -> Result<()> {
+        instructions::user_deposit::handle_user_deposit(ctx, amount, user_ref)
+    }
+
+    /// Payout a registered mint from vault to payout_wallet. Only authority can call.
+    /// In multisig mode also consumes an approved Payout proposal.
     pub fn payout(ctx: Context<Payout>, amount: u64) -> Result<()> {
         instructions::payout::handle_payout(ctx, amount)
     }
 
+    /// Move a registered mint to another vault of this program, e.g. to
+    /// rebalance reserves. Only authority can call, and only to a vault on
+    /// the transfer allowlist. In multisig mode also consumes an approved
+    /// InternalTransfer proposal.
+    pub fn internal_transfer(ctx: Context<InternalTransfer>, amount: u64) -> Result<()> {
+        instructions::internal_transfer::handle_internal_transfer(ctx, amount)
+    }
+
+    /// Propose a new transfer allowlist. Only authority can call.
+    /// Replaces any pending proposal; applicable after TRANSFER_ALLOWLIST_TIMELOCK.
+    /// In multisig mode also consumes an approved ProposeTransferAllowlist proposal.
+    pub fn propose_transfer_allowlist(
+        ctx: Context<ProposeTransferAllowlist>,
+        transfer_allowlist: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_transfer_allowlist::handle_propose_transfer_allowlist(
+            ctx,
+            transfer_allowlist,
+        )
+    }
+
+    /// Apply the pending transfer allowlist once its timelock has elapsed.
+    /// Only authority can call. In multisig mode also consumes an approved
+    /// ApplyTransferAllowlist proposal naming the pending list.
+    pub fn apply_transfer_allowlist(ctx: Context<ApplyTransferAllowlist>) -> Result<()> {
+        instructions::apply_transfer_allowlist::handle_apply_transfer_allowlist(ctx)
+    }
+
+    /// Drop the pending transfer allowlist before it takes effect.
+    /// Guardian or authority can call.
+    pub fn cancel_transfer_allowlist(ctx: Context<CancelTransferAllowlist>) -> Result<()> {
+        instructions::cancel_transfer_allowlist::handle_cancel_transfer_allowlist(ctx)
+    }
+
     /// Create a withdrawal request for a user. Only authority can call.
-    /// User can then claim USDT directly by signing with their wallet.
+    /// User can then claim the request's mint directly by signing with their wallet.
+    /// `request_id` is part of the PDA seeds, so a user may hold several
+    /// pending requests as long as each uses a distinct id.
+    /// In multisig mode, amounts above `large_withdrawal_threshold`
+    /// also consume an approved CreateWithdrawal proposal.
+    /// `destination` is the wallet the user asked to be paid to (e.g. a cold
+    /// wallet or exchange deposit address); None pays the user's own wallet.
     pub fn create_withdrawal(
         ctx: Context<CreateWithdrawal>,
+        request_id: u64,
         amount: u64,
         expires_in: i64,
+        destination: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_withdrawal::handle_create_withdrawal(
+            ctx,
+            request_id,
+            amount,
+            expires_in,
+            destination,
+        )
+    }
+
+    /// Create up to MAX_WITHDRAWAL_BATCH requests in one transaction.
+    /// Each entry's withdrawal request and user ledger PDAs are passed as
+    /// remaining accounts, in entry order. The whole batch must fit the
+    /// available balance and rate limits, or nothing is created; in
+    /// multisig mode large amounts still go through create_withdrawal.
+    pub fn create_withdrawals_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateWithdrawalsBatch<'info>>,
+        withdrawals: Vec<BatchWithdrawal>,
     ) -> Result<()> {
-        instructions::create_withdrawal::handle_create_withdrawal(ctx, amount, expires_in)
+        instructions::create_withdrawals_batch::handle_create_withdrawals_batch(ctx, withdrawals)
     }
 
     /// Claim a pending withdrawal. User signs with their wallet.
-    /// USDT goes directly from vault to user's token account.
-    pub fn claim_withdrawal(ctx: Context<ClaimWithdrawal>) -> Result<()> {
-        instructions::claim_withdrawal::handle_claim_withdrawal(ctx)
+    /// Funds go directly from vault to the token account of the request's
+    /// destination (the user's own wallet unless one was set at creation).
+    pub fn claim_withdrawal(ctx: Context<ClaimWithdrawal>, request_id: u64) -> Result<()> {
+        instructions::claim_withdrawal::handle_claim_withdrawal(ctx, request_id)
     }
 
     /// Cancel an expired withdrawal request. Only authority can call.
     /// Cleans up the PDA and returns rent to authority.
-    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
-        instructions::cancel_withdrawal::handle_cancel_withdrawal(ctx)
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>, request_id: u64) -> Result<()> {
+        instructions::cancel_withdrawal::handle_cancel_withdrawal(ctx, request_id)
+    }
+
+    /// Cancel many expired requests at once. Only authority can call.
+    /// Each request and its user ledger are passed as remaining accounts;
+    /// requests that are still claimable or already closed are skipped.
+    pub fn cancel_expired_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelExpiredBatch<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_expired_batch::handle_cancel_expired_batch(ctx)
+    }
+
+    /// Cancel an expired request without the authority. Anyone can call once
+    /// `vault.crank_grace_period` has passed after expiry; the caller earns
+    /// `vault.crank_bounty` lamports and the rest of the rent goes to authority.
+    pub fn crank_cancel_expired(ctx: Context<CrankCancelExpired>, request_id: u64) -> Result<()> {
+        instructions::crank_cancel_expired::handle_crank_cancel_expired(ctx, request_id)
+    }
+
+    /// Claim with a relayer paying the transaction fee and the user's ATA rent,
+    /// so wallets without SOL can withdraw. The user still signs. If the
+    /// request carries a relayer fee it goes to the recorded relayer token
+    /// account; `max_fee` bounds what the user agrees to pay.
+    pub fn claim_withdrawal_relayed(
+        ctx: Context<ClaimWithdrawalRelayed>,
+        request_id: u64,
+        max_fee: u64,
+    ) -> Result<()> {
+        instructions::claim_withdrawal_relayed::handle_claim_withdrawal_relayed(
+            ctx, request_id, max_fee,
+        )
+    }
+
+    /// Record the relayer token account and fee for sponsored claims of a
+    /// pending request. Only authority can call. The fee is capped at
+    /// MAX_RELAYER_FEE and must stay below the request amount.
+    pub fn set_withdrawal_relayer(
+        ctx: Context<SetWithdrawalRelayer>,
+        request_id: u64,
+        relayer_token_account: Option<Pubkey>,
+        relayer_fee: u64,
+    ) -> Result<()> {
+        instructions::set_withdrawal_relayer::handle_set_withdrawal_relayer(
+            ctx,
+            request_id,
+            relayer_token_account,
+            relayer_fee,
+        )
+    }
+
+    /// Claim with a backend-signed voucher instead of a withdrawal request.
+    /// The transaction must carry an Ed25519 verification of the voucher by
+    /// `vault.voucher_signer` right before this instruction. Each nonce is
+    /// usable once per user; the user pays for the whole transaction.
+    pub fn claim_with_voucher(
+        ctx: Context<ClaimWithVoucher>,
+        amount: u64,
+        nonce: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::claim_with_voucher::handle_claim_with_voucher(ctx, amount, nonce, expires_at)
+    }
+
+    /// Cancel your own pending withdrawal request. Signed by the request's user,
+    /// works at any time. Rent goes back to whoever paid it at creation;
+    /// `reason` is an app-defined code (non-zero) recorded in the event.
+    pub fn user_cancel_withdrawal(
+        ctx: Context<UserCancelWithdrawal>,
+        request_id: u64,
+        reason: u8,
+    ) -> Result<()> {
+        instructions::user_cancel_withdrawal::handle_user_cancel_withdrawal(ctx, request_id, reason)
+    }
+
+    /// Push a pending request's deadline to `now + expires_in`. Only authority
+    /// can call. The new deadline must be later than the current one; expired
+    /// requests that were not cancelled yet can be revived this way.
+    pub fn extend_withdrawal(
+        ctx: Context<ExtendWithdrawal>,
+        request_id: u64,
+        expires_in: i64,
+    ) -> Result<()> {
+        instructions::extend_withdrawal::handle_extend_withdrawal(ctx, request_id, expires_in)
+    }
+
+    /// Lower a pending request's amount. Only authority can call.
+    /// The difference is released from `total_reserved`; raising the amount
+    /// requires a new request.
+    pub fn amend_withdrawal(
+        ctx: Context<AmendWithdrawal>,
+        request_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::amend_withdrawal::handle_amend_withdrawal(ctx, request_id, amount)
+    }
+
+    /// Propose a new authority. Only current authority can call.
+    /// Takes effect once the proposed key signs `accept_authority`.
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::propose_authority::handle_propose_authority(ctx, new_authority)
+    }
+
+    /// Accept a pending authority proposal. Signed by the proposed authority.
+    /// Vault address is unchanged because its PDA is seeded by `seed_key`
+    /// and `vault_id`.
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handle_accept_authority(ctx)
+    }
+
+    /// Set the multisig signer set (N keys, threshold M). Only authority can call.
+    /// Enabling is authority-only; once on, changes need an approved proposal.
+    /// An empty signer set turns multisig mode off.
+    pub fn configure_multisig(
+        ctx: Context<ConfigureMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        large_withdrawal_threshold: u64,
+    ) -> Result<()> {
+        instructions::configure_multisig::handle_configure_multisig(
+            ctx,
+            signers,
+            threshold,
+            large_withdrawal_threshold,
+        )
+    }
+
+    /// Propose a sensitive action. Only multisig signers can call.
+    /// The proposer's approval is recorded automatically.
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        instructions::create_proposal::handle_create_proposal(ctx, action)
+    }
+
+    /// Approve a pending proposal. Only multisig signers can call.
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal::handle_approve_proposal(ctx)
+    }
+
+    /// Close an executed proposal, or one left stale by a signer set change,
+    /// returning its rent to the proposer. Anyone can call.
+    pub fn close_proposal(ctx: Context<CloseProposal>) -> Result<()> {
+        instructions::close_proposal::handle_close_proposal(ctx)
+    }
+
+    /// Set payout / withdrawal rate limits (0 = unlimited). Only authority can call.
+    /// In multisig mode also consumes an approved SetRateLimits proposal.
+    pub fn set_rate_limits(
+        ctx: Context<SetRateLimits>,
+        max_payout_per_tx: u64,
+        max_outflow_per_window: u64,
+        max_withdrawal_amount: u64,
+    ) -> Result<()> {
+        instructions::set_rate_limits::handle_set_rate_limits(
+            ctx,
+            max_payout_per_tx,
+            max_outflow_per_window,
+            max_withdrawal_amount,
+        )
+    }
+
+    /// Pause the vault: blocks deposit, payout and create_withdrawal.
+    /// Pending requests stay claimable. Only guardian can call.
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        instructions::set_paused::handle_pause(ctx)
+    }
+
+    /// Resume normal operation. Only guardian can call.
+    pub fn unpause(ctx: Context<SetPaused>) -> Result<()> {
+        instructions::set_paused::handle_unpause(ctx)
+    }
+
+    /// Set the grace period after expiry before anyone may crank a request
+    /// closed, and the lamport bounty they earn for it. Only authority can call.
+    pub fn set_crank_config(
+        ctx: Context<SetCrankConfig>,
+        grace_period: i64,
+        bounty: u64,
+    ) -> Result<()> {
+        instructions::set_crank_config::handle_set_crank_config(ctx, grace_period, bounty)
+    }
+
+    /// Set the key that signs claim vouchers (Pubkey::default() disables
+    /// voucher claims). Only authority can call. In multisig mode also
+    /// consumes an approved SetVoucherSigner proposal.
+    pub fn set_voucher_signer(
+        ctx: Context<SetVoucherSigner>,
+        new_voucher_signer: Pubkey,
+    ) -> Result<()> {
+        instructions::set_voucher_signer::handle_set_voucher_signer(ctx, new_voucher_signer)
+    }
+
+    /// Hand the guardian role to another key. Only guardian can call.
+    pub fn set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian::handle_set_guardian(ctx, new_guardian)
+    }
+
+    /// Commit a proof-of-liabilities snapshot. Only authority can call.
+    /// Stores a Merkle root over (user, balance) leaves and their total so
+    /// users can prove their in-game balance is included and backed.
+    /// Token accounts of the vault's other registered mints follow as
+    /// remaining accounts, in registry order; the snapshot records each
+    /// mint's balance and reservations, and their unreserved total.
+    pub fn commit_liabilities<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CommitLiabilities<'info>>,
+        epoch: u64,
+        merkle_root: [u8; 32],
+        total_liabilities: u64,
+        leaf_count: u64,
+    ) -> Result<()> {
+        instructions::commit_liabilities::handle_commit_liabilities(
+            ctx,
+            epoch,
+            merkle_root,
+            total_liabilities,
+            leaf_count,
+        )
     }
 }
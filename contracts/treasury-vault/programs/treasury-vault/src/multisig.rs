@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::TreasuryError;
+use crate::events::ProposalExecutedEvent;
+use crate::state::{Proposal, ProposalAction, TreasuryVault, MAX_MULTISIG_SIGNERS};
+
+/// Validates a signer set before it is stored on the vault.
+/// An empty set (with threshold 0) turns multisig mode off.
+pub fn validate_multisig_config(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        signers.len() <= MAX_MULTISIG_SIGNERS,
+        TreasuryError::InvalidMultisigConfig
+    );
+
+    for (i, signer) in signers.iter().enumerate() {
+        require!(
+            *signer != Pubkey::default() && !signers[..i].contains(signer),
+            TreasuryError::InvalidMultisigConfig
+        );
+    }
+
+    if signers.is_empty() {
+        require!(threshold == 0, TreasuryError::InvalidMultisigConfig);
+    } else {
+        require!(
+            threshold >= 1 && threshold as usize <= signers.len(),
+            TreasuryError::InvalidMultisigConfig
+        );
+    }
+
+    Ok(())
+}
+
+/// Consumes an approved proposal for `action`.
+/// Sensitive instructions call this in multisig mode instead of trusting the authority alone.
+pub fn execute_proposal(
+    vault: &Account<TreasuryVault>,
+    proposal: Option<&mut Account<Proposal>>,
+    action: &ProposalAction,
+) -> Result<()> {
+    let proposal = proposal.ok_or(TreasuryError::MultisigRequired)?;
+
+    require_keys_eq!(proposal.vault, vault.key(), TreasuryError::InvalidProposal);
+    require!(
+        proposal.multisig_version == vault.multisig_version,
+        TreasuryError::StaleProposal
+    );
+    require!(!proposal.executed, TreasuryError::ProposalAlreadyExecuted);
+    require!(proposal.action == *action, TreasuryError::InvalidProposal);
+    require!(
+        proposal.approval_count >= vault.multisig_threshold,
+        TreasuryError::ProposalNotApproved
+    );
+
+    proposal.executed = true;
+
+    emit!(ProposalExecutedEvent {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        approval_count: proposal.approval_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,54 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::TreasuryError;
+
+/// Maximum number of keys in the vault's multisig signer set
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Maximum number of mints in a vault's registry, the primary USDT mint included
+pub const MAX_VAULT_MINTS: usize = 8;
+
+/// Length of the rolling outflow rate-limit window (24h)
+pub const RATE_LIMIT_WINDOW: i64 = 24 * 60 * 60;
+
+/// Width of one outflow bucket (1h)
+pub const OUTFLOW_BUCKET_SECONDS: i64 = 60 * 60;
+
+/// Hourly outflow buckets kept: the current hour and the 24 before it, so
+/// every RATE_LIMIT_WINDOW span falls inside the buckets summed
+pub const OUTFLOW_BUCKETS: usize = (RATE_LIMIT_WINDOW / OUTFLOW_BUCKET_SECONDS) as usize + 1;
+
+/// `WithdrawalCancelledEvent::reason` of an expired request cancelled by the authority
+pub const CANCEL_REASON_EXPIRED: u8 = 0;
+
+/// Cap on the USDT fee a relayer may take from a sponsored claim (1 USDT, raw units)
+pub const MAX_RELAYER_FEE: u64 = 1_000_000;
+
+/// Voucher nonces tracked by one VoucherNonces page
+pub const VOUCHER_NONCES_PER_PAGE: u64 = 1024;
+
+/// Time after `expires_at` before anyone may crank a request closed, so the
+/// backend's own cleanup runs first
+pub const DEFAULT_CRANK_GRACE_PERIOD: i64 = 24 * 60 * 60;
+
+/// Lamports paid to whoever cranks an expired request closed (0.0001 SOL)
+pub const DEFAULT_CRANK_BOUNTY: u64 = 100_000;
+
+/// Cap on the crank bounty, under half a withdrawal request's rent so most
+/// of it always returns to the authority
+pub const MAX_CRANK_BOUNTY: u64 = 1_000_000;
+
+/// Most requests `create_withdrawals_batch` takes. A full batch for distinct
+/// users with destinations is ~1090 bytes; a sixth entry would exceed the
+/// 1232-byte transaction limit.
+pub const MAX_WITHDRAWAL_BATCH: usize = 5;
+
+/// Maximum number of destination vaults on a vault's transfer allowlist
+pub const MAX_TRANSFER_ALLOWLIST: usize = 8;
+
+/// Delay between proposing a transfer allowlist change and applying it (48h)
+pub const TRANSFER_ALLOWLIST_TIMELOCK: i64 = 48 * 60 * 60;
+
 #[account]
 #[derive(InitSpace)]
 pub struct WithdrawalRequest {
@@ -9,6 +58,10 @@ pub struct WithdrawalRequest {
     /// User who can claim this withdrawal
     pub user: Pubkey,
 
+    /// Backend-assigned request ID, part of the PDA seeds so a user can
+    /// hold several pending withdrawals at once
+    pub request_id: u64,
+
     /// Amount in raw USDT units (6 decimals)
     pub amount: u64,
 
@@ -18,6 +71,24 @@ pub struct WithdrawalRequest {
     /// Unix timestamp after which claim is no longer possible
     pub expires_at: i64,
 
+    /// Registered mint the claim is paid in
+    pub mint: Pubkey,
+
+    /// Wallet whose token account receives the claim, approved by the user
+    /// when the request was created. None = the user's own wallet.
+    pub destination: Option<Pubkey>,
+
+    /// Token account that receives `relayer_fee` when the claim is sponsored
+    /// through `claim_withdrawal_relayed`. None = sponsored claims are free.
+    pub relayer_token_account: Option<Pubkey>,
+
+    /// USDT deducted from the claim for the relayer (raw units, <= MAX_RELAYER_FEE)
+    pub relayer_fee: u64,
+
+    /// Account that paid the request's rent at creation; a user cancel
+    /// returns the rent here
+    pub payer: Pubkey,
+
     /// PDA bump seed
     pub bump: u8,
 }
@@ -28,21 +99,52 @@ pub struct TreasuryVault {
     /// Authority (backend wallet) — only this key can call deposit/payout
     pub authority: Pubkey,
 
+    /// Key used in the vault PDA seeds (the authority at initialize).
+    /// Never changes, so rotating `authority` keeps the vault address.
+    pub seed_key: Pubkey,
+
+    /// Second PDA seed (little-endian), chosen at initialize, so one
+    /// `seed_key` can own several vaults: treasury, jackpot pool, reserves
+    pub vault_id: u16,
+
+    /// Authority proposed via `propose_authority`, waiting for `accept_authority`
+    pub pending_authority: Option<Pubkey>,
+
+    /// Guardian — separate key that can pause/unpause the vault
+    pub guardian: Pubkey,
+
+    /// When set, deposit/payout/create_withdrawal are blocked.
+    /// Claims of already-created requests keep working.
+    pub paused: bool,
+
     /// Payout wallet — the only allowed recipient of payout instructions
     pub payout_wallet: Pubkey,
 
-    /// USDT SPL mint address
+    /// USDT SPL mint address, the primary mint (`mints[0]`)
     pub usdt_mint: Pubkey,
 
-    /// Vault's token account (ATA owned by this PDA)
+    /// Vault's token account for `usdt_mint` (ATA owned by this PDA)
     pub vault_token_account: Pubkey,
 
-    /// Total USDT deposited (raw units, 6 decimals)
+    /// Decimals shared by every registered mint, so amounts, totals and
+    /// rate limits are in the same raw units whatever the mint
+    pub mint_decimals: u8,
+
+    /// Registry of mints the vault custodies, each with its own token
+    /// account and totals. The primary mint is registered at initialize.
+    #[max_len(MAX_VAULT_MINTS)]
+    pub mints: Vec<VaultMint>,
+
+    /// Total deposited across all mints (raw units, 6 decimals)
     pub total_deposited: u64,
 
-    /// Total USDT paid out (raw units, 6 decimals)
+    /// Total paid out across all mints (raw units, 6 decimals)
     pub total_paid_out: u64,
 
+    /// Reserved for pending withdrawal requests across all mints (raw units,
+    /// 6 decimals). Each mint's share is covered by that mint's balance.
+    pub total_reserved: u64,
+
     /// Number of deposit transactions (u32 = up to 4B ops)
     pub deposit_count: u32,
 
@@ -55,6 +157,644 @@ pub struct TreasuryVault {
     /// Last payout unix timestamp
     pub last_payout_at: i64,
 
+    /// Multisig signer set. Empty = multisig mode off, authority acts alone
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub multisig_signers: Vec<Pubkey>,
+
+    /// Approvals (M) required to execute a proposal
+    pub multisig_threshold: u8,
+
+    /// Bumped on every signer set change; invalidates older proposals
+    pub multisig_version: u32,
+
+    /// In multisig mode, withdrawal requests above this amount need a proposal
+    pub large_withdrawal_threshold: u64,
+
+    /// Number of proposals created (used as the next proposal id)
+    pub proposal_count: u64,
+
+    /// Max amount of a single payout (0 = unlimited)
+    pub max_payout_per_tx: u64,
+
+    /// Max payout + withdrawal-request volume per rolling 24h window (0 = unlimited)
+    pub max_outflow_per_window: u64,
+
+    /// Max amount of a single withdrawal request (0 = unlimited)
+    pub max_withdrawal_amount: u64,
+
+    /// Outflow per hour, indexed by `hour % OUTFLOW_BUCKETS`. Only the
+    /// buckets for `outflow_hour` and the hours before it are live; the rest
+    /// are cleared as time moves on.
+    pub outflow_buckets: [u64; OUTFLOW_BUCKETS],
+
+    /// Hour (unix timestamp / OUTFLOW_BUCKET_SECONDS) of the newest bucket
+    pub outflow_hour: i64,
+
+    /// Epoch of the latest LiabilitySnapshot (0 = none committed yet)
+    pub liability_epoch: u64,
+
+    /// Backend key whose Ed25519 vouchers `claim_with_voucher` accepts.
+    /// Pubkey::default() = voucher claims disabled.
+    pub voucher_signer: Pubkey,
+
+    /// Seconds after `expires_at` before `crank_cancel_expired` is open to anyone
+    pub crank_grace_period: i64,
+
+    /// Lamports from a cranked request's rent paid to the cranker
+    pub crank_bounty: u64,
+
+    /// Vaults of this program that `internal_transfer` may send to. Set at
+    /// initialize, then changed only through the timelocked pending list.
+    #[max_len(MAX_TRANSFER_ALLOWLIST)]
+    pub transfer_allowlist: Vec<Pubkey>,
+
+    /// Allowlist proposed by `propose_transfer_allowlist`
+    #[max_len(MAX_TRANSFER_ALLOWLIST)]
+    pub pending_transfer_allowlist: Vec<Pubkey>,
+
+    /// When the pending allowlist may be applied (0 = no change pending)
+    pub transfer_allowlist_effective_at: i64,
+
     /// PDA bump seed
     pub bump: u8,
 }
+
+impl TreasuryVault {
+    /// Registry entry of `mint`, if the vault custodies it
+    pub fn mint(&self, mint: &Pubkey) -> Option<&VaultMint> {
+        self.mints.iter().find(|entry| entry.mint == *mint)
+    }
+
+    /// Mutable registry entry of `mint`; unregistered mints are an error
+    pub fn mint_mut(&mut self, mint: &Pubkey) -> Result<&mut VaultMint> {
+        self.mints
+            .iter_mut()
+            .find(|entry| entry.mint == *mint)
+            .ok_or_else(|| TreasuryError::InvalidMint.into())
+    }
+
+    /// True when `token_account` is the vault's registered account for `mint`
+    pub fn is_token_account(&self, mint: &Pubkey, token_account: &Pubkey) -> bool {
+        self.mint(mint)
+            .is_some_and(|entry| entry.token_account == *token_account)
+    }
+
+    /// True when `internal_transfer` may send to `destination_vault`
+    pub fn transfer_allowed(&self, destination_vault: &Pubkey) -> bool {
+        self.transfer_allowlist.contains(destination_vault)
+    }
+
+    /// True when `mint` is registered and takes deposits and new requests
+    pub fn mint_enabled(&self, mint: &Pubkey) -> bool {
+        self.mint(mint).is_some_and(|entry| entry.enabled)
+    }
+
+    /// Part of `mint`'s token balance not promised to pending withdrawal requests
+    pub fn available_balance(&self, mint: &Pubkey, token_balance: u64) -> u64 {
+        let reserved = self.mint(mint).map_or(0, |entry| entry.total_reserved);
+        token_balance.saturating_sub(reserved)
+    }
+
+    /// Stats for `amount` of `mint` deposited
+    pub fn record_deposit(&mut self, mint: &Pubkey, amount: u64, now: i64) -> Result<()> {
+        let entry = self.mint_mut(mint)?;
+        entry.total_deposited = entry
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        entry.deposit_count = entry
+            .deposit_count
+            .checked_add(1)
+            .ok_or(TreasuryError::Overflow)?;
+
+        self.total_deposited = self
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        self.deposit_count = self
+            .deposit_count
+            .checked_add(1)
+            .ok_or(TreasuryError::Overflow)?;
+        self.last_deposit_at = now;
+        Ok(())
+    }
+
+    /// Reserves `amount` of `mint` for a new withdrawal request
+    pub fn reserve(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let entry = self.mint_mut(mint)?;
+        entry.total_reserved = entry
+            .total_reserved
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        self.total_reserved = self
+            .total_reserved
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        Ok(())
+    }
+
+    /// Releases a reservation made by `reserve`
+    pub fn release(&mut self, mint: &Pubkey, amount: u64) -> Result<()> {
+        let entry = self.mint_mut(mint)?;
+        entry.total_reserved = entry
+            .total_reserved
+            .checked_sub(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        self.total_reserved = self
+            .total_reserved
+            .checked_sub(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        Ok(())
+    }
+
+    /// Releases `amount` of a withdrawal request created at `created_at` that
+    /// will not be claimed (cancelled or amended down), refunding it to the
+    /// outflow window
+    pub fn release_unclaimed(
+        &mut self,
+        mint: &Pubkey,
+        amount: u64,
+        created_at: i64,
+        now: i64,
+    ) -> Result<()> {
+        self.release(mint, amount)?;
+        self.refund_outflow(amount, created_at, now)
+    }
+
+    /// Stats for a claimed withdrawal request: `amount` leaves the vault and
+    /// its reservation is released
+    pub fn record_claim(&mut self, mint: &Pubkey, amount: u64, now: i64) -> Result<()> {
+        self.release(mint, amount)?;
+        self.record_paid_out(mint, amount, now)
+    }
+
+    /// Stats for `amount` of `mint` leaving the vault
+    pub fn record_paid_out(&mut self, mint: &Pubkey, amount: u64, now: i64) -> Result<()> {
+        let entry = self.mint_mut(mint)?;
+        entry.total_paid_out = entry
+            .total_paid_out
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        entry.payout_count = entry
+            .payout_count
+            .checked_add(1)
+            .ok_or(TreasuryError::Overflow)?;
+
+        self.total_paid_out = self
+            .total_paid_out
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        self.payout_count = self
+            .payout_count
+            .checked_add(1)
+            .ok_or(TreasuryError::Overflow)?;
+        self.last_payout_at = now;
+        Ok(())
+    }
+
+    /// Counts `amount` against the rolling outflow window, failing if it
+    /// would exceed `max_outflow_per_window`. The check sums the current hour
+    /// and the 24 before it, so no 24h span ever sees more than the max; the
+    /// oldest hour drops out between 24h and 25h after it was spent.
+    pub fn record_outflow(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.roll_outflow(now);
+
+        let outflow = self
+            .outflow_buckets
+            .iter()
+            .try_fold(amount, |sum, bucket| sum.checked_add(*bucket))
+            .ok_or(TreasuryError::Overflow)?;
+
+        if self.max_outflow_per_window > 0 {
+            require!(
+                outflow <= self.max_outflow_per_window,
+                TreasuryError::RateLimitExceeded
+            );
+        }
+
+        let bucket = &mut self.outflow_buckets[bucket_index(self.outflow_hour)];
+        *bucket = bucket.checked_add(amount).ok_or(TreasuryError::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Takes `amount` counted by `record_outflow` at `created_at` back out of
+    /// its hour. Once that hour has left the window there is nothing to refund.
+    pub fn refund_outflow(&mut self, amount: u64, created_at: i64, now: i64) -> Result<()> {
+        self.roll_outflow(now);
+
+        let hour = created_at.div_euclid(OUTFLOW_BUCKET_SECONDS);
+        if hour <= self.outflow_hour && self.outflow_hour - hour < OUTFLOW_BUCKETS as i64 {
+            let bucket = &mut self.outflow_buckets[bucket_index(hour)];
+            *bucket = bucket.saturating_sub(amount);
+        }
+
+        Ok(())
+    }
+
+    /// Moves the newest bucket up to the hour of `now`, clearing the buckets
+    /// of the hours skipped over
+    fn roll_outflow(&mut self, now: i64) {
+        let hour = now.div_euclid(OUTFLOW_BUCKET_SECONDS);
+        if hour <= self.outflow_hour {
+            return;
+        }
+        let elapsed = (hour - self.outflow_hour).min(OUTFLOW_BUCKETS as i64);
+        for skipped in (hour - elapsed + 1)..=hour {
+            self.outflow_buckets[bucket_index(skipped)] = 0;
+        }
+        self.outflow_hour = hour;
+    }
+
+    pub fn vouchers_enabled(&self) -> bool {
+        self.voucher_signer != Pubkey::default()
+    }
+
+    pub fn multisig_enabled(&self) -> bool {
+        !self.multisig_signers.is_empty()
+    }
+
+    pub fn multisig_signer_index(&self, key: &Pubkey) -> Option<usize> {
+        self.multisig_signers
+            .iter()
+            .position(|signer| signer == key)
+    }
+
+    /// Validates a transfer allowlist for the vault at `vault`: at most
+    /// MAX_TRANSFER_ALLOWLIST distinct vaults, never the vault itself
+    pub fn validate_transfer_allowlist(vault: &Pubkey, allowlist: &[Pubkey]) -> Result<()> {
+        require!(
+            allowlist.len() <= MAX_TRANSFER_ALLOWLIST,
+            TreasuryError::InvalidTransferAllowlist
+        );
+        for (i, destination) in allowlist.iter().enumerate() {
+            require!(
+                *destination != Pubkey::default()
+                    && destination != vault
+                    && !allowlist[..i].contains(destination),
+                TreasuryError::InvalidTransferAllowlist
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Slot of `hour` in `outflow_buckets`
+fn bucket_index(hour: i64) -> usize {
+    hour.rem_euclid(OUTFLOW_BUCKETS as i64) as usize
+}
+
+/// One mint in a vault's registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct VaultMint {
+    pub mint: Pubkey,
+
+    /// Vault's token account for `mint` (ATA owned by the vault PDA)
+    pub token_account: Pubkey,
+
+    /// Disabled mints take no deposits, withdrawal requests or vouchers.
+    /// Pending requests still claim and payout can still drain the balance.
+    pub enabled: bool,
+
+    /// Total deposited in this mint (raw units)
+    pub total_deposited: u64,
+
+    /// Total paid out in this mint (raw units)
+    pub total_paid_out: u64,
+
+    /// Reserved in this mint for pending withdrawal requests (raw units)
+    pub total_reserved: u64,
+
+    pub deposit_count: u32,
+
+    pub payout_count: u32,
+}
+
+impl VaultMint {
+    pub fn new(mint: Pubkey, token_account: Pubkey) -> Self {
+        Self {
+            mint,
+            token_account,
+            enabled: true,
+            total_deposited: 0,
+            total_paid_out: 0,
+            total_reserved: 0,
+            deposit_count: 0,
+            payout_count: 0,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserLedger {
+    /// Which vault this ledger tracks
+    pub vault: Pubkey,
+
+    /// Wallet whose activity is recorded
+    pub user: Pubkey,
+
+    /// Lifetime USDT deposited via user_deposit (raw units, 6 decimals)
+    pub total_deposited: u64,
+
+    /// Lifetime USDT claimed from withdrawal requests (raw units, 6 decimals)
+    pub total_claimed: u64,
+
+    /// USDT in currently pending withdrawal requests (raw units, 6 decimals)
+    pub pending_amount: u64,
+
+    /// Number of currently pending withdrawal requests
+    pub pending_count: u32,
+
+    /// Number of user_deposit transactions
+    pub deposit_count: u32,
+
+    /// Number of claimed withdrawal requests
+    pub claim_count: u32,
+
+    /// Number of cancelled withdrawal requests
+    pub cancel_count: u32,
+
+    /// Last deposit unix timestamp
+    pub last_deposit_at: i64,
+
+    /// Last claim unix timestamp
+    pub last_claim_at: i64,
+
+    /// Unix timestamp of the last change to this ledger
+    pub last_activity_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UserLedger {
+    /// Fills identity fields on first use (accounts are `init_if_needed`)
+    pub fn init_if_new(&mut self, vault: Pubkey, user: Pubkey, bump: u8) {
+        if self.vault == Pubkey::default() {
+            self.vault = vault;
+            self.user = user;
+            self.bump = bump;
+        }
+    }
+
+    pub fn record_deposit(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.total_deposited = self
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        self.deposit_count = self
+            .deposit_count
+            .checked_add(1)
+            .ok_or(TreasuryError::Overflow)?;
+        self.last_deposit_at = now;
+        self.last_activity_at = now;
+        Ok(())
+    }
+
+    pub fn record_request(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.pending_amount = self
+            .pending_amount
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        self.pending_count = self
+            .pending_count
+            .checked_add(1)
+            .ok_or(TreasuryError::Overflow)?;
+        self.last_activity_at = now;
+        Ok(())
+    }
+
+    pub fn record_claim(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.release_pending(amount)?;
+        self.record_claimed(amount, now)
+    }
+
+    /// A claim that never had a pending request (voucher claims)
+    pub fn record_claimed(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.total_claimed = self
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        self.claim_count = self
+            .claim_count
+            .checked_add(1)
+            .ok_or(TreasuryError::Overflow)?;
+        self.last_claim_at = now;
+        self.last_activity_at = now;
+        Ok(())
+    }
+
+    pub fn record_cancel(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.release_pending(amount)?;
+        self.cancel_count = self
+            .cancel_count
+            .checked_add(1)
+            .ok_or(TreasuryError::Overflow)?;
+        self.last_activity_at = now;
+        Ok(())
+    }
+
+    /// A pending request was lowered by `released`; it stays pending
+    pub fn record_amend(&mut self, released: u64, now: i64) -> Result<()> {
+        self.pending_amount = self
+            .pending_amount
+            .checked_sub(released)
+            .ok_or(TreasuryError::Overflow)?;
+        self.last_activity_at = now;
+        Ok(())
+    }
+
+    fn release_pending(&mut self, amount: u64) -> Result<()> {
+        self.pending_amount = self
+            .pending_amount
+            .checked_sub(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        self.pending_count = self
+            .pending_count
+            .checked_sub(1)
+            .ok_or(TreasuryError::Overflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct LiabilitySnapshot {
+    /// Which vault this snapshot covers
+    pub vault: Pubkey,
+
+    /// Snapshot epoch, strictly increasing per vault, part of the PDA seeds
+    pub epoch: u64,
+
+    /// Merkle-sum root over (user, balance) leaves of in-game balances, also
+    /// committing to `total_liabilities` and `leaf_count` (see treasury-vault-proof)
+    pub merkle_root: [u8; 32],
+
+    /// Sum of all leaf balances (raw units, 6 decimals)
+    pub total_liabilities: u64,
+
+    /// Number of leaves in the tree
+    pub leaf_count: u64,
+
+    /// Vault token balance across all registered mints at commit time, less
+    /// what is reserved for pending withdrawal requests, for a quick
+    /// solvency comparison
+    pub vault_balance: u64,
+
+    /// Balance and reservations of each registered mint, in registry order,
+    /// so a verifier can leave out a mint it does not accept as backing
+    #[max_len(MAX_VAULT_MINTS)]
+    pub mint_balances: Vec<MintBalance>,
+
+    /// Unix timestamp when snapshot was committed
+    pub committed_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// One mint's share of a LiabilitySnapshot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct MintBalance {
+    pub mint: Pubkey,
+
+    /// Vault token balance in `mint` at commit time (raw units)
+    pub balance: u64,
+
+    /// Part of `balance` reserved for pending withdrawal requests
+    pub reserved: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    /// Which vault this proposal belongs to
+    pub vault: Pubkey,
+
+    /// Sequential id, part of the PDA seeds
+    pub proposal_id: u64,
+
+    /// Signer that created the proposal
+    pub proposer: Pubkey,
+
+    /// Action that becomes executable once the threshold is reached
+    pub action: ProposalAction,
+
+    /// `multisig_version` of the vault at creation time
+    pub multisig_version: u32,
+
+    /// Bitmask of approvals, bit i = vault.multisig_signers[i]
+    pub approvals: u16,
+
+    /// Number of set bits in `approvals`
+    pub approval_count: u8,
+
+    /// Set once the action has been executed; a proposal runs at most once
+    pub executed: bool,
+
+    /// Unix timestamp when proposal was created
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// One request created by `create_withdrawals_batch`, same meaning as the
+/// `create_withdrawal` arguments
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BatchWithdrawal {
+    pub user: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub expires_in: i64,
+    pub destination: Option<Pubkey>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProposalAction {
+    Payout {
+        mint: Pubkey,
+        amount: u64,
+    },
+    CreateWithdrawal {
+        mint: Pubkey,
+        user: Pubkey,
+        request_id: u64,
+        amount: u64,
+        expires_in: i64,
+        destination: Option<Pubkey>,
+    },
+    ConfigureMultisig {
+        #[max_len(MAX_MULTISIG_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        large_withdrawal_threshold: u64,
+    },
+    SetRateLimits {
+        max_payout_per_tx: u64,
+        max_outflow_per_window: u64,
+        max_withdrawal_amount: u64,
+    },
+    InternalTransfer {
+        mint: Pubkey,
+        destination_vault: Pubkey,
+        amount: u64,
+    },
+    SetVoucherSigner {
+        voucher_signer: Pubkey,
+    },
+    ProposeTransferAllowlist {
+        #[max_len(MAX_TRANSFER_ALLOWLIST)]
+        transfer_allowlist: Vec<Pubkey>,
+    },
+    /// Names the pending list, so approvals cover what actually gets installed
+    ApplyTransferAllowlist {
+        #[max_len(MAX_TRANSFER_ALLOWLIST)]
+        transfer_allowlist: Vec<Pubkey>,
+    },
+    AddMint {
+        mint: Pubkey,
+    },
+}
+
+/// Used-nonce bitmap for one user's vouchers, covering nonces
+/// `page * VOUCHER_NONCES_PER_PAGE ..` the next VOUCHER_NONCES_PER_PAGE
+#[account]
+#[derive(InitSpace)]
+pub struct VoucherNonces {
+    pub vault: Pubkey,
+
+    pub user: Pubkey,
+
+    /// nonce / VOUCHER_NONCES_PER_PAGE
+    pub page: u64,
+
+    /// Bit `nonce % VOUCHER_NONCES_PER_PAGE` is set once that nonce is spent
+    pub bitmap: [u8; (VOUCHER_NONCES_PER_PAGE / 8) as usize],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VoucherNonces {
+    /// Fills identity fields on first use (accounts are `init_if_needed`)
+    pub fn init_if_new(&mut self, vault: Pubkey, user: Pubkey, page: u64, bump: u8) {
+        if self.vault == Pubkey::default() {
+            self.vault = vault;
+            self.user = user;
+            self.page = page;
+            self.bump = bump;
+        }
+    }
+
+    /// Marks `nonce` spent, failing if it already was
+    pub fn consume(&mut self, nonce: u64) -> Result<()> {
+        let bit = nonce % VOUCHER_NONCES_PER_PAGE;
+        let byte = &mut self.bitmap[(bit / 8) as usize];
+        let mask = 1u8 << (bit % 8);
+        require!(*byte & mask == 0, TreasuryError::VoucherAlreadyUsed);
+        *byte |= mask;
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::errors::TreasuryError;
+
+/// Prefix of every voucher message, so a signature made for anything else
+/// can never pass as a voucher
+pub const VOUCHER_DOMAIN: &[u8] = b"treasury_vault:voucher";
+
+/// Ed25519 precompile data: [num_signatures, padding, 7 x u16 offsets, ...]
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+/// `*_instruction_index` value meaning "inside the Ed25519 instruction itself"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Off-chain withdrawal authorization signed by `vault.voucher_signer`
+pub struct Voucher {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub expires_at: i64,
+}
+
+impl Voucher {
+    /// Bytes the voucher signer signs:
+    /// domain || vault || user || mint || amount (LE) || nonce (LE) || expires_at (LE)
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = Vec::with_capacity(VOUCHER_DOMAIN.len() + 32 + 32 + 32 + 8 + 8 + 8);
+        message.extend_from_slice(VOUCHER_DOMAIN);
+        message.extend_from_slice(self.vault.as_ref());
+        message.extend_from_slice(self.user.as_ref());
+        message.extend_from_slice(self.mint.as_ref());
+        message.extend_from_slice(&self.amount.to_le_bytes());
+        message.extend_from_slice(&self.nonce.to_le_bytes());
+        message.extend_from_slice(&self.expires_at.to_le_bytes());
+        message
+    }
+}
+
+/// Checks that the instruction right before this one is an Ed25519
+/// precompile call verifying exactly `message` under `signer`.
+///
+/// The runtime rejects the whole transaction if the precompile's signature
+/// check fails, so finding it here means the signature is valid. All data
+/// must live inside the precompile instruction itself, otherwise the
+/// verified bytes could differ from the ones read here.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current = load_current_index_checked(instructions_sysvar)? as usize;
+    require!(current > 0, TreasuryError::InvalidVoucher);
+    let instruction = load_instruction_at_checked(current - 1, instructions_sysvar)?;
+    require!(
+        instruction.program_id == ed25519_program::ID,
+        TreasuryError::InvalidVoucher
+    );
+
+    let data = &instruction.data;
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE && data[0] == 1,
+        TreasuryError::InvalidVoucher
+    );
+    let offset = |index: usize| {
+        let at = ED25519_OFFSETS_START + 2 * index;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let signature_instruction = offset(1);
+    let public_key_offset = offset(2) as usize;
+    let public_key_instruction = offset(3);
+    let message_offset = offset(4) as usize;
+    let message_size = offset(5) as usize;
+    let message_instruction = offset(6);
+    require!(
+        signature_instruction == CURRENT_INSTRUCTION
+            && public_key_instruction == CURRENT_INSTRUCTION
+            && message_instruction == CURRENT_INSTRUCTION,
+        TreasuryError::InvalidVoucher
+    );
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32);
+    let signed = data.get(message_offset..message_offset + message_size);
+    require!(
+        public_key == Some(signer.as_ref()) && signed == Some(message),
+        TreasuryError::InvalidVoucher
+    );
+    Ok(())
+}
@@ -21,6 +21,7 @@ import * as path from "path";
 
 const USDT_DECIMALS = 6;
 const ONE_USDT = 1_000_000;
+const VAULT_ID = 0;
 
 function loadKeypair(filePath: string): Keypair {
   const raw = JSON.parse(fs.readFileSync(filePath, "utf-8"));
@@ -106,10 +107,22 @@ async function main() {
     JSON.stringify(Array.from(payoutWallet.secretKey))
   );
 
+  // Guardian (emergency pause key) must differ from authority
+  const guardian = Keypair.generate();
+  console.log("Guardian:", guardian.publicKey.toBase58());
+  fs.writeFileSync(
+    path.join(__dirname, "devnet-guardian.json"),
+    JSON.stringify(Array.from(guardian.secretKey))
+  );
+
   // --- Step 4: Initialize vault ---
   console.log("\n=== Step 4: Initialize vault ===");
   const [vaultPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("treasury_vault"), authority.publicKey.toBuffer()],
+    [
+      Buffer.from("treasury_vault"),
+      authority.publicKey.toBuffer(),
+      new BN(VAULT_ID).toArrayLike(Buffer, "le", 2),
+    ],
     program.programId
   );
   console.log("Vault PDA:", vaultPda.toBase58());
@@ -124,11 +137,12 @@ async function main() {
 
   try {
     const tx = await program.methods
-      .initialize()
+      .initialize(VAULT_ID, [])
       .accounts({
         authority: authority.publicKey,
         usdtMint,
         payoutWallet: payoutWallet.publicKey,
+        guardian: guardian.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc();
@@ -150,7 +164,8 @@ async function main() {
     .deposit(new BN(100 * ONE_USDT))
     .accounts({
       authority: authority.publicKey,
-      usdtMint,
+      vault: vaultPda,
+      mint: usdtMint,
       vaultTokenAccount,
       tokenProgram: TOKEN_PROGRAM_ID,
     })
@@ -166,7 +181,8 @@ async function main() {
     .payout(new BN(30 * ONE_USDT))
     .accounts({
       authority: authority.publicKey,
-      usdtMint,
+      vault: vaultPda,
+      mint: usdtMint,
       vaultTokenAccount,
       payoutWallet: payoutWallet.publicKey,
       tokenProgram: TOKEN_PROGRAM_ID,